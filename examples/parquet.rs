@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 
 #[rustversion::attr(nightly, allow(non_local_definitions))]
 fn main() {
@@ -33,7 +33,6 @@ fn main() {
         k: NaiveTime,
         l: Option<HashMap<String, Vec<u8>>>,
         m: NaiveDate,
-        n: TimeDelta,
     }
     let input = [
         Foo {
@@ -53,7 +52,6 @@ fn main() {
                 vec![1, 2, 3, 4, 42],
             )])),
             m: NaiveDate::MAX,
-            n: TimeDelta::seconds(12345),
         },
         Foo {
             a: 42,
@@ -69,7 +67,6 @@ fn main() {
             k: Utc::now().time(),
             l: None,
             m: NaiveDate::MIN,
-            n: TimeDelta::minutes(1234),
         },
     ];
 
@@ -79,7 +76,7 @@ fn main() {
 
     let record_batch = RecordBatch::from(narrow_array);
     println!("From narrow StructArray to Arrow RecordBatch");
-    pretty::print_batches(&[record_batch.clone()]).unwrap();
+    pretty::print_batches(std::slice::from_ref(&record_batch)).unwrap();
 
     let mut buffer = Vec::new();
     let mut writer = ArrowWriter::try_new(&mut buffer, record_batch.schema(), None).unwrap();
@@ -89,10 +86,10 @@ fn main() {
     let mut reader = ParquetRecordBatchReader::try_new(Bytes::from(buffer), 1024).unwrap();
     let read = reader.next().unwrap().unwrap();
     println!("From Arrow RecordBatch to Parquet and back to Arrow RecordBatch");
-    pretty::print_batches(&[read.clone()]).unwrap();
+    pretty::print_batches(std::slice::from_ref(&read)).unwrap();
     assert_eq!(record_batch, read.clone());
 
-    let round_trip: StructArray<Foo, false, ScalarBuffer> = read.into();
+    let round_trip: StructArray<Foo, false, ScalarBuffer> = read.try_into().unwrap();
     let arrow_struct_array_round_trip = arrow_array::StructArray::from(round_trip);
     let record_batch_round_trip = arrow_array::RecordBatch::from(arrow_struct_array_round_trip);
     println!(
@@ -13,6 +13,16 @@ pub(super) fn derive(
     input: &DeriveInput,
     variants: &Punctuated<Variant, token::Comma>,
 ) -> TokenStream {
+    if let Err(error) = variants.iter().try_for_each(|variant| {
+        util::reject_narrow_attrs(&variant.attrs)?;
+        variant
+            .fields
+            .iter()
+            .try_for_each(|field| util::reject_narrow_attrs(&field.attrs))
+    }) {
+        return error.into_compile_error();
+    }
+
     let input = Enum::new(input, variants);
 
     // Generate the conversion to i8
@@ -39,6 +49,12 @@ pub(super) fn derive(
     // Generate an extend impl for the wrapper struct def.
     let array_struct_extend_sparse_impl = input.array_struct_extend_sparse_impl();
 
+    // Generate a length impl for the sparse wrapper struct def.
+    let array_struct_len_sparse_impl = input.array_struct_len_sparse_impl();
+
+    // Generate a slice impl for the sparse wrapper struct def.
+    let array_struct_slice_sparse_impl = input.array_struct_slice_sparse_impl();
+
     // Generate the UnionArrayType impl.
     let union_array_type_impl = input.union_array_type_impl();
 
@@ -57,6 +73,12 @@ pub(super) fn derive(
     // Generate the UnionArrayIterators impl for wrapper array struct.
     let array_union_array_iterators_impl = input.array_union_array_iterators_impl();
 
+    // Generate the TypeIdIndex impl for wrapper array struct.
+    let array_type_id_index_impl = input.array_type_id_index_impl();
+
+    // Generate the IntoOwned impl for the enum.
+    let enum_into_owned_impl = input.enum_into_owned_impl();
+
     let tokens = quote! {
         #i8_conversion
 
@@ -74,6 +96,10 @@ pub(super) fn derive(
 
         #array_struct_extend_sparse_impl
 
+        #array_struct_len_sparse_impl
+
+        #array_struct_slice_sparse_impl
+
         #array_into_iter_struct_def
 
         #array_into_iter_type_id_iter_dense_impl
@@ -82,9 +108,13 @@ pub(super) fn derive(
 
         #array_union_array_iterators_impl
 
+        #array_type_id_index_impl
+
         #union_array_type_impl
 
         #array_type_impl
+
+        #enum_into_owned_impl
     };
 
     #[cfg(feature = "arrow-rs")]
@@ -96,7 +126,7 @@ pub(super) fn derive(
         let union_array_to_vec_array_impl = input.union_array_to_vec_array_impl();
 
         // Generate the conversion from the variant arrays.
-        let union_array_from_iter_array_impl = input.union_array_from_iter_array_impl();
+        let union_array_from_vec_array_impl = input.union_array_from_vec_array_impl();
 
         quote! {
             #tokens
@@ -105,7 +135,7 @@ pub(super) fn derive(
 
             #union_array_to_vec_array_impl
 
-            #union_array_from_iter_array_impl
+            #union_array_from_vec_array_impl
         }
     }
     #[cfg(not(feature = "arrow-rs"))]
@@ -675,6 +705,87 @@ impl<'a> Enum<'a> {
         parse2(tokens).expect("array_union_array_iterators_impl")
     }
 
+    /// Generates `TypeIdIndex` impl for the wrapper array struct.
+    fn array_type_id_index_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let self_generics = self.generics.clone();
+        let self_ident = self.ident;
+        let (_, self_ty_generics, _) = self_generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(OffsetItem: #narrow::offset::OffsetElement))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(UnionLayout: #narrow::array::UnionType))
+            .visit_generics_mut(&mut generics);
+        let array_generics = generics.clone();
+        let (_, array_ty_generics, _) = array_generics.split_for_impl();
+        generics.params.insert(0, parse_quote!('_index));
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(
+                self.variant_indices().map::<WherePredicate, _>(|idx| {
+                    let data: Type = parse_quote!(<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data);
+                    parse_quote!(<#data as #narrow::array::ArrayType<#data>>::Array<Buffer, OffsetItem, UnionLayout>: #narrow::IndexOwned<'_index, #data>)
+                })
+            );
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        let fields = self
+            .variant_indices()
+            .map(|idx| {
+                quote! {
+                    #idx => <#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::from_data(
+                        #narrow::IndexOwned::index_owned_unchecked(&self.#idx, index)
+                    )
+                }
+            }).chain(iter::once(
+                quote! {
+                    _ => {
+                        panic!("type id greater than number of variants");
+                    }
+                }
+            ));
+
+        let array_struct_ident = self.array_struct_ident();
+        let tokens = quote! {
+            impl #impl_generics #narrow::array::union::TypeIdIndex<'_index> for #array_struct_ident #array_ty_generics #where_clause {
+                type Enum = #self_ident #self_ty_generics;
+
+                unsafe fn index_unchecked(&'_index self, type_id: ::std::primitive::i8, index: usize) -> Self::Enum {
+                    match type_id {
+                        #(
+                            #fields,
+                        )*
+                    }
+                }
+            }
+        };
+        parse2(tokens).expect("array_type_id_index_impl")
+    }
+
+    /// Generates the `IntoOwned` impl for the enum, which is returned
+    /// by value when indexing union arrays.
+    fn enum_into_owned_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        let ident = self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let tokens = quote! {
+            impl #impl_generics #narrow::IntoOwned<#ident #ty_generics> for #ident #ty_generics #where_clause {
+                fn into_owned(self) -> #ident #ty_generics {
+                    self
+                }
+            }
+        };
+        parse2(tokens).expect("enum_into_owned_impl")
+    }
+
     // Adds a default impl for the array wrapper struct.
     fn array_struct_clone_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
@@ -981,6 +1092,99 @@ impl<'a> Enum<'a> {
         parse2(tokens).expect("array_struct_extend_sparse_impl")
     }
 
+    /// Returns the generics of the sparse wrapper struct impls, with the
+    /// given bound for all variant arrays.
+    fn sparse_impl_generics(&self, bound: &TypeParamBound) -> Generics {
+        let narrow = util::narrow();
+
+        let self_generics = self.generics.clone();
+        let self_ident = self.ident;
+        let (_, self_ty_generics, _) = self_generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBound(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(OffsetItem: #narrow::offset::OffsetElement))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(
+                self.variant_indices().map::<WherePredicate, _>(|idx| {
+                    parse_quote!(<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<Buffer, OffsetItem, #narrow::array::SparseLayout>: #bound)
+                })
+            );
+        generics
+    }
+
+    /// Adds the sparse layout argument to the self type of the given impl.
+    fn sparse_self_ty(item_impl: &mut ItemImpl) {
+        let narrow = util::narrow();
+
+        match *item_impl.self_ty {
+            Type::Path(ref mut path) => {
+                let last_segment = path.path.segments.last_mut().unwrap();
+                match last_segment.arguments {
+                    syn::PathArguments::AngleBracketed(ref mut args) => {
+                        args.args.push(parse_quote!(#narrow::array::SparseLayout));
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // Adds a length impl for the sparse array wrapper struct. All variant
+    // arrays have the length of the union array.
+    fn array_struct_len_sparse_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        let generics = self.sparse_impl_generics(&parse_quote!(#narrow::Length));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let ident = self.array_struct_ident();
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl #impl_generics #narrow::Length for #ident #ty_generics #where_clause {
+                fn len(&self) -> usize {
+                    self.0.len()
+                }
+            }
+        };
+        Self::sparse_self_ty(&mut item_impl);
+        let tokens = quote!(#item_impl);
+        parse2(tokens).expect("array_struct_len_sparse_impl")
+    }
+
+    // Adds a slice impl for the sparse array wrapper struct.
+    fn array_struct_slice_sparse_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        let generics = self.sparse_impl_generics(&parse_quote!(#narrow::Slice));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let ident = self.array_struct_ident();
+        let slice_fields = self
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(idx, _)| Index::from(idx))
+            .map(|idx| quote!(#narrow::Slice::slice_unchecked(&self.#idx, offset, len)));
+        let mut item_impl: ItemImpl = parse_quote! {
+            impl #impl_generics #narrow::Slice for #ident #ty_generics #where_clause {
+                unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+                    Self(
+                        #(
+                            #slice_fields,
+                        )*
+                    )
+                }
+            }
+        };
+        Self::sparse_self_ty(&mut item_impl);
+        let tokens = quote!(#item_impl);
+        parse2(tokens).expect("array_struct_slice_sparse_impl")
+    }
+
     fn union_array_type_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
 
@@ -1115,7 +1319,7 @@ impl<'a> Enum<'a> {
     }
 
     #[cfg(feature = "arrow-rs")]
-    fn union_array_from_iter_array_impl(&self) -> ItemImpl {
+    fn union_array_from_vec_array_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
         let self_ident = self.ident;
         let (_, self_ty_generics, _) = self.generics.split_for_impl();
@@ -1129,38 +1333,39 @@ impl<'a> Enum<'a> {
             .visit_generics_mut(&mut generics);
         generics.make_where_clause().predicates.extend(
             self.variant_indices().map::<WherePredicate, _>(|idx| {
-                parse_quote!(::std::sync::Arc<dyn ::arrow_array::Array>: Into<
+                parse_quote!(
                 <<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data as #narrow::array::ArrayType<<#self_ident #self_ty_generics as #narrow::array::union::EnumVariant<#idx>>::Data>>::Array<
                     Buffer,
                     OffsetItem,
-                    #narrow::array::SparseLayout,
-                >,
-            >)
+                    UnionLayout,
+                >: ::std::convert::TryFrom<::std::sync::Arc<dyn ::arrow_array::Array>, Error = #narrow::Error>
+            )
             }),
         );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         let len = Literal::usize_unsuffixed(self.variants.len());
-        let pops = (0..self.variants.len()).map(|_| {
-            quote!(iter
-                .next()
-                .expect(&format!(
-                    "not enough variant data arrays, expected {VARIANTS}"
-                ))
-                .into())
-        });
+        let variant_array = (0..self.variants.len())
+            .map(|idx| format_ident!("array_{idx}"))
+            .collect::<Vec<_>>();
+        let variant_name = (0..self.variants.len()).map(|idx| idx.to_string());
         let tokens = quote! {
-            impl #impl_generics ::std::iter::FromIterator<::std::sync::Arc<dyn ::arrow_array::Array>> for #ident #ty_generics #where_clause {
-                fn from_iter<_I: ::std::iter::IntoIterator<Item = ::std::sync::Arc<dyn ::arrow_array::Array>>>(iter: _I) -> Self {
-                    let mut iter = iter.into_iter();
-                    const VARIANTS: usize = #len;
-                    Self(
+            impl #impl_generics ::std::convert::TryFrom<::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>> for #ident #ty_generics #where_clause {
+                type Error = #narrow::Error;
+
+                fn try_from(value: ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>) -> ::std::result::Result<Self, Self::Error> {
+                    let [#(#variant_array,)*]: [_; #len] = ::std::convert::TryInto::try_into(value).map_err(|arrays: ::std::vec::Vec<_>| #narrow::Error::FieldCount {
+                        path: ::std::vec::Vec::new(),
+                        expected: #len,
+                        actual: arrays.len(),
+                    })?;
+                    ::std::result::Result::Ok(Self(
                         #(
-                            #pops,
+                            ::std::convert::TryInto::try_into(#variant_array).map_err(|error: #narrow::Error| error.with_field(#variant_name))?,
                         )*
-                    )
+                    ))
                 }
             }
         };
-        parse2(tokens).expect("union_array_from_iter_array_impl")
+        parse2(tokens).expect("union_array_from_vec_array_impl")
     }
 }
@@ -21,10 +21,17 @@ static NARROW: Lazy<String> = Lazy::new(|| match proc_macro_crate::crate_name(CR
 
 /// Derive macro for the ArrayType trait.
 ///
+/// Fields of structs support the following attributes:
+/// - `#[narrow(dictionary)]`: store the field in a dictionary array with
+///   `i32` keys.
 #[proc_macro_derive(ArrayType, attributes(narrow))]
 pub fn derive_array_type(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
+    if let Err(error) = util::reject_narrow_attrs(&input.attrs) {
+        return error.into_compile_error().into();
+    }
+
     match input.data {
         syn::Data::Struct(DataStruct { ref fields, .. }) => r#struct::derive(&input, fields),
         syn::Data::Enum(DataEnum { ref variants, .. }) => r#enum::derive(&input, variants),
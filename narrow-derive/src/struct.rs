@@ -8,7 +8,10 @@ use syn::{
 };
 
 pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
-    let input = Struct::new(input, fields);
+    let input = match Struct::new(input, fields) {
+        Ok(input) => input,
+        Err(error) => return error.into_compile_error(),
+    };
 
     // If this is a unit struct we generate a unit impl.
     let unit_impl = matches!(fields, Fields::Unit)
@@ -34,6 +37,9 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
     // Generate the Length implementation.
     let array_len_impl = input.array_len_impl();
 
+    // Generate the Slice implementation.
+    let array_slice_impl = input.array_slice_impl();
+
     // Generate the Extend implementation.
     let array_extend_impl = input.array_extend_impl();
 
@@ -49,6 +55,15 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
     // Generate the IntoIterator implementation.
     let array_into_iter_impl = input.array_into_iter_impl();
 
+    // Generate the ref struct definition.
+    let ref_struct_def = input.ref_struct_def();
+
+    // Generate the Index implementation.
+    let array_index_impl = input.array_index_impl();
+
+    // Generate the IntoOwned implementation for the ref struct.
+    let ref_into_owned_impl = input.ref_into_owned_impl();
+
     let tokens = quote! {
         #unit_impl
 
@@ -64,6 +79,8 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
 
         #array_len_impl
 
+        #array_slice_impl
+
         #array_extend_impl
 
         #array_from_iter_impl
@@ -73,6 +90,12 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
         #array_iter_iterator_impl
 
         #array_into_iter_impl
+
+        #ref_struct_def
+
+        #array_index_impl
+
+        #ref_into_owned_impl
     };
 
     #[cfg(feature = "arrow-rs")]
@@ -102,21 +125,54 @@ pub(super) fn derive(input: &DeriveInput, fields: &Fields) -> TokenStream {
 
 type FieldIdents<'a> = Map<Enumerate<punctuated::Iter<'a, Field>>, fn((usize, &Field)) -> Ident>;
 
+/// The `#[narrow(..)]` attributes of a field.
+#[derive(Default)]
+struct FieldAttrs {
+    /// Store the field in a dictionary array.
+    dictionary: bool,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut field_attrs = Self::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("narrow"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("dictionary") {
+                    field_attrs.dictionary = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported narrow field attribute"))
+                }
+            })?;
+        }
+        Ok(field_attrs)
+    }
+}
+
 struct Struct<'a> {
     vis: &'a Visibility,
     ident: &'a Ident,
     generics: &'a Generics,
     fields: &'a Fields,
+    field_attrs: Vec<FieldAttrs>,
 }
 
 impl<'a> Struct<'a> {
-    pub fn new(input: &'a DeriveInput, fields: &'a Fields) -> Self {
-        Self {
+    pub fn new(input: &'a DeriveInput, fields: &'a Fields) -> syn::Result<Self> {
+        Ok(Self {
             vis: &input.vis,
             ident: &input.ident,
             generics: &input.generics,
             fields,
-        }
+            field_attrs: fields
+                .iter()
+                .map(FieldAttrs::parse)
+                .collect::<syn::Result<_>>()?,
+        })
     }
 }
 
@@ -131,6 +187,11 @@ impl Struct<'_> {
         format_ident!("{}ArrayIter", self.ident)
     }
 
+    /// Returns the name of the ref struct.
+    fn ref_struct_ident(&self) -> Ident {
+        format_ident!("{}Ref", self.ident)
+    }
+
     /// Returns the `ArrayType` trait bound
     fn array_type_bound() -> TypeParamBound {
         let narrow = util::narrow();
@@ -173,7 +234,25 @@ impl Struct<'_> {
             })
     }
 
-    fn field_idents(&self) -> FieldIdents {
+    /// Returns the array types of the fields.
+    fn field_array_types(&self) -> impl Iterator<Item = Type> + '_ {
+        let narrow = util::narrow();
+        self.field_types()
+            .zip(self.field_types_drop_option())
+            .zip(&self.field_attrs)
+            .map(move |((ty, ty_drop), field_attrs)| {
+                if field_attrs.dictionary {
+                    // The values of a dictionary are not nullable, nulls are
+                    // stored in the keys.
+                    let nullable = util::option_param(ty).is_some();
+                    parse_quote!(#narrow::array::DictionaryArray<i32, <#ty_drop as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>, #nullable, Buffer>)
+                } else {
+                    parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>)
+                }
+            })
+    }
+
+    fn field_idents(&self) -> FieldIdents<'_> {
         self.fields
             .iter()
             .enumerate()
@@ -283,11 +362,10 @@ impl Struct<'_> {
             // Fields
             let field_ident = self.field_idents().map(|ident| ident.to_string());
             let field_name = field_ident.clone();
-            let field_ty = self.field_types();
-            let field_ty_drop = self.field_types_drop_option();
+            let field_array_ty = self.field_array_types();
             let fields = quote!(
                 #(
-                    ::std::sync::Arc::new(<<#field_ty as ::narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as #narrow::arrow::Array>::as_field(#field_ident)),
+                    ::std::sync::Arc::new(<#field_array_ty as #narrow::arrow::Array>::as_field(#field_ident)),
                 )*
             );
             quote! {
@@ -366,7 +444,7 @@ impl Struct<'_> {
         parse2(tokens).expect("struct_array_into_array_refs")
     }
 
-    /// Add an `From` implementation for the array to convert from a vec of array refs
+    /// Add a `TryFrom` implementation for the array to convert from a vec of array refs
     #[cfg(feature = "arrow-rs")]
     fn struct_array_from_array_refs(&self) -> ItemImpl {
         let narrow = util::narrow();
@@ -381,41 +459,48 @@ impl Struct<'_> {
             .make_where_clause()
             .predicates
             .extend(self.where_predicate_fields(parse_quote!(
-                ::std::convert::From<::std::sync::Arc<dyn ::arrow_array::Array>>
+                ::std::convert::TryFrom<::std::sync::Arc<dyn ::arrow_array::Array>, Error = #narrow::Error>
             )));
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         // Fields
+        let (field_ident, field_name): (Vec<_>, Vec<_>) = match self.fields {
+            Fields::Unit => (vec![format_ident!("array")], vec![self.ident.to_string()]),
+            _ => self
+                .field_idents()
+                .map(|ident| (format_ident!("{}_array", ident), ident.to_string()))
+                .unzip(),
+        };
         let field_arrays = self.surround_with_delimiters(match self.fields {
             Fields::Named(_) => {
-                let field_ident = self.field_idents();
+                let ident = self.field_idents();
                 quote!(
                     #(
-                        #field_ident: arrays.next().expect("array").into(),
+                        #ident: ::std::convert::TryInto::try_into(#field_ident).map_err(|error: #narrow::Error| error.with_field(#field_name))?,
                     )*
                 )
             }
-            Fields::Unnamed(_) => {
-                let field = std::iter::repeat(quote!(arrays.next().expect("array").into()))
-                    .take(self.fields.len());
+            Fields::Unnamed(_) | Fields::Unit => {
                 quote!(
                     #(
-                        #field,
+                        ::std::convert::TryInto::try_into(#field_ident).map_err(|error: #narrow::Error| error.with_field(#field_name))?,
                     )*
                 )
             }
-            Fields::Unit => {
-                quote!(arrays.next().expect("array").into())
-            }
         });
+        let len = field_ident.len();
         let ident = self.array_struct_ident();
         let tokens = quote! {
-            impl #impl_generics ::std::convert::From<::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>> for #ident #ty_generics #where_clause  {
-                fn from(value: ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>) -> Self {
-                    let mut arrays = value.into_iter();
-                    let result = Self #field_arrays;
-                    assert!(arrays.next().is_none());
-                    result
+            impl #impl_generics ::std::convert::TryFrom<::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>> for #ident #ty_generics #where_clause  {
+                type Error = #narrow::Error;
+
+                fn try_from(value: ::std::vec::Vec<::std::sync::Arc<dyn ::arrow_array::Array>>) -> ::std::result::Result<Self, Self::Error> {
+                    let [#(#field_ident,)*]: [_; #len] = ::std::convert::TryInto::try_into(value).map_err(|arrays: ::std::vec::Vec<_>| #narrow::Error::FieldCount {
+                        path: ::std::vec::Vec::new(),
+                        expected: #len,
+                        actual: arrays.len(),
+                    })?;
+                    ::std::result::Result::Ok(Self #field_arrays)
                 }
             }
         };
@@ -438,22 +523,20 @@ impl Struct<'_> {
         let fields = self.surround_with_delimiters(match self.fields {
             Fields::Named(_) => {
                 let field_ident = self.field_idents();
-                let field_ty = self.field_types();
                 let field_vis = self.field_vis();
-                let field_ty_drop = self.field_types_drop_option();
+                let field_array_ty = self.field_array_types();
                 quote!(
                     #(
-                        #field_vis #field_ident: <#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>,
+                        #field_vis #field_ident: #field_array_ty,
                     )*
                 )
             }
             Fields::Unnamed(_) => {
-                let field_ty = self.field_types();
                 let field_vis = self.field_vis();
-                let field_ty_drop = self.field_types_drop_option();
+                let field_array_ty = self.field_array_types();
                 quote!(
                     #(
-                        #field_vis <#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>,
+                        #field_vis #field_array_ty,
                     )*
                 )
             }
@@ -618,6 +701,58 @@ impl Struct<'_> {
         parse2(tokens).expect("array_len_impl")
     }
 
+    fn array_slice_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::Slice)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let slice_fields = self.surround_with_delimiters(match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                quote!(
+                    #(
+                        #field_ident: #narrow::Slice::slice_unchecked(&self.#field_ident, offset, len),
+                    )*
+                )
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = self
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, _)| Index::from(idx));
+                quote!(
+                    #(
+                        #narrow::Slice::slice_unchecked(&self.#field_idx, offset, len),
+                    )*
+                )
+            }
+            Fields::Unit => {
+                quote!(#narrow::Slice::slice_unchecked(&self.0, offset, len))
+            }
+        });
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::Slice for #ident #ty_generics #where_clause {
+                unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+                    Self #slice_fields
+                }
+            }
+        );
+        parse2(tokens).expect("array_slice_impl")
+    }
+
     fn array_extend_impl(&self) -> ItemImpl {
         let narrow = util::narrow();
         let ident = self.ident;
@@ -632,13 +767,13 @@ impl Struct<'_> {
         AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
         AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
             .visit_generics_mut(&mut generics);
-        generics
-            .make_where_clause()
-            .predicates
-            .extend(
-                self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::Extend<#ty>))
-            );
+        generics.make_where_clause().predicates.extend(
+            self.field_types()
+                .zip(self.field_array_types())
+                .map::<WherePredicate, _>(
+                    |(ty, array_ty)| parse_quote!(#array_ty: ::std::iter::Extend<#ty>),
+                ),
+        );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let array_struct_ident = self.array_struct_ident();
@@ -700,8 +835,8 @@ impl Struct<'_> {
             .make_where_clause()
             .predicates
             .extend(
-                self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::default::Default + ::std::iter::Extend<#ty>))
+                self.field_types().zip(self.field_array_types())
+                    .map::<WherePredicate, _>(|(ty, array_ty)| parse_quote!(#array_ty: ::std::default::Default + ::std::iter::Extend<#ty>))
             );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
@@ -753,38 +888,34 @@ impl Struct<'_> {
         AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
         AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
             .visit_generics_mut(&mut generics);
-        generics
-            .make_where_clause()
-            .predicates
-            .extend(
-                self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::IntoIterator<Item = #ty>))
-            );
+        generics.make_where_clause().predicates.extend(
+            self.field_types()
+                .zip(self.field_array_types())
+                .map::<WherePredicate, _>(
+                    |(ty, array_ty)| parse_quote!(#array_ty: ::std::iter::IntoIterator<Item = #ty>),
+                ),
+        );
         let (impl_generics, _, where_clause) = generics.split_for_impl();
 
         // Iter struct definition
         let array_iter_struct_ident = self.array_iter_struct_ident();
         let fields = self.surround_with_delimiters(match self.fields {
             Fields::Unnamed(_) => {
-                let field_ty = self.field_types();
                 let field_vis = self.field_vis();
-                let field_ty_drop = self.field_types_drop_option();
-                let narrow = util::narrow();
+                let field_array_ty = self.field_array_types();
                 quote!(
                     #(
-                        #field_vis <<#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as ::std::iter::IntoIterator>::IntoIter,
+                        #field_vis <#field_array_ty as ::std::iter::IntoIterator>::IntoIter,
                     )*
                 )
             }
             Fields::Named(_) => {
                 let field_ident = self.field_idents();
-                let field_ty = self.field_types();
                 let field_vis = self.field_vis();
-                let field_ty_drop = self.field_types_drop_option();
-                let narrow = util::narrow();
+                let field_array_ty = self.field_array_types();
                 quote!(
                     #(
-                        #field_vis #field_ident: <<#field_ty as #narrow::array::ArrayType<#field_ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA> as ::std::iter::IntoIterator>::IntoIter,
+                        #field_vis #field_ident: <#field_array_ty as ::std::iter::IntoIterator>::IntoIter,
                     )*
                 )
             }
@@ -832,13 +963,13 @@ impl Struct<'_> {
         AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
         AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
             .visit_generics_mut(&mut generics);
-        generics
-            .make_where_clause()
-            .predicates
-            .extend(
-                self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::IntoIterator<Item = #ty>))
-            );
+        generics.make_where_clause().predicates.extend(
+            self.field_types()
+                .zip(self.field_array_types())
+                .map::<WherePredicate, _>(
+                    |(ty, array_ty)| parse_quote!(#array_ty: ::std::iter::IntoIterator<Item = #ty>),
+                ),
+        );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         let next = match self.fields {
@@ -907,13 +1038,13 @@ impl Struct<'_> {
         AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
         AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
             .visit_generics_mut(&mut generics);
-        generics
-            .make_where_clause()
-            .predicates
-            .extend(
-                self.field_types().zip(self.field_types_drop_option())
-                    .map::<WherePredicate, _>(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: ::std::iter::IntoIterator<Item = #ty>))
-            );
+        generics.make_where_clause().predicates.extend(
+            self.field_types()
+                .zip(self.field_array_types())
+                .map::<WherePredicate, _>(
+                    |(ty, array_ty)| parse_quote!(#array_ty: ::std::iter::IntoIterator<Item = #ty>),
+                ),
+        );
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
         // Iter struct definition
@@ -956,6 +1087,195 @@ impl Struct<'_> {
         parse2(tokens).expect("array_into_iter_impl")
     }
 
+    /// Returns the type parameters of the ref struct, one for every field.
+    fn ref_type_params(&self) -> Vec<Ident> {
+        (0..self.fields.len())
+            .map(|idx| format_ident!("_{idx}"))
+            .collect()
+    }
+
+    /// Returns the struct definition of the ref struct, a view of a row in
+    /// the array with the items of the field arrays. Unit structs don't have
+    /// a ref struct because they are returned by value.
+    fn ref_struct_def(&self) -> Option<ItemStruct> {
+        if matches!(self.fields, Fields::Unit) {
+            return None;
+        }
+
+        let ty_param = self.ref_type_params();
+        let fields = self.surround_with_delimiters(match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                let field_vis = self.field_vis();
+                quote!(
+                    #(
+                        #field_vis #field_ident: #ty_param,
+                    )*
+                )
+            }
+            Fields::Unnamed(_) => {
+                let field_vis = self.field_vis();
+                quote!(
+                    #(
+                        #field_vis #ty_param,
+                    )*
+                )
+            }
+            Fields::Unit => unreachable!(),
+        });
+
+        let rest = if matches!(self.fields, Fields::Named(_)) {
+            fields
+        } else {
+            quote!(#fields;)
+        };
+
+        let vis = self.vis;
+        let ident = self.ref_struct_ident();
+        let tokens = quote!(
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            #vis struct #ident < #( #ty_param, )* > #rest
+        );
+        Some(parse2(tokens).expect("ref_struct_def"))
+    }
+
+    fn array_index_impl(&self) -> ItemImpl {
+        let narrow = util::narrow();
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(self.ident, &generics).visit_generics_mut(&mut generics);
+        AddTypeParamBoundWithSelf(Self::array_type_bound()).visit_generics_mut(&mut generics);
+        AddTypeParam(parse_quote!(Buffer: #narrow::buffer::BufferType))
+            .visit_generics_mut(&mut generics);
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(self.where_predicate_fields(parse_quote!(#narrow::Index)));
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let ref_struct_ident = self.ref_struct_ident();
+        let (item, index) = match self.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => {
+                let field_array_ty = self.field_array_types();
+                let item = quote!(
+                    #ref_struct_ident<
+                        #(
+                            <#field_array_ty as #narrow::Index>::Item<'_index>,
+                        )*
+                    >
+                );
+                let index = if matches!(self.fields, Fields::Named(_)) {
+                    let field_ident = self.field_idents();
+                    let field_ident_value = self.field_idents();
+                    quote!(
+                        #ref_struct_ident {
+                            #(
+                                #field_ident: self.#field_ident_value.index_unchecked(index),
+                            )*
+                        }
+                    )
+                } else {
+                    let field_idx = self
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, _)| Index::from(idx));
+                    quote!(
+                        #ref_struct_ident(
+                            #(
+                                self.#field_idx.index_unchecked(index),
+                            )*
+                        )
+                    )
+                };
+                (item, index)
+            }
+            Fields::Unit => {
+                let ident = self.ident;
+                let (_, ty_generics, _) = self.generics.split_for_impl();
+                (
+                    quote!(<#narrow::array::NullArray<#ident #ty_generics, false, Buffer> as #narrow::Index>::Item<'_index>),
+                    quote!(self.0.index_unchecked(index)),
+                )
+            }
+        };
+
+        let ident = self.array_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::Index for #ident #ty_generics #where_clause {
+                type Item<'_index> = #item
+                where
+                    Self: '_index;
+
+                unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+                    #index
+                }
+            }
+        );
+        parse2(tokens).expect("array_index_impl")
+    }
+
+    /// Returns the `IntoOwned` implementation for the ref struct, which
+    /// converts the row view back to the derive input.
+    fn ref_into_owned_impl(&self) -> Option<ItemImpl> {
+        let narrow = util::narrow();
+        let ident = self.ident;
+
+        if matches!(self.fields, Fields::Unit) {
+            return None;
+        }
+
+        // Generics
+        let mut generics = self.generics.clone();
+        SelfReplace::new(ident, &generics).visit_generics_mut(&mut generics);
+        let (_, ident_ty_generics, _) = generics.split_for_impl();
+        let ident_ty_generics = ident_ty_generics.to_token_stream();
+        let ty_param = self.ref_type_params();
+        self.field_types()
+            .zip(&ty_param)
+            .for_each(|(ty, ty_param)| {
+                AddTypeParam(parse_quote!(#ty_param: #narrow::IntoOwned<#ty>))
+                    .visit_generics_mut(&mut generics);
+            });
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+        let fields = self.surround_with_delimiters(match self.fields {
+            Fields::Named(_) => {
+                let field_ident = self.field_idents();
+                let field_ident_value = self.field_idents();
+                quote!(
+                    #(
+                        #field_ident: #narrow::IntoOwned::into_owned(self.#field_ident_value),
+                    )*
+                )
+            }
+            Fields::Unnamed(_) => {
+                let field_idx = self
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, _)| Index::from(idx));
+                quote!(
+                    #(
+                        #narrow::IntoOwned::into_owned(self.#field_idx),
+                    )*
+                )
+            }
+            Fields::Unit => unreachable!(),
+        });
+
+        let ref_struct_ident = self.ref_struct_ident();
+        let tokens = quote!(
+            impl #impl_generics #narrow::IntoOwned<#ident #ident_ty_generics> for #ref_struct_ident < #( #ty_param, )* > #where_clause {
+                fn into_owned(self) -> #ident #ident_ty_generics {
+                    #ident #fields
+                }
+            }
+        );
+        Some(parse2(tokens).expect("ref_into_owned_impl"))
+    }
+
     fn field_tuple(&self) -> TokenStream {
         let mut ident = self.field_idents();
         let initial = ident.next_back().map(|last| quote!((#last, ()))).unwrap();
@@ -966,22 +1286,20 @@ impl Struct<'_> {
         &self,
         bound: TypeParamBound,
     ) -> impl Iterator<Item = WherePredicate> + '_ {
-        let narrow = util::narrow();
-        self.field_types().zip(self.field_types_drop_option())
-            .map(move |(ty, ty_drop)| parse_quote!(<#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>: #bound))
+        self.field_array_types()
+            .map(move |array_ty| parse_quote!(#array_ty: #bound))
     }
 
     #[cfg(feature = "arrow-rs")]
     fn where_predicate_fields_arrow_array_into(&self) -> impl Iterator<Item = WherePredicate> + '_ {
-        self.field_types().zip(self.field_types_drop_option())
-            .map(move |(ty, ty_drop)| {
-                let narrow = util::narrow();
-                parse_quote!(
-                <#ty as #narrow::array::ArrayType<#ty_drop>>::Array<Buffer, #narrow::offset::NA, #narrow::array::union::NA>:
+        self.field_array_types().map(|array_ty| {
+            parse_quote!(
+                #array_ty:
                     ::std::convert::Into<
                         ::std::sync::Arc<dyn ::arrow_array::Array>
                     >
-            )})
+            )
+        })
     }
 }
 
@@ -1000,6 +1318,7 @@ mod tests {
 
         assert_eq!(
             Struct::new(&derive_input, &as_struct.fields)
+                .unwrap()
                 .surround_with_delimiters(quote!(x))
                 .to_string(),
             "(x)"
@@ -1014,6 +1333,7 @@ mod tests {
 
         assert_eq!(
             Struct::new(&derive_input, &as_struct.fields)
+                .unwrap()
                 .surround_with_delimiters(quote!(x))
                 .to_string(),
             "(x)"
@@ -1031,6 +1351,7 @@ mod tests {
 
         assert_eq!(
             Struct::new(&derive_input, &as_struct.fields)
+                .unwrap()
                 .surround_with_delimiters(quote!(x))
                 .to_string(),
             "{ x }"
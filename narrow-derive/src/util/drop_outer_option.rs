@@ -3,28 +3,35 @@ use syn::{
     TypePath,
 };
 
+/// Returns the inner type if the given type is an `Option`.
+pub fn option_param(ty: &Type) -> Option<&Type> {
+    if let Type::Path(TypePath { ref path, .. }) = *ty {
+        if let Some(path_segment) = path
+            .segments
+            .iter()
+            .find(|path_segment| "Option" == path_segment.ident.to_string().as_str())
+        {
+            if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                ref args, ..
+            }) = path_segment.arguments
+            {
+                if let Some(GenericArgument::Type(inner)) = args.first() {
+                    return Some(inner);
+                }
+            }
+        }
+    }
+    None
+}
+
 /// Adds the owned type param to all type parameters.
 pub struct DropOuterParam;
 
 impl VisitMut for DropOuterParam {
     fn visit_type_mut(&mut self, i: &mut syn::Type) {
-        if let Type::Path(TypePath { ref mut path, .. }) = i {
-            if let Some(path_segment) = path
-                .segments
-                .iter()
-                .find(|path_segment| "Option" == path_segment.ident.to_string().as_str())
-            {
-                if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                    ref args,
-                    ..
-                }) = path_segment.arguments
-                {
-                    if let Some(GenericArgument::Type(ty)) = args.first() {
-                        // Replace with the inner type of the option
-                        *i = ty.clone();
-                    }
-                }
-            }
+        if let Some(ty) = option_param(i) {
+            // Replace with the inner type of the option
+            *i = ty.clone();
         }
     }
 }
@@ -65,4 +72,13 @@ mod tests {
         DropOuterParam.visit_type_mut(&mut ty);
         assert_eq!(quote!(#ty).to_string(), "Option < Foo >");
     }
+
+    #[test]
+    fn option_param() {
+        assert!(super::option_param(&parse_quote!(Foo)).is_none());
+        assert!(super::option_param(&parse_quote!(Option)).is_none());
+        let ty: syn::Type = parse_quote!(std::option::Option<Foo>);
+        let inner = super::option_param(&ty);
+        assert_eq!(quote!(#inner).to_string(), "Foo");
+    }
 }
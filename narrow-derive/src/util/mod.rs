@@ -1,6 +1,7 @@
 use crate::NARROW;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use syn::Attribute;
 
 mod add_type_param;
 pub(super) use add_type_param::*;
@@ -14,9 +15,37 @@ pub(super) use type_param_bound::*;
 mod drop_outer_option;
 pub(super) use drop_outer_option::*;
 
+/// Returns an error if any of the given attributes is a `#[narrow(..)]`
+/// attribute. These are only supported on the fields of structs.
+pub(super) fn reject_narrow_attrs(attrs: &[Attribute]) -> syn::Result<()> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("narrow"))
+        .map_or(Ok(()), |attr| {
+            Err(syn::Error::new_spanned(
+                attr,
+                "narrow attributes are only supported on struct fields",
+            ))
+        })
+}
+
 /// Returns the name of the `narrow` crate. Panics when the `narrow` crate is
 /// not found.
 pub(super) fn narrow() -> TokenStream {
     let ident = format_ident!("{}", &*NARROW);
     quote!(#ident)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn reject_narrow_attrs() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[derive(Debug)])];
+        assert!(super::reject_narrow_attrs(&attrs).is_ok());
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[narrow(dictionary)])];
+        assert!(super::reject_narrow_attrs(&attrs).is_err());
+    }
+}
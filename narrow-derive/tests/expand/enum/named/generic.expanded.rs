@@ -286,6 +286,74 @@ where
             });
     }
 }
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Length for FooArray<T, Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+impl<
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Slice for FooArray<T, Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(
+            narrow::Slice::slice_unchecked(&self.0, offset, len),
+            narrow::Slice::slice_unchecked(&self.1, offset, len),
+            narrow::Slice::slice_unchecked(&self.2, offset, len),
+        )
+    }
+}
 struct FooArrayIntoIter<
     T: narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
@@ -562,6 +630,96 @@ where
         >(self.0.into_iter(), self.1.into_iter(), self.2.into_iter())
     }
 }
+impl<
+    '_index,
+    T: narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::TypeIdIndex<'_index>
+for FooArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <Foo<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <Foo<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >,
+    <<Foo<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <Foo<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >,
+{
+    type Enum = Foo<T>;
+    unsafe fn index_unchecked(
+        &'_index self,
+        type_id: ::std::primitive::i8,
+        index: usize,
+    ) -> Self::Enum {
+        match type_id {
+            0 => {
+                <Foo<
+                    T,
+                > as narrow::array::union::EnumVariant<
+                    0,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.0, index))
+            }
+            1 => {
+                <Foo<
+                    T,
+                > as narrow::array::union::EnumVariant<
+                    1,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.1, index))
+            }
+            2 => {
+                <Foo<
+                    T,
+                > as narrow::array::union::EnumVariant<
+                    2,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.2, index))
+            }
+            _ => {
+                {
+                    ::core::panicking::panic_fmt(
+                        format_args!("type id greater than number of variants"),
+                    );
+                };
+            }
+        }
+    }
+}
 impl<T: narrow::array::ArrayType> narrow::array::UnionArrayType<3> for Foo<T> {
     type Array<
         Buffer: narrow::buffer::BufferType,
@@ -582,6 +740,11 @@ impl<T: narrow::array::ArrayType> narrow::array::ArrayType<Foo<T>> for Foo<T> {
         OffsetItem,
     >;
 }
+impl<T> narrow::IntoOwned<Foo<T>> for Foo<T> {
+    fn into_owned(self) -> Foo<T> {
+        self
+    }
+}
 #[automatically_derived]
 impl<T: ::core::clone::Clone> ::core::clone::Clone for Foo<T> {
     #[inline]
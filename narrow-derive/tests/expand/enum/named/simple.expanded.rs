@@ -346,6 +346,71 @@ where
             });
     }
 }
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Length for FooBarArray<Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+impl<
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Slice for FooBarArray<Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(
+            narrow::Slice::slice_unchecked(&self.0, offset, len),
+            narrow::Slice::slice_unchecked(&self.1, offset, len),
+            narrow::Slice::slice_unchecked(&self.2, offset, len),
+            narrow::Slice::slice_unchecked(&self.3, offset, len),
+        )
+    }
+}
 struct FooBarArrayIntoIter<
     Buffer: narrow::buffer::BufferType,
     OffsetItem: narrow::offset::OffsetElement,
@@ -645,6 +710,100 @@ where
         >(self.0.into_iter(), self.1.into_iter(), self.2.into_iter(), self.3.into_iter())
     }
 }
+impl<
+    '_index,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::TypeIdIndex<'_index>
+for FooBarArray<Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar as narrow::array::union::EnumVariant<0>>::Data,
+    >,
+    <<FooBar as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar as narrow::array::union::EnumVariant<1>>::Data,
+    >,
+    <<FooBar as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar as narrow::array::union::EnumVariant<2>>::Data,
+    >,
+    <<FooBar as narrow::array::union::EnumVariant<
+        3,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar as narrow::array::union::EnumVariant<3>>::Data,
+    >,
+{
+    type Enum = FooBar;
+    unsafe fn index_unchecked(
+        &'_index self,
+        type_id: ::std::primitive::i8,
+        index: usize,
+    ) -> Self::Enum {
+        match type_id {
+            0 => {
+                <FooBar as narrow::array::union::EnumVariant<
+                    0,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.0, index))
+            }
+            1 => {
+                <FooBar as narrow::array::union::EnumVariant<
+                    1,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.1, index))
+            }
+            2 => {
+                <FooBar as narrow::array::union::EnumVariant<
+                    2,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.2, index))
+            }
+            3 => {
+                <FooBar as narrow::array::union::EnumVariant<
+                    3,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.3, index))
+            }
+            _ => {
+                {
+                    ::core::panicking::panic_fmt(
+                        format_args!("type id greater than number of variants"),
+                    );
+                };
+            }
+        }
+    }
+}
 impl narrow::array::UnionArrayType<4> for FooBar {
     type Array<
         Buffer: narrow::buffer::BufferType,
@@ -665,3 +824,8 @@ impl narrow::array::ArrayType<FooBar> for FooBar {
         OffsetItem,
     >;
 }
+impl narrow::IntoOwned<FooBar> for FooBar {
+    fn into_owned(self) -> FooBar {
+        self
+    }
+}
@@ -181,6 +181,59 @@ where
             });
     }
 }
+impl<
+    const X: bool,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Length for FooBarArray<X, Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+impl<
+    const X: bool,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Slice for FooBarArray<X, Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(
+            narrow::Slice::slice_unchecked(&self.0, offset, len),
+            narrow::Slice::slice_unchecked(&self.1, offset, len),
+        )
+    }
+}
 struct FooBarArrayIntoIter<
     const X: bool,
     Buffer: narrow::buffer::BufferType,
@@ -390,6 +443,75 @@ where
         >(self.0.into_iter(), self.1.into_iter())
     }
 }
+impl<
+    '_index,
+    const X: bool,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::TypeIdIndex<'_index>
+for FooBarArray<X, Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar<X> as narrow::array::union::EnumVariant<0>>::Data,
+    >,
+    <<FooBar<
+        X,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar<X> as narrow::array::union::EnumVariant<1>>::Data,
+    >,
+{
+    type Enum = FooBar<X>;
+    unsafe fn index_unchecked(
+        &'_index self,
+        type_id: ::std::primitive::i8,
+        index: usize,
+    ) -> Self::Enum {
+        match type_id {
+            0 => {
+                <FooBar<
+                    X,
+                > as narrow::array::union::EnumVariant<
+                    0,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.0, index))
+            }
+            1 => {
+                <FooBar<
+                    X,
+                > as narrow::array::union::EnumVariant<
+                    1,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.1, index))
+            }
+            _ => {
+                {
+                    ::core::panicking::panic_fmt(
+                        format_args!("type id greater than number of variants"),
+                    );
+                };
+            }
+        }
+    }
+}
 impl<const X: bool> narrow::array::UnionArrayType<2> for FooBar<X> {
     type Array<
         Buffer: narrow::buffer::BufferType,
@@ -410,3 +532,8 @@ impl<const X: bool> narrow::array::ArrayType<FooBar<X>> for FooBar<X> {
         OffsetItem,
     >;
 }
+impl<const X: bool> narrow::IntoOwned<FooBar<X>> for FooBar<X> {
+    fn into_owned(self) -> FooBar<X> {
+        self
+    }
+}
@@ -292,6 +292,74 @@ where
             });
     }
 }
+impl<
+    T: Default + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Length for FooBarArray<T, Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Length,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+impl<
+    T: Default + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+> narrow::Slice for FooBarArray<T, Buffer, OffsetItem, narrow::array::SparseLayout>
+where
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<Buffer, OffsetItem, narrow::array::SparseLayout>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(
+            narrow::Slice::slice_unchecked(&self.0, offset, len),
+            narrow::Slice::slice_unchecked(&self.1, offset, len),
+            narrow::Slice::slice_unchecked(&self.2, offset, len),
+        )
+    }
+}
 struct FooBarArrayIntoIter<
     T: Default + narrow::array::ArrayType,
     Buffer: narrow::buffer::BufferType,
@@ -568,6 +636,96 @@ where
         >(self.0.into_iter(), self.1.into_iter(), self.2.into_iter())
     }
 }
+impl<
+    '_index,
+    T: Default + narrow::array::ArrayType,
+    Buffer: narrow::buffer::BufferType,
+    OffsetItem: narrow::offset::OffsetElement,
+    UnionLayout: narrow::array::UnionType,
+> narrow::array::union::TypeIdIndex<'_index>
+for FooBarArray<T, Buffer, OffsetItem, UnionLayout>
+where
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        0,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar<T> as narrow::array::union::EnumVariant<0>>::Data,
+    >,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        1,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar<T> as narrow::array::union::EnumVariant<1>>::Data,
+    >,
+    <<FooBar<
+        T,
+    > as narrow::array::union::EnumVariant<
+        2,
+    >>::Data as narrow::array::ArrayType<
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >>::Array<
+        Buffer,
+        OffsetItem,
+        UnionLayout,
+    >: narrow::IndexOwned<
+        '_index,
+        <FooBar<T> as narrow::array::union::EnumVariant<2>>::Data,
+    >,
+{
+    type Enum = FooBar<T>;
+    unsafe fn index_unchecked(
+        &'_index self,
+        type_id: ::std::primitive::i8,
+        index: usize,
+    ) -> Self::Enum {
+        match type_id {
+            0 => {
+                <FooBar<
+                    T,
+                > as narrow::array::union::EnumVariant<
+                    0,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.0, index))
+            }
+            1 => {
+                <FooBar<
+                    T,
+                > as narrow::array::union::EnumVariant<
+                    1,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.1, index))
+            }
+            2 => {
+                <FooBar<
+                    T,
+                > as narrow::array::union::EnumVariant<
+                    2,
+                >>::from_data(narrow::IndexOwned::index_owned_unchecked(&self.2, index))
+            }
+            _ => {
+                {
+                    ::core::panicking::panic_fmt(
+                        format_args!("type id greater than number of variants"),
+                    );
+                };
+            }
+        }
+    }
+}
 impl<T: Default + narrow::array::ArrayType> narrow::array::UnionArrayType<3>
 for FooBar<T> {
     type Array<
@@ -590,3 +748,8 @@ for FooBar<T> {
         OffsetItem,
     >;
 }
+impl<T: Default> narrow::IntoOwned<FooBar<T>> for FooBar<T> {
+    fn into_owned(self) -> FooBar<T> {
+        self
+    }
+}
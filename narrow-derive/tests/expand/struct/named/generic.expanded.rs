@@ -91,6 +91,23 @@ where
         self.a.len()
     }
 }
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Slice for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            a: narrow::Slice::slice_unchecked(&self.a, offset, len),
+        }
+    }
+}
 impl<
     'a,
     T: narrow::array::ArrayType<T>,
@@ -199,3 +216,80 @@ where
         }
     }
 }
+struct FooRef<_0> {
+    a: _0,
+}
+#[automatically_derived]
+impl<_0: ::core::clone::Clone> ::core::clone::Clone for FooRef<_0> {
+    #[inline]
+    fn clone(&self) -> FooRef<_0> {
+        FooRef {
+            a: ::core::clone::Clone::clone(&self.a),
+        }
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy> ::core::marker::Copy for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug> ::core::fmt::Debug for FooRef<_0> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field1_finish(f, "FooRef", "a", &&self.a)
+    }
+}
+#[automatically_derived]
+impl<_0> ::core::marker::StructuralPartialEq for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq> ::core::cmp::PartialEq for FooRef<_0> {
+    #[inline]
+    fn eq(&self, other: &FooRef<_0>) -> bool {
+        self.a == other.a
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq> ::core::cmp::Eq for FooRef<_0> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Index for FooArray<'a, T, Buffer>
+where
+    T: Copy,
+    <&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = FooRef<
+        <<&'a T as narrow::array::ArrayType<
+            &'a T,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        FooRef {
+            a: self.a.index_unchecked(index),
+        }
+    }
+}
+impl<'a, T, _0: narrow::IntoOwned<&'a T>> narrow::IntoOwned<Foo<'a, T>> for FooRef<_0>
+where
+    T: Copy,
+{
+    fn into_owned(self) -> Foo<'a, T> {
+        Foo {
+            a: narrow::IntoOwned::into_owned(self.a),
+        }
+    }
+}
@@ -123,6 +123,31 @@ where
         self.a.len()
     }
 }
+impl<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::BufferType> narrow::Slice
+for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <Option<
+        T,
+    > as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            a: narrow::Slice::slice_unchecked(&self.a, offset, len),
+            b: narrow::Slice::slice_unchecked(&self.b, offset, len),
+            c: narrow::Slice::slice_unchecked(&self.c, offset, len),
+        }
+    }
+}
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
@@ -341,3 +366,142 @@ where
         }
     }
 }
+struct BarRef<_0, _1, _2> {
+    a: _0,
+    b: _1,
+    c: _2,
+}
+#[automatically_derived]
+impl<
+    _0: ::core::clone::Clone,
+    _1: ::core::clone::Clone,
+    _2: ::core::clone::Clone,
+> ::core::clone::Clone for BarRef<_0, _1, _2> {
+    #[inline]
+    fn clone(&self) -> BarRef<_0, _1, _2> {
+        BarRef {
+            a: ::core::clone::Clone::clone(&self.a),
+            b: ::core::clone::Clone::clone(&self.b),
+            c: ::core::clone::Clone::clone(&self.c),
+        }
+    }
+}
+#[automatically_derived]
+impl<
+    _0: ::core::marker::Copy,
+    _1: ::core::marker::Copy,
+    _2: ::core::marker::Copy,
+> ::core::marker::Copy for BarRef<_0, _1, _2> {}
+#[automatically_derived]
+impl<
+    _0: ::core::fmt::Debug,
+    _1: ::core::fmt::Debug,
+    _2: ::core::fmt::Debug,
+> ::core::fmt::Debug for BarRef<_0, _1, _2> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "BarRef",
+            "a",
+            &self.a,
+            "b",
+            &self.b,
+            "c",
+            &&self.c,
+        )
+    }
+}
+#[automatically_derived]
+impl<_0, _1, _2> ::core::marker::StructuralPartialEq for BarRef<_0, _1, _2> {}
+#[automatically_derived]
+impl<
+    _0: ::core::cmp::PartialEq,
+    _1: ::core::cmp::PartialEq,
+    _2: ::core::cmp::PartialEq,
+> ::core::cmp::PartialEq for BarRef<_0, _1, _2> {
+    #[inline]
+    fn eq(&self, other: &BarRef<_0, _1, _2>) -> bool {
+        self.a == other.a && self.b == other.b && self.c == other.c
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq, _1: ::core::cmp::Eq, _2: ::core::cmp::Eq> ::core::cmp::Eq
+for BarRef<_0, _1, _2> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+        let _: ::core::cmp::AssertParamIsEq<_1>;
+        let _: ::core::cmp::AssertParamIsEq<_2>;
+    }
+}
+impl<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::BufferType> narrow::Index
+for BarArray<T, Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <Option<
+        bool,
+    > as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <Option<
+        T,
+    > as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = BarRef<
+        <<u32 as narrow::array::ArrayType<
+            u32,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<Option<
+            bool,
+        > as narrow::array::ArrayType<
+            bool,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<Option<
+            T,
+        > as narrow::array::ArrayType<
+            T,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        BarRef {
+            a: self.a.index_unchecked(index),
+            b: self.b.index_unchecked(index),
+            c: self.c.index_unchecked(index),
+        }
+    }
+}
+impl<
+    T,
+    _0: narrow::IntoOwned<u32>,
+    _1: narrow::IntoOwned<Option<bool>>,
+    _2: narrow::IntoOwned<Option<T>>,
+> narrow::IntoOwned<Bar<T>> for BarRef<_0, _1, _2> {
+    fn into_owned(self) -> Bar<T> {
+        Bar {
+            a: narrow::IntoOwned::into_owned(self.a),
+            b: narrow::IntoOwned::into_owned(self.b),
+            c: narrow::IntoOwned::into_owned(self.c),
+        }
+    }
+}
@@ -107,6 +107,28 @@ where
         self.a.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Slice for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <bool as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType<
+        Vec<u8>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            a: narrow::Slice::slice_unchecked(&self.a, offset, len),
+            b: narrow::Slice::slice_unchecked(&self.b, offset, len),
+            c: narrow::Slice::slice_unchecked(&self.c, offset, len),
+        }
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
@@ -302,3 +324,136 @@ where
         }
     }
 }
+struct FooRef<_0, _1, _2> {
+    a: _0,
+    b: _1,
+    c: _2,
+}
+#[automatically_derived]
+impl<
+    _0: ::core::clone::Clone,
+    _1: ::core::clone::Clone,
+    _2: ::core::clone::Clone,
+> ::core::clone::Clone for FooRef<_0, _1, _2> {
+    #[inline]
+    fn clone(&self) -> FooRef<_0, _1, _2> {
+        FooRef {
+            a: ::core::clone::Clone::clone(&self.a),
+            b: ::core::clone::Clone::clone(&self.b),
+            c: ::core::clone::Clone::clone(&self.c),
+        }
+    }
+}
+#[automatically_derived]
+impl<
+    _0: ::core::marker::Copy,
+    _1: ::core::marker::Copy,
+    _2: ::core::marker::Copy,
+> ::core::marker::Copy for FooRef<_0, _1, _2> {}
+#[automatically_derived]
+impl<
+    _0: ::core::fmt::Debug,
+    _1: ::core::fmt::Debug,
+    _2: ::core::fmt::Debug,
+> ::core::fmt::Debug for FooRef<_0, _1, _2> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_struct_field3_finish(
+            f,
+            "FooRef",
+            "a",
+            &self.a,
+            "b",
+            &self.b,
+            "c",
+            &&self.c,
+        )
+    }
+}
+#[automatically_derived]
+impl<_0, _1, _2> ::core::marker::StructuralPartialEq for FooRef<_0, _1, _2> {}
+#[automatically_derived]
+impl<
+    _0: ::core::cmp::PartialEq,
+    _1: ::core::cmp::PartialEq,
+    _2: ::core::cmp::PartialEq,
+> ::core::cmp::PartialEq for FooRef<_0, _1, _2> {
+    #[inline]
+    fn eq(&self, other: &FooRef<_0, _1, _2>) -> bool {
+        self.a == other.a && self.b == other.b && self.c == other.c
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq, _1: ::core::cmp::Eq, _2: ::core::cmp::Eq> ::core::cmp::Eq
+for FooRef<_0, _1, _2> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+        let _: ::core::cmp::AssertParamIsEq<_1>;
+        let _: ::core::cmp::AssertParamIsEq<_2>;
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::Index for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <bool as narrow::array::ArrayType<
+        bool,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <Option<
+        Vec<u8>,
+    > as narrow::array::ArrayType<
+        Vec<u8>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = FooRef<
+        <<u32 as narrow::array::ArrayType<
+            u32,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<bool as narrow::array::ArrayType<
+            bool,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<Option<
+            Vec<u8>,
+        > as narrow::array::ArrayType<
+            Vec<u8>,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        FooRef {
+            a: self.a.index_unchecked(index),
+            b: self.b.index_unchecked(index),
+            c: self.c.index_unchecked(index),
+        }
+    }
+}
+impl<
+    _0: narrow::IntoOwned<u32>,
+    _1: narrow::IntoOwned<bool>,
+    _2: narrow::IntoOwned<Option<Vec<u8>>>,
+> narrow::IntoOwned<Foo> for FooRef<_0, _1, _2> {
+    fn into_owned(self) -> Foo {
+        Foo {
+            a: narrow::IntoOwned::into_owned(self.a),
+            b: narrow::IntoOwned::into_owned(self.b),
+            c: narrow::IntoOwned::into_owned(self.c),
+        }
+    }
+}
@@ -42,6 +42,12 @@ for FooArray<N, Buffer> {
         self.0.len()
     }
 }
+impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::Slice
+for FooArray<N, Buffer> {
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<const N: usize, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo<N>>
 for FooArray<N, Buffer> {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo<N>>>(&mut self, iter: _I) {
@@ -80,3 +86,16 @@ for FooArray<N, Buffer> {
         FooArrayIter(self.0.into_iter())
     }
 }
+impl<const N: usize, Buffer: narrow::buffer::BufferType> narrow::Index
+for FooArray<N, Buffer> {
+    type Item<'_index> = <narrow::array::NullArray<
+        Foo<N>,
+        false,
+        Buffer,
+    > as narrow::Index>::Item<'_index>
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0.index_unchecked(index)
+    }
+}
@@ -64,6 +64,14 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Slice for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
 where
     Self: Debug,
@@ -106,3 +114,18 @@ where
         FooArrayIter(self.0.into_iter())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Index for FooArray<Buffer>
+where
+    Foo: Debug,
+{
+    type Item<'_index> = <narrow::array::NullArray<
+        Foo,
+        false,
+        Buffer,
+    > as narrow::Index>::Item<'_index>
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0.index_unchecked(index)
+    }
+}
@@ -39,6 +39,11 @@ impl<Buffer: narrow::buffer::BufferType> narrow::Length for FooArray<Buffer> {
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Slice for FooArray<Buffer> {
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer> {
     fn extend<_I: ::std::iter::IntoIterator<Item = Foo>>(&mut self, iter: _I) {
         self.0.extend(iter)
@@ -68,3 +73,15 @@ impl<Buffer: narrow::buffer::BufferType> ::std::iter::IntoIterator for FooArray<
         FooArrayIter(self.0.into_iter())
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Index for FooArray<Buffer> {
+    type Item<'_index> = <narrow::array::NullArray<
+        Foo,
+        false,
+        Buffer,
+    > as narrow::Index>::Item<'_index>
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0.index_unchecked(index)
+    }
+}
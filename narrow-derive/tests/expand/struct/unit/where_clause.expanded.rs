@@ -76,6 +76,16 @@ where
         self.0.len()
     }
 }
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::Slice
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<const N: bool, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo<N>>
 for FooArray<N, Buffer>
 where
@@ -134,3 +144,20 @@ where
         FooArrayIter(self.0.into_iter())
     }
 }
+impl<const N: bool, Buffer: narrow::buffer::BufferType> narrow::Index
+for FooArray<N, Buffer>
+where
+    Foo<N>: Sized,
+    (): From<Foo<N>>,
+{
+    type Item<'_index> = <narrow::array::NullArray<
+        Foo<N>,
+        false,
+        Buffer,
+    > as narrow::Index>::Item<'_index>
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0.index_unchecked(index)
+    }
+}
@@ -69,6 +69,20 @@ where
         self.0.len()
     }
 }
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Slice for FooArray<'a, T, Buffer>
+where
+    <&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<
     'a,
     T: narrow::array::ArrayType<T>,
@@ -169,3 +183,68 @@ where
         FooArrayIter(self.0.into_iter())
     }
 }
+struct FooRef<_0>(_0);
+#[automatically_derived]
+impl<_0: ::core::clone::Clone> ::core::clone::Clone for FooRef<_0> {
+    #[inline]
+    fn clone(&self) -> FooRef<_0> {
+        FooRef(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy> ::core::marker::Copy for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug> ::core::fmt::Debug for FooRef<_0> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "FooRef", &&self.0)
+    }
+}
+#[automatically_derived]
+impl<_0> ::core::marker::StructuralPartialEq for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq> ::core::cmp::PartialEq for FooRef<_0> {
+    #[inline]
+    fn eq(&self, other: &FooRef<_0>) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq> ::core::cmp::Eq for FooRef<_0> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Index for FooArray<'a, T, Buffer>
+where
+    <&'a T as narrow::array::ArrayType<
+        &'a T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = FooRef<
+        <<&'a T as narrow::array::ArrayType<
+            &'a T,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        FooRef(self.0.index_unchecked(index))
+    }
+}
+impl<'a, T, _0: narrow::IntoOwned<&'a T>> narrow::IntoOwned<Foo<'a, T>> for FooRef<_0> {
+    fn into_owned(self) -> Foo<'a, T> {
+        Foo(narrow::IntoOwned::into_owned(self.0))
+    }
+}
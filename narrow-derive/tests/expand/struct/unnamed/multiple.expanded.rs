@@ -108,6 +108,30 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Slice for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType<
+        u8,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <u16 as narrow::array::ArrayType<
+        u16,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <u64 as narrow::array::ArrayType<
+        u64,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(
+            narrow::Slice::slice_unchecked(&self.0, offset, len),
+            narrow::Slice::slice_unchecked(&self.1, offset, len),
+            narrow::Slice::slice_unchecked(&self.2, offset, len),
+            narrow::Slice::slice_unchecked(&self.3, offset, len),
+        )
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Bar> for BarArray<Buffer>
 where
     <u8 as narrow::array::ArrayType<
@@ -335,3 +359,149 @@ where
         )
     }
 }
+struct BarRef<_0, _1, _2, _3>(_0, _1, _2, _3);
+#[automatically_derived]
+impl<
+    _0: ::core::clone::Clone,
+    _1: ::core::clone::Clone,
+    _2: ::core::clone::Clone,
+    _3: ::core::clone::Clone,
+> ::core::clone::Clone for BarRef<_0, _1, _2, _3> {
+    #[inline]
+    fn clone(&self) -> BarRef<_0, _1, _2, _3> {
+        BarRef(
+            ::core::clone::Clone::clone(&self.0),
+            ::core::clone::Clone::clone(&self.1),
+            ::core::clone::Clone::clone(&self.2),
+            ::core::clone::Clone::clone(&self.3),
+        )
+    }
+}
+#[automatically_derived]
+impl<
+    _0: ::core::marker::Copy,
+    _1: ::core::marker::Copy,
+    _2: ::core::marker::Copy,
+    _3: ::core::marker::Copy,
+> ::core::marker::Copy for BarRef<_0, _1, _2, _3> {}
+#[automatically_derived]
+impl<
+    _0: ::core::fmt::Debug,
+    _1: ::core::fmt::Debug,
+    _2: ::core::fmt::Debug,
+    _3: ::core::fmt::Debug,
+> ::core::fmt::Debug for BarRef<_0, _1, _2, _3> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field4_finish(
+            f,
+            "BarRef",
+            &self.0,
+            &self.1,
+            &self.2,
+            &&self.3,
+        )
+    }
+}
+#[automatically_derived]
+impl<_0, _1, _2, _3> ::core::marker::StructuralPartialEq for BarRef<_0, _1, _2, _3> {}
+#[automatically_derived]
+impl<
+    _0: ::core::cmp::PartialEq,
+    _1: ::core::cmp::PartialEq,
+    _2: ::core::cmp::PartialEq,
+    _3: ::core::cmp::PartialEq,
+> ::core::cmp::PartialEq for BarRef<_0, _1, _2, _3> {
+    #[inline]
+    fn eq(&self, other: &BarRef<_0, _1, _2, _3>) -> bool {
+        self.0 == other.0 && self.1 == other.1 && self.2 == other.2 && self.3 == other.3
+    }
+}
+#[automatically_derived]
+impl<
+    _0: ::core::cmp::Eq,
+    _1: ::core::cmp::Eq,
+    _2: ::core::cmp::Eq,
+    _3: ::core::cmp::Eq,
+> ::core::cmp::Eq for BarRef<_0, _1, _2, _3> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+        let _: ::core::cmp::AssertParamIsEq<_1>;
+        let _: ::core::cmp::AssertParamIsEq<_2>;
+        let _: ::core::cmp::AssertParamIsEq<_3>;
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::Index for BarArray<Buffer>
+where
+    <u8 as narrow::array::ArrayType<
+        u8,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <u16 as narrow::array::ArrayType<
+        u16,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <u64 as narrow::array::ArrayType<
+        u64,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = BarRef<
+        <<u8 as narrow::array::ArrayType<
+            u8,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<u16 as narrow::array::ArrayType<
+            u16,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<u32 as narrow::array::ArrayType<
+            u32,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<u64 as narrow::array::ArrayType<
+            u64,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        BarRef(
+            self.0.index_unchecked(index),
+            self.1.index_unchecked(index),
+            self.2.index_unchecked(index),
+            self.3.index_unchecked(index),
+        )
+    }
+}
+impl<
+    _0: narrow::IntoOwned<u8>,
+    _1: narrow::IntoOwned<u16>,
+    _2: narrow::IntoOwned<u32>,
+    _3: narrow::IntoOwned<u64>,
+> narrow::IntoOwned<Bar> for BarRef<_0, _1, _2, _3> {
+    fn into_owned(self) -> Bar {
+        Bar(
+            narrow::IntoOwned::into_owned(self.0),
+            narrow::IntoOwned::into_owned(self.1),
+            narrow::IntoOwned::into_owned(self.2),
+            narrow::IntoOwned::into_owned(self.3),
+        )
+    }
+}
@@ -55,6 +55,16 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Slice for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Foo> for FooArray<Buffer>
 where
     <u32 as narrow::array::ArrayType<
@@ -136,6 +146,67 @@ where
         FooArrayIter(self.0.into_iter())
     }
 }
+struct FooRef<_0>(_0);
+#[automatically_derived]
+impl<_0: ::core::clone::Clone> ::core::clone::Clone for FooRef<_0> {
+    #[inline]
+    fn clone(&self) -> FooRef<_0> {
+        FooRef(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy> ::core::marker::Copy for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug> ::core::fmt::Debug for FooRef<_0> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "FooRef", &&self.0)
+    }
+}
+#[automatically_derived]
+impl<_0> ::core::marker::StructuralPartialEq for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq> ::core::cmp::PartialEq for FooRef<_0> {
+    #[inline]
+    fn eq(&self, other: &FooRef<_0>) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq> ::core::cmp::Eq for FooRef<_0> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::Index for FooArray<Buffer>
+where
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = FooRef<
+        <<u32 as narrow::array::ArrayType<
+            u32,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        FooRef(self.0.index_unchecked(index))
+    }
+}
+impl<_0: narrow::IntoOwned<u32>> narrow::IntoOwned<Foo> for FooRef<_0> {
+    fn into_owned(self) -> Foo {
+        Foo(narrow::IntoOwned::into_owned(self.0))
+    }
+}
 struct Bar(Foo);
 impl narrow::array::ArrayType<Bar> for Bar {
     type Array<
@@ -193,6 +264,16 @@ where
         self.0.len()
     }
 }
+impl<Buffer: narrow::buffer::BufferType> narrow::Slice for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType<
+        Foo,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<Buffer: narrow::buffer::BufferType> ::std::iter::Extend<Bar> for BarArray<Buffer>
 where
     <Foo as narrow::array::ArrayType<
@@ -274,3 +355,64 @@ where
         BarArrayIter(self.0.into_iter())
     }
 }
+struct BarRef<_0>(_0);
+#[automatically_derived]
+impl<_0: ::core::clone::Clone> ::core::clone::Clone for BarRef<_0> {
+    #[inline]
+    fn clone(&self) -> BarRef<_0> {
+        BarRef(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy> ::core::marker::Copy for BarRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug> ::core::fmt::Debug for BarRef<_0> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "BarRef", &&self.0)
+    }
+}
+#[automatically_derived]
+impl<_0> ::core::marker::StructuralPartialEq for BarRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq> ::core::cmp::PartialEq for BarRef<_0> {
+    #[inline]
+    fn eq(&self, other: &BarRef<_0>) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq> ::core::cmp::Eq for BarRef<_0> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+    }
+}
+impl<Buffer: narrow::buffer::BufferType> narrow::Index for BarArray<Buffer>
+where
+    <Foo as narrow::array::ArrayType<
+        Foo,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = BarRef<
+        <<Foo as narrow::array::ArrayType<
+            Foo,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        BarRef(self.0.index_unchecked(index))
+    }
+}
+impl<_0: narrow::IntoOwned<Foo>> narrow::IntoOwned<Bar> for BarRef<_0> {
+    fn into_owned(self) -> Bar {
+        Bar(narrow::IntoOwned::into_owned(self.0))
+    }
+}
@@ -81,6 +81,18 @@ where
         self.0.len()
     }
 }
+impl<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::BufferType> narrow::Slice
+for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<
     T: narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
@@ -178,6 +190,72 @@ where
         FooArrayIter(self.0.into_iter())
     }
 }
+struct FooRef<_0>(_0);
+#[automatically_derived]
+impl<_0: ::core::clone::Clone> ::core::clone::Clone for FooRef<_0> {
+    #[inline]
+    fn clone(&self) -> FooRef<_0> {
+        FooRef(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy> ::core::marker::Copy for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug> ::core::fmt::Debug for FooRef<_0> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "FooRef", &&self.0)
+    }
+}
+#[automatically_derived]
+impl<_0> ::core::marker::StructuralPartialEq for FooRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq> ::core::cmp::PartialEq for FooRef<_0> {
+    #[inline]
+    fn eq(&self, other: &FooRef<_0>) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq> ::core::cmp::Eq for FooRef<_0> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+    }
+}
+impl<T: narrow::array::ArrayType<T>, Buffer: narrow::buffer::BufferType> narrow::Index
+for FooArray<T, Buffer>
+where
+    T: Copy,
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = FooRef<
+        <<T as narrow::array::ArrayType<
+            T,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        FooRef(self.0.index_unchecked(index))
+    }
+}
+impl<T, _0: narrow::IntoOwned<T>> narrow::IntoOwned<Foo<T>> for FooRef<_0>
+where
+    T: Copy,
+{
+    fn into_owned(self) -> Foo<T> {
+        Foo(narrow::IntoOwned::into_owned(self.0))
+    }
+}
 struct Bar<'a, T>(&'a Foo<T>);
 impl<'a, T: narrow::array::ArrayType<T>> narrow::array::ArrayType<Bar<'a, T>>
 for Bar<'a, T> {
@@ -257,6 +335,22 @@ where
         self.0.len()
     }
 }
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Slice for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType<
+        &'a Foo<T>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<
     'a,
     T: narrow::array::ArrayType<T>,
@@ -369,6 +463,76 @@ where
         BarArrayIter(self.0.into_iter())
     }
 }
+struct BarRef<_0>(_0);
+#[automatically_derived]
+impl<_0: ::core::clone::Clone> ::core::clone::Clone for BarRef<_0> {
+    #[inline]
+    fn clone(&self) -> BarRef<_0> {
+        BarRef(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy> ::core::marker::Copy for BarRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug> ::core::fmt::Debug for BarRef<_0> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "BarRef", &&self.0)
+    }
+}
+#[automatically_derived]
+impl<_0> ::core::marker::StructuralPartialEq for BarRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq> ::core::cmp::PartialEq for BarRef<_0> {
+    #[inline]
+    fn eq(&self, other: &BarRef<_0>) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq> ::core::cmp::Eq for BarRef<_0> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+    }
+}
+impl<
+    'a,
+    T: narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Index for BarArray<'a, T, Buffer>
+where
+    <&'a Foo<
+        T,
+    > as narrow::array::ArrayType<
+        &'a Foo<T>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = BarRef<
+        <<&'a Foo<
+            T,
+        > as narrow::array::ArrayType<
+            &'a Foo<T>,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        BarRef(self.0.index_unchecked(index))
+    }
+}
+impl<'a, T, _0: narrow::IntoOwned<&'a Foo<T>>> narrow::IntoOwned<Bar<'a, T>>
+for BarRef<_0> {
+    fn into_owned(self) -> Bar<'a, T> {
+        Bar(narrow::IntoOwned::into_owned(self.0))
+    }
+}
 struct FooBar<'a>(Bar<'a, u32>);
 impl<'a> narrow::array::ArrayType<FooBar<'a>> for FooBar<'a> {
     type Array<
@@ -440,6 +604,19 @@ where
         self.0.len()
     }
 }
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::Slice for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType<
+        Bar<'a, u32>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(narrow::Slice::slice_unchecked(&self.0, offset, len))
+    }
+}
 impl<'a, Buffer: narrow::buffer::BufferType> ::std::iter::Extend<FooBar<'a>>
 for FooBarArray<'a, Buffer>
 where
@@ -542,3 +719,71 @@ where
         FooBarArrayIter(self.0.into_iter())
     }
 }
+struct FooBarRef<_0>(_0);
+#[automatically_derived]
+impl<_0: ::core::clone::Clone> ::core::clone::Clone for FooBarRef<_0> {
+    #[inline]
+    fn clone(&self) -> FooBarRef<_0> {
+        FooBarRef(::core::clone::Clone::clone(&self.0))
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy> ::core::marker::Copy for FooBarRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug> ::core::fmt::Debug for FooBarRef<_0> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field1_finish(f, "FooBarRef", &&self.0)
+    }
+}
+#[automatically_derived]
+impl<_0> ::core::marker::StructuralPartialEq for FooBarRef<_0> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq> ::core::cmp::PartialEq for FooBarRef<_0> {
+    #[inline]
+    fn eq(&self, other: &FooBarRef<_0>) -> bool {
+        self.0 == other.0
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq> ::core::cmp::Eq for FooBarRef<_0> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+    }
+}
+impl<'a, Buffer: narrow::buffer::BufferType> narrow::Index for FooBarArray<'a, Buffer>
+where
+    <Bar<
+        'a,
+        u32,
+    > as narrow::array::ArrayType<
+        Bar<'a, u32>,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = FooBarRef<
+        <<Bar<
+            'a,
+            u32,
+        > as narrow::array::ArrayType<
+            Bar<'a, u32>,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        FooBarRef(self.0.index_unchecked(index))
+    }
+}
+impl<'a, _0: narrow::IntoOwned<Bar<'a, u32>>> narrow::IntoOwned<FooBar<'a>>
+for FooBarRef<_0> {
+    fn into_owned(self) -> FooBar<'a> {
+        FooBar(narrow::IntoOwned::into_owned(self.0))
+    }
+}
@@ -85,6 +85,25 @@ where
         self.0.len()
     }
 }
+impl<
+    T: Sized + narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Slice for FooArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(
+            narrow::Slice::slice_unchecked(&self.0, offset, len),
+            narrow::Slice::slice_unchecked(&self.1, offset, len),
+        )
+    }
+}
 impl<
     T: Sized + narrow::array::ArrayType<T>,
     Buffer: narrow::buffer::BufferType,
@@ -223,3 +242,89 @@ where
         FooArrayIter(self.0.into_iter(), self.1.into_iter())
     }
 }
+struct FooRef<_0, _1>(_0, _1);
+#[automatically_derived]
+impl<_0: ::core::clone::Clone, _1: ::core::clone::Clone> ::core::clone::Clone
+for FooRef<_0, _1> {
+    #[inline]
+    fn clone(&self) -> FooRef<_0, _1> {
+        FooRef(
+            ::core::clone::Clone::clone(&self.0),
+            ::core::clone::Clone::clone(&self.1),
+        )
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::marker::Copy, _1: ::core::marker::Copy> ::core::marker::Copy
+for FooRef<_0, _1> {}
+#[automatically_derived]
+impl<_0: ::core::fmt::Debug, _1: ::core::fmt::Debug> ::core::fmt::Debug
+for FooRef<_0, _1> {
+    #[inline]
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        ::core::fmt::Formatter::debug_tuple_field2_finish(f, "FooRef", &self.0, &&self.1)
+    }
+}
+#[automatically_derived]
+impl<_0, _1> ::core::marker::StructuralPartialEq for FooRef<_0, _1> {}
+#[automatically_derived]
+impl<_0: ::core::cmp::PartialEq, _1: ::core::cmp::PartialEq> ::core::cmp::PartialEq
+for FooRef<_0, _1> {
+    #[inline]
+    fn eq(&self, other: &FooRef<_0, _1>) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+#[automatically_derived]
+impl<_0: ::core::cmp::Eq, _1: ::core::cmp::Eq> ::core::cmp::Eq for FooRef<_0, _1> {
+    #[inline]
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<_0>;
+        let _: ::core::cmp::AssertParamIsEq<_1>;
+    }
+}
+impl<
+    T: Sized + narrow::array::ArrayType<T>,
+    Buffer: narrow::buffer::BufferType,
+> narrow::Index for FooArray<T, Buffer>
+where
+    <T as narrow::array::ArrayType<
+        T,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+    <u32 as narrow::array::ArrayType<
+        u32,
+    >>::Array<Buffer, narrow::offset::NA, narrow::array::union::NA>: narrow::Index,
+{
+    type Item<'_index> = FooRef<
+        <<T as narrow::array::ArrayType<
+            T,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+        <<u32 as narrow::array::ArrayType<
+            u32,
+        >>::Array<
+            Buffer,
+            narrow::offset::NA,
+            narrow::array::union::NA,
+        > as narrow::Index>::Item<'_index>,
+    >
+    where
+        Self: '_index;
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        FooRef(self.0.index_unchecked(index), self.1.index_unchecked(index))
+    }
+}
+impl<
+    T: Sized,
+    _0: narrow::IntoOwned<T>,
+    _1: narrow::IntoOwned<u32>,
+> narrow::IntoOwned<Foo<T>> for FooRef<_0, _1> {
+    fn into_owned(self) -> Foo<T> {
+        Foo(narrow::IntoOwned::into_owned(self.0), narrow::IntoOwned::into_owned(self.1))
+    }
+}
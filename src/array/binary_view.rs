@@ -0,0 +1,353 @@
+//! Array with variable-size binary values, stored as views.
+
+use super::{Array, FixedSizePrimitiveArray};
+use crate::{
+    bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
+    buffer::{Buffer as _, BufferType, VecBuffer},
+    validity::{Nullability, Validity},
+    Index, IndexOwned, Length, Slice,
+};
+
+/// The size of a view in bytes.
+const VIEW_LEN: usize = 16;
+
+/// The maximum length of values that are stored inline in their view.
+const MAX_INLINE_LEN: usize = 12;
+
+/// Array with variable-size binary values, stored as views.
+///
+/// Every value is described by a 16-byte view. Values of at most 12 bytes are
+/// stored inline in their view, larger values are stored in one of the data
+/// buffers, with their length, prefix, buffer index and offset stored in the
+/// view. The views use the memory layout of the Arrow format.
+pub struct BinaryViewArray<const NULLABLE: bool = false, Buffer: BufferType = VecBuffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+{
+    /// The views of the values.
+    pub views: FixedSizePrimitiveArray<i128, NULLABLE, Buffer>,
+
+    /// The data buffers with values that are not stored inline.
+    pub buffers: Vec<FixedSizePrimitiveArray<u8, false, Buffer>>,
+}
+
+/// Returns the view for the given value, appending the value to the last
+/// data buffer if it can't be stored inline.
+pub(crate) fn view<Buffer: BufferType>(
+    value: &[u8],
+    buffers: &mut Vec<FixedSizePrimitiveArray<u8, false, Buffer>>,
+) -> i128
+where
+    FixedSizePrimitiveArray<u8, false, Buffer>: Default + Extend<u8> + Length,
+{
+    let len = u32::try_from(value.len()).expect("view length overflow");
+    let mut view = [0; VIEW_LEN];
+    view[..4].copy_from_slice(&len.to_le_bytes());
+    if value.len() <= MAX_INLINE_LEN {
+        view[4..4 + value.len()].copy_from_slice(value);
+    } else {
+        // Buffer offsets are stored as `u32`.
+        if buffers.last().map_or(true, |buffer| {
+            u32::try_from(buffer.len() + value.len()).is_err()
+        }) {
+            buffers.push(FixedSizePrimitiveArray::default());
+        }
+        let buffer_index = u32::try_from(buffers.len() - 1).expect("buffer index overflow");
+        let buffer = buffers.last_mut().expect("a data buffer");
+        let offset = u32::try_from(buffer.len()).expect("buffer offset overflow");
+        buffer.extend(value.iter().copied());
+        view[4..8].copy_from_slice(&value[..4]);
+        view[8..12].copy_from_slice(&buffer_index.to_le_bytes());
+        view[12..].copy_from_slice(&offset.to_le_bytes());
+    }
+    i128::from_ne_bytes(view)
+}
+
+/// Returns the `u32` field of a view that starts at `start`.
+fn field(view: &[u8], start: usize) -> usize {
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(&view[start..start + 4]);
+    usize::try_from(u32::from_le_bytes(bytes)).expect("convert fail")
+}
+
+/// Returns the value of the view at `index` in the given view bytes.
+fn value<'a, Buffer: BufferType>(
+    views: &'a [u8],
+    buffers: &'a [FixedSizePrimitiveArray<u8, false, Buffer>],
+    index: usize,
+) -> &'a [u8] {
+    let view = &views[index * VIEW_LEN..(index + 1) * VIEW_LEN];
+    let len = field(view, 0);
+    if len <= MAX_INLINE_LEN {
+        &view[4..4 + len]
+    } else {
+        let offset = field(view, 12);
+        &buffers[field(view, 8)].as_ref()[offset..offset + len]
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Array for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    Vec<u8>: Nullability<NULLABLE>,
+{
+    type Item = <Vec<u8> as Nullability<NULLABLE>>::Item;
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Clone for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<i128, NULLABLE, Buffer>: Clone,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            views: self.views.clone(),
+            buffers: self.buffers.clone(),
+        }
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Default for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<i128, NULLABLE, Buffer>: Default,
+{
+    fn default() -> Self {
+        Self {
+            views: FixedSizePrimitiveArray::default(),
+            buffers: Vec::new(),
+        }
+    }
+}
+
+impl<T: AsRef<[u8]>, Buffer: BufferType> Extend<T> for BinaryViewArray<false, Buffer>
+where
+    FixedSizePrimitiveArray<i128, false, Buffer>: Extend<i128>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Default + Extend<u8> + Length,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let Self {
+            ref mut views,
+            ref mut buffers,
+        } = *self;
+        views.extend(iter.into_iter().map(|item| view(item.as_ref(), buffers)));
+    }
+}
+
+impl<T: AsRef<[u8]>, Buffer: BufferType> Extend<Option<T>> for BinaryViewArray<true, Buffer>
+where
+    FixedSizePrimitiveArray<i128, true, Buffer>: Extend<Option<i128>>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Default + Extend<u8> + Length,
+{
+    fn extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) {
+        let Self {
+            ref mut views,
+            ref mut buffers,
+        } = *self;
+        views.extend(
+            iter.into_iter()
+                .map(|opt| opt.map(|item| view(item.as_ref(), buffers))),
+        );
+    }
+}
+
+impl<Buffer: BufferType> From<BinaryViewArray<false, Buffer>> for BinaryViewArray<true, Buffer>
+where
+    FixedSizePrimitiveArray<i128, false, Buffer>: Into<FixedSizePrimitiveArray<i128, true, Buffer>>,
+{
+    fn from(value: BinaryViewArray<false, Buffer>) -> Self {
+        Self {
+            views: value.views.into(),
+            buffers: value.buffers,
+        }
+    }
+}
+
+impl<T, const NULLABLE: bool, Buffer: BufferType> FromIterator<T>
+    for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    Self: Default + Extend<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<Buffer: BufferType> Index for BinaryViewArray<false, Buffer> {
+    type Item<'a>
+        = &'a [u8]
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        value(self.views.0.as_bytes(), &self.buffers, index)
+    }
+}
+
+impl<Buffer: BufferType> Index for BinaryViewArray<true, Buffer> {
+    type Item<'a>
+        = Option<&'a [u8]>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.views
+            .is_valid_unchecked(index)
+            .then(|| value(self.views.0.data.as_bytes(), &self.buffers, index))
+    }
+}
+
+/// An iterator over the items in a [`BinaryViewArray`].
+pub struct BinaryViewIntoIter<const NULLABLE: bool, Buffer: BufferType>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+{
+    /// The array.
+    array: BinaryViewArray<NULLABLE, Buffer>,
+    /// The index of the next item.
+    index: usize,
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Iterator for BinaryViewIntoIter<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    BinaryViewArray<NULLABLE, Buffer>: Array + Length,
+    for<'a> BinaryViewArray<NULLABLE, Buffer>:
+        IndexOwned<'a, <BinaryViewArray<NULLABLE, Buffer> as Array>::Item>,
+{
+    type Item = <BinaryViewArray<NULLABLE, Buffer> as Array>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.index < self.array.len()).then(|| {
+            self.index += 1;
+            // Safety:
+            // - Bounds checked above.
+            unsafe { self.array.index_owned_unchecked(self.index - 1) }
+        })
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> IntoIterator for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    BinaryViewIntoIter<NULLABLE, Buffer>: Iterator,
+{
+    type Item = <BinaryViewIntoIter<NULLABLE, Buffer> as Iterator>::Item;
+    type IntoIter = BinaryViewIntoIter<NULLABLE, Buffer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        BinaryViewIntoIter {
+            array: self,
+            index: 0,
+        }
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Length for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<i128, NULLABLE, Buffer>: Length,
+{
+    fn len(&self) -> usize {
+        self.views.len()
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Slice for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<i128> as Validity<NULLABLE>>::Storage<Buffer>: Slice,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Clone,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            views: FixedSizePrimitiveArray(self.views.0.slice_unchecked(offset, len)),
+            buffers: self.buffers.clone(),
+        }
+    }
+}
+
+impl<Buffer: BufferType> BitmapRef for BinaryViewArray<true, Buffer> {
+    type Buffer = Buffer;
+
+    fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+        self.views.bitmap_ref()
+    }
+}
+
+impl<Buffer: BufferType> BitmapRefMut for BinaryViewArray<true, Buffer> {
+    fn bitmap_ref_mut(&mut self) -> &mut Bitmap<Self::Buffer> {
+        self.views.bitmap_ref_mut()
+    }
+}
+
+impl<Buffer: BufferType> ValidityBitmap for BinaryViewArray<true, Buffer> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::ArcSliceBuffer;
+
+    #[test]
+    fn from_iter() {
+        let input: [&[u8]; 3] = [b"short", b"", b"a value that is not inlined"];
+        let array = input.into_iter().collect::<BinaryViewArray>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.buffers.len(), 1);
+        assert_eq!(array.buffers[0].as_ref(), input[2]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn from_iter_nullable() {
+        let input = [
+            Some(b"a value that is not inlined".to_vec()),
+            None,
+            Some(vec![1]),
+        ];
+        let array = input.clone().into_iter().collect::<BinaryViewArray<true>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.is_valid(0), Some(true));
+        assert_eq!(array.is_null(1), Some(true));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn extend() {
+        let mut array = ["a value that is not inlined"]
+            .into_iter()
+            .collect::<BinaryViewArray>();
+        array.extend(["another value that is not inlined", "inlined"]);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.buffers.len(), 1);
+        assert_eq!(array.index_checked(1), b"another value that is not inlined");
+        assert_eq!(array.index_checked(2), b"inlined");
+    }
+
+    #[test]
+    fn index() {
+        let array = [Some("twelve bytes"), None, Some("thirteen byte")]
+            .into_iter()
+            .collect::<BinaryViewArray<true>>();
+        assert_eq!(array.index_checked(0), Some(&b"twelve bytes"[..]));
+        assert_eq!(array.index_checked(1), None);
+        assert_eq!(array.index_checked(2), Some(&b"thirteen byte"[..]));
+        assert_eq!(array.index(3), None);
+        assert_eq!(array.buffers[0].as_ref(), b"thirteen byte");
+    }
+
+    #[test]
+    fn slice() {
+        let array = ["a", "a value that is not inlined", "c"]
+            .into_iter()
+            .collect::<BinaryViewArray<false, ArcSliceBuffer>>();
+        let slice = array.slice(1..);
+        assert_eq!(slice.len(), 2);
+        assert_eq!(slice.index_checked(0), b"a value that is not inlined");
+        assert_eq!(slice.index_checked(1), b"c");
+    }
+}
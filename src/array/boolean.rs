@@ -6,7 +6,7 @@ use crate::{
     buffer::{BufferRef, BufferRefMut, BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, Slice,
 };
 use std::fmt::{Debug, Formatter, Result};
 
@@ -131,7 +131,8 @@ where
     Bitmap<Buffer>: Validity<NULLABLE>,
     <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Index,
 {
-    type Item<'a> = <<Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
+    type Item<'a>
+        = <<Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
     where
         Self: 'a;
 
@@ -180,6 +181,16 @@ where
     }
 }
 
+impl<const NULLABLE: bool, Buffer: BufferType> Slice for BooleanArray<NULLABLE, Buffer>
+where
+    Bitmap<Buffer>: Validity<NULLABLE>,
+    <Bitmap<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<Buffer: BufferType> BitmapRef for BooleanArray<true, Buffer> {
     type Buffer = Buffer;
 
@@ -217,7 +228,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::buffer::BoxBuffer;
+    use crate::buffer::{ArcSliceBuffer, BoxBuffer};
     use std::mem;
 
     #[test]
@@ -311,6 +322,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn slice() {
+        let array = [true, false, false, true, true, false, true, true, false]
+            .into_iter()
+            .collect::<BooleanArray<false, ArcSliceBuffer>>();
+        let sliced = array.slice(6..);
+        assert_eq!(sliced.len(), 3);
+        assert_eq!(sliced.iter().collect::<Vec<_>>(), [true, true, false]);
+
+        let nullable = [Some(true), None, Some(false), Some(true)]
+            .into_iter()
+            .collect::<BooleanArray<true, ArcSliceBuffer>>();
+        let sliced_nullable = nullable.slice(1..3);
+        assert_eq!(sliced_nullable.index_checked(0), None);
+        assert_eq!(sliced_nullable.index_checked(1), Some(false));
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(mem::size_of::<BooleanArray>(), mem::size_of::<Bitmap>());
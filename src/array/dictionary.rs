@@ -0,0 +1,489 @@
+//! Array with dictionary-encoded values.
+
+use super::{Array, FixedSizePrimitiveArray};
+use crate::{
+    bitmap::{Bitmap, BitmapRef, ValidityBitmap},
+    buffer::{Buffer as _, BufferType, VecBuffer},
+    validity::{Nullability, Validity},
+    FixedSize, Index, IndexOwned, Length, Slice,
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Types that can be used as keys of a [`DictionaryArray`].
+pub trait DictionaryKey: FixedSize {
+    /// Returns the key for the value at `index` in the values array, or
+    /// `None` if `index` can't be represented by this key type.
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// Returns the index of the value in the values array for this key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this key is negative. Keys of arrays converted from Arrow
+    /// arrays are checked during the conversion.
+    fn to_index(self) -> usize;
+}
+
+/// Implement [`DictionaryKey`] for the given integer types.
+macro_rules! impl_dictionary_key {
+    ($($ty:ty),+) => {
+        $(
+            impl DictionaryKey for $ty {
+                fn from_index(index: usize) -> Option<Self> {
+                    Self::try_from(index).ok()
+                }
+
+                fn to_index(self) -> usize {
+                    usize::try_from(self).expect("negative dictionary key")
+                }
+            }
+        )+
+    };
+}
+
+impl_dictionary_key!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Array with dictionary-encoded values.
+///
+/// The `keys` of a dictionary array are indices into the `values` array.
+/// Extending a dictionary array only appends values to the `values` array
+/// that are not in it yet.
+pub struct DictionaryArray<
+    K: DictionaryKey,
+    V: Array,
+    const NULLABLE: bool = false,
+    Buffer: BufferType = VecBuffer,
+> where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+{
+    /// The keys of the items in this array.
+    pub keys: FixedSizePrimitiveArray<K, NULLABLE, Buffer>,
+
+    /// The distinct values of the items in this array.
+    pub values: V,
+
+    /// Maps values to their keys, used to deduplicate values when extending
+    /// this array.
+    lookup: Lookup<<V as Array>::Item, K>,
+}
+
+impl<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType>
+    DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+{
+    /// Constructs a [`DictionaryArray`] from `keys` and `values`.
+    ///
+    /// The values are added to the lookup used to deduplicate values on the
+    /// first call to `extend`, so extending the array does not append values
+    /// that are already in `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the values array is too large for the key type.
+    pub fn new(keys: FixedSizePrimitiveArray<K, NULLABLE, Buffer>, values: V) -> Self
+    where
+        V: Length,
+    {
+        assert!(
+            values
+                .len()
+                .checked_sub(1)
+                .map_or(true, |last| K::from_index(last).is_some()),
+            "dictionary key overflow"
+        );
+        Self {
+            keys,
+            values,
+            lookup: Lookup::default(),
+        }
+    }
+}
+
+impl<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType> Array
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    <V as Array>::Item: Nullability<NULLABLE>,
+{
+    type Item = <<V as Array>::Item as Nullability<NULLABLE>>::Item;
+}
+
+impl<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType> Clone
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>: Clone,
+    V: Clone,
+    <V as Array>::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            values: self.values.clone(),
+            lookup: self.lookup.clone(),
+        }
+    }
+}
+
+impl<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType> Default
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>: Default,
+    V: Default,
+{
+    fn default() -> Self {
+        Self {
+            keys: FixedSizePrimitiveArray::default(),
+            values: V::default(),
+            lookup: Lookup::default(),
+        }
+    }
+}
+
+/// Maps values to their keys, used to deduplicate values when extending a
+/// [`DictionaryArray`].
+struct Lookup<T, K> {
+    /// The keys of the values.
+    keys: HashMap<T, K>,
+    /// The number of values in the values array that are in `keys`.
+    len: usize,
+}
+
+impl<T, K> Default for Lookup<T, K> {
+    fn default() -> Self {
+        Self {
+            keys: HashMap::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: Clone, K: Clone> Clone for Lookup<T, K> {
+    fn clone(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash, K: DictionaryKey> Lookup<T, K> {
+    /// Returns the key of `value`, appending it to `values` when it is not in
+    /// there yet.
+    ///
+    /// Values that were added to `values` since the last call are added to
+    /// the lookup first.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the number of distinct values exceeds the key type.
+    fn key<V>(&mut self, values: &mut V, value: T) -> K
+    where
+        V: Extend<T> + Length,
+        for<'a> V: IndexOwned<'a, T>,
+    {
+        for index in self.len..values.len() {
+            self.keys
+                // Safety:
+                // - The index is in bounds of the values array.
+                .entry(unsafe { values.index_owned_unchecked(index) })
+                .or_insert_with(|| K::from_index(index).expect("dictionary key overflow"));
+        }
+        let key = *self.keys.entry(value).or_insert_with_key(|item| {
+            let key = K::from_index(values.len()).expect("dictionary key overflow");
+            values.extend(std::iter::once(item.clone()));
+            key
+        });
+        self.len = values.len();
+        key
+    }
+}
+
+impl<K: DictionaryKey, V: Array, Buffer: BufferType> Extend<<V as Array>::Item>
+    for DictionaryArray<K, V, false, Buffer>
+where
+    FixedSizePrimitiveArray<K, false, Buffer>: Extend<K>,
+    V: Extend<<V as Array>::Item> + Length,
+    for<'a> V: IndexOwned<'a, <V as Array>::Item>,
+    <V as Array>::Item: Clone + Eq + Hash,
+{
+    fn extend<I: IntoIterator<Item = <V as Array>::Item>>(&mut self, iter: I) {
+        let Self {
+            ref mut keys,
+            ref mut values,
+            ref mut lookup,
+        } = *self;
+        keys.extend(iter.into_iter().map(|value| lookup.key(values, value)));
+    }
+}
+
+impl<K: DictionaryKey, V: Array, Buffer: BufferType> Extend<Option<<V as Array>::Item>>
+    for DictionaryArray<K, V, true, Buffer>
+where
+    FixedSizePrimitiveArray<K, true, Buffer>: Extend<Option<K>>,
+    V: Extend<<V as Array>::Item> + Length,
+    for<'a> V: IndexOwned<'a, <V as Array>::Item>,
+    <V as Array>::Item: Clone + Eq + Hash,
+{
+    fn extend<I: IntoIterator<Item = Option<<V as Array>::Item>>>(&mut self, iter: I) {
+        let Self {
+            ref mut keys,
+            ref mut values,
+            ref mut lookup,
+        } = *self;
+        keys.extend(
+            iter.into_iter()
+                .map(|opt| opt.map(|value| lookup.key(values, value))),
+        );
+    }
+}
+
+impl<K: DictionaryKey, V: Array, T, const NULLABLE: bool, Buffer: BufferType> FromIterator<T>
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    Self: Default + Extend<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<K: DictionaryKey, V: Array + Index, Buffer: BufferType> Index
+    for DictionaryArray<K, V, false, Buffer>
+where
+    FixedSizePrimitiveArray<K, false, Buffer>: Length,
+{
+    type Item<'a>
+        = <V as Index>::Item<'a>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.values
+            .index_checked(self.keys.as_ref().get_unchecked(index).to_index())
+    }
+}
+
+impl<K: DictionaryKey, V: Array + Index, Buffer: BufferType> Index
+    for DictionaryArray<K, V, true, Buffer>
+where
+    FixedSizePrimitiveArray<K, true, Buffer>: Length,
+{
+    type Item<'a>
+        = Option<<V as Index>::Item<'a>>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.keys.is_valid_unchecked(index).then(|| {
+            self.values
+                .index_checked(self.keys.0.data.as_slice().get_unchecked(index).to_index())
+        })
+    }
+}
+
+/// An iterator over the items in a [`DictionaryArray`].
+pub struct DictionaryIntoIter<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>: IntoIterator,
+{
+    /// Iterator over the keys.
+    keys: <FixedSizePrimitiveArray<K, NULLABLE, Buffer> as IntoIterator>::IntoIter,
+    /// The values the keys refer to.
+    values: V,
+}
+
+impl<K: DictionaryKey, V: Array + Length, Buffer: BufferType> Iterator
+    for DictionaryIntoIter<K, V, false, Buffer>
+where
+    FixedSizePrimitiveArray<K, false, Buffer>: IntoIterator<Item = K>,
+    for<'a> V: IndexOwned<'a, <V as Array>::Item>,
+{
+    type Item = <V as Array>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys.next().map(|key| {
+            let index = key.to_index();
+            assert!(index < self.values.len(), "dictionary key out of bounds");
+            // Safety:
+            // - Bounds checked above.
+            unsafe { self.values.index_owned_unchecked(index) }
+        })
+    }
+}
+
+impl<K: DictionaryKey, V: Array + Length, Buffer: BufferType> Iterator
+    for DictionaryIntoIter<K, V, true, Buffer>
+where
+    FixedSizePrimitiveArray<K, true, Buffer>: IntoIterator<Item = Option<K>>,
+    for<'a> V: IndexOwned<'a, <V as Array>::Item>,
+{
+    type Item = Option<<V as Array>::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys.next().map(|opt| {
+            opt.map(|key| {
+                let index = key.to_index();
+                assert!(index < self.values.len(), "dictionary key out of bounds");
+                // Safety:
+                // - Bounds checked above.
+                unsafe { self.values.index_owned_unchecked(index) }
+            })
+        })
+    }
+}
+
+impl<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType> IntoIterator
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>: IntoIterator,
+    DictionaryIntoIter<K, V, NULLABLE, Buffer>: Iterator,
+{
+    type Item = <DictionaryIntoIter<K, V, NULLABLE, Buffer> as Iterator>::Item;
+    type IntoIter = DictionaryIntoIter<K, V, NULLABLE, Buffer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DictionaryIntoIter {
+            keys: self.keys.into_iter(),
+            values: self.values,
+        }
+    }
+}
+
+impl<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType> Length
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>: Length,
+{
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+}
+
+impl<K: DictionaryKey, V: Array, const NULLABLE: bool, Buffer: BufferType> Slice
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>: Slice,
+    V: Clone,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            keys: self.keys.slice_unchecked(offset, len),
+            values: self.values.clone(),
+            lookup: Lookup::default(),
+        }
+    }
+}
+
+impl<K: DictionaryKey, V: Array, Buffer: BufferType> BitmapRef
+    for DictionaryArray<K, V, true, Buffer>
+{
+    type Buffer = Buffer;
+
+    fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+        self.keys.bitmap_ref()
+    }
+}
+
+impl<K: DictionaryKey, V: Array, Buffer: BufferType> ValidityBitmap
+    for DictionaryArray<K, V, true, Buffer>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::StringArray,
+        buffer::{ArcSliceBuffer, BufferRef},
+    };
+
+    #[test]
+    fn from_iter() {
+        let input = ["NL", "US", "NL", "NL", "DE", "US"].map(ToOwned::to_owned);
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<DictionaryArray<u8, StringArray>>();
+        assert_eq!(array.len(), 6);
+        assert_eq!(array.keys.0.as_slice(), [0, 1, 0, 0, 2, 1]);
+        assert_eq!(array.values.len(), 3);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn from_iter_nullable() {
+        let input = [Some("a"), None, Some("b"), Some("a"), None].map(|x| x.map(ToOwned::to_owned));
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<DictionaryArray<i32, StringArray, true>>();
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.values.len(), 2);
+        assert_eq!(array.is_null(1), Some(true));
+        assert_eq!(
+            array.keys.bitmap_ref().buffer_ref().as_slice(),
+            &[0b0000_1101]
+        );
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn extend() {
+        let mut array = ["a", "b"]
+            .map(ToOwned::to_owned)
+            .into_iter()
+            .collect::<DictionaryArray<u8, StringArray>>();
+        array.extend(["b", "c", "a"].map(ToOwned::to_owned));
+        assert_eq!(array.keys.0.as_slice(), [0, 1, 1, 2, 0]);
+        assert_eq!(array.values.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "dictionary key overflow")]
+    fn key_overflow() {
+        let _: DictionaryArray<i8, StringArray> = (0..=128).map(|x| x.to_string()).collect();
+    }
+
+    #[test]
+    fn index() {
+        let array = ["a", "b", "a"]
+            .map(ToOwned::to_owned)
+            .into_iter()
+            .collect::<DictionaryArray<u8, StringArray>>();
+        assert_eq!(array.index_checked(0), "a");
+        assert_eq!(array.index_checked(1), "b");
+        assert_eq!(array.index_checked(2), "a");
+        assert_eq!(array.index(3), None);
+
+        let nullable = [Some("a"), None]
+            .map(|x| x.map(ToOwned::to_owned))
+            .into_iter()
+            .collect::<DictionaryArray<u8, StringArray, true>>();
+        assert_eq!(nullable.index_checked(0), Some("a"));
+        assert_eq!(nullable.index_checked(1), None);
+    }
+
+    #[test]
+    fn slice() {
+        let array = ["a", "b", "c", "a"]
+            .map(ToOwned::to_owned)
+            .into_iter()
+            .collect::<DictionaryArray<u8, StringArray<false, i32, ArcSliceBuffer>, false, ArcSliceBuffer>>();
+        let mut sliced = array.slice(2..);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.index_checked(0), "c");
+        sliced.extend(["b".to_owned()]);
+        assert_eq!(sliced.values.len(), 3);
+        assert_eq!(sliced.keys.0.as_slice(), [2, 0, 1]);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
     buffer::{Buffer, BufferType, VecBuffer},
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, Slice,
 };
 
 use super::{Array, FixedSizeListArray, FixedSizePrimitiveArray};
@@ -269,11 +269,23 @@ where
     }
 }
 
+impl<const N: usize, const NULLABLE: bool, Buffer: BufferType> Slice
+    for FixedSizeBinaryArray<N, NULLABLE, Buffer>
+where
+    FixedSizePrimitiveArray<u8, false, Buffer>: Validity<NULLABLE>,
+    FixedSizeListArray<N, FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<const N: usize, Buffer: BufferType> ValidityBitmap for FixedSizeBinaryArray<N, true, Buffer> {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::buffer::ArcSliceBuffer;
 
     #[test]
     fn from_iter() {
@@ -307,6 +319,17 @@ mod tests {
         assert_eq!(array_nullable.index(2), None);
     }
 
+    #[test]
+    fn slice() {
+        let input = [Some([1_u8, 2]), None, Some([5, 6])];
+        let array = input
+            .into_iter()
+            .collect::<FixedSizeBinaryArray<2, true, ArcSliceBuffer>>();
+        let sliced = array.slice(2..);
+        assert_eq!(sliced.len(), 1);
+        assert_eq!(sliced.index(0), Some(Some([&5, &6])));
+    }
+
     #[test]
     fn into_iter() {
         let input = [[1_u8, 2], [3, 4]];
@@ -10,7 +10,7 @@ use crate::{
     buffer::{BufferMut, BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, Slice,
 };
 
 use super::Array;
@@ -159,7 +159,8 @@ impl<const N: usize, T: Array, Buffer: BufferType> Index for FixedSizeListArray<
 where
     T: Index,
 {
-    type Item<'a> = [<T as Index>::Item<'a>; N]
+    type Item<'a>
+        = [<T as Index>::Item<'a>; N]
     where
         Self: 'a;
 
@@ -185,7 +186,8 @@ impl<const N: usize, T: Array, Buffer: BufferType> Index for FixedSizeListArray<
 where
     T: Index,
 {
-    type Item<'a> = Option<[<T as Index>::Item<'a>; N]>
+    type Item<'a>
+        = Option<[<T as Index>::Item<'a>; N]>
     where
         Self: 'a;
 
@@ -369,6 +371,28 @@ where
     }
 }
 
+impl<const N: usize, T: Array, Buffer: BufferType> Slice for FixedSizeListArray<N, T, false, Buffer>
+where
+    T: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset * N, len * N))
+    }
+}
+
+impl<const N: usize, T: Array, Buffer: BufferType> Slice for FixedSizeListArray<N, T, true, Buffer>
+where
+    T: Slice,
+    Bitmap<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(Nullable {
+            data: self.0.data.slice_unchecked(offset * N, len * N),
+            validity: self.0.validity.slice_unchecked(offset, len),
+        })
+    }
+}
+
 impl<const N: usize, T: Array, Buffer: BufferType> ValidityBitmap
     for FixedSizeListArray<N, T, true, Buffer>
 {
@@ -376,7 +400,10 @@ impl<const N: usize, T: Array, Buffer: BufferType> ValidityBitmap
 
 #[cfg(test)]
 mod tests {
-    use crate::array::{FixedSizePrimitiveArray, StringArray};
+    use crate::{
+        array::{FixedSizePrimitiveArray, StringArray},
+        buffer::ArcSliceBuffer,
+    };
 
     use super::*;
 
@@ -616,6 +643,33 @@ mod tests {
         assert_eq!(array_nullable_string_nullable.index(3), None);
     }
 
+    #[test]
+    fn slice() {
+        let input = [[1_u8, 2], [3, 4], [5, 6]];
+        let array = input.into_iter().collect::<FixedSizeListArray<
+            2,
+            FixedSizePrimitiveArray<u8, false, ArcSliceBuffer>,
+            false,
+            ArcSliceBuffer,
+        >>();
+        let sliced = array.slice(1..);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.index(0), Some([&3, &4]));
+        assert_eq!(sliced.index(1), Some([&5, &6]));
+
+        let input_nullable = [Some([1_u8, 2]), None, Some([5, 6])];
+        let array_nullable = input_nullable.into_iter().collect::<FixedSizeListArray<
+            2,
+            FixedSizePrimitiveArray<u8, false, ArcSliceBuffer>,
+            true,
+            ArcSliceBuffer,
+        >>();
+        let sliced_nullable = array_nullable.slice(1..3);
+        assert_eq!(sliced_nullable.len(), 2);
+        assert_eq!(sliced_nullable.index(0), Some(None));
+        assert_eq!(sliced_nullable.index(1), Some(Some([&5, &6])));
+    }
+
     #[test]
     fn fixed_size_array_chunks() {
         {
@@ -6,7 +6,7 @@ use crate::{
     buffer::{Buffer, BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    FixedSize, Index, Length,
+    FixedSize, Index, Length, Slice,
 };
 use std::{
     fmt::{Debug, Formatter, Result},
@@ -47,9 +47,16 @@ type_def!(Uint64Array, u64);
 #[cfg(not(feature = "arrow-rs"))]
 type_def!(Uint128Array, u128);
 
+#[cfg(not(feature = "arrow-rs"))]
 type_def!(IsizeArray, isize);
+#[cfg(not(feature = "arrow-rs"))]
 type_def!(UsizeArray, usize);
+// With `arrow-rs`, `isize` and `usize` are stored as logical types.
+#[cfg(feature = "arrow-rs")]
+pub use crate::logical::primitive::{IsizeArray, UsizeArray};
 
+#[cfg(feature = "half")]
+type_def!(Float16Array, half::f16);
 type_def!(Float32Array, f32);
 type_def!(Float64Array, f64);
 
@@ -168,7 +175,11 @@ where
     <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
     <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: Index,
 {
-    type Item<'a> = <<<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
+    type Item<'a>
+        =
+        <<<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<
+            'a,
+        >
     where
         Self: 'a;
 
@@ -256,12 +267,23 @@ where
     }
 }
 
+impl<T: FixedSize, const NULLABLE: bool, Buffer: BufferType> Slice
+    for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
+    <<Buffer as BufferType>::Buffer<T> as Validity<NULLABLE>>::Storage<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<T: FixedSize, Buffer: BufferType> ValidityBitmap for FixedSizePrimitiveArray<T, true, Buffer> {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::buffer::{BufferRef, BufferRefMut};
+    use crate::buffer::{ArcSliceBuffer, BufferRef, BufferRefMut};
     use std::mem;
 
     #[test]
@@ -367,6 +389,25 @@ mod tests {
         assert_eq!(nullable.index_checked(3), Some(&4));
     }
 
+    #[test]
+    fn slice() {
+        let array = [1, 2, 3, 4]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u32, false, ArcSliceBuffer>>();
+        let sliced = array.slice(1..3);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.as_ref(), [2, 3]);
+        assert_eq!(sliced.index_checked(1), &3);
+
+        let nullable = [Some(1), None, Some(3), Some(4)]
+            .into_iter()
+            .collect::<FixedSizePrimitiveArray<u32, true, ArcSliceBuffer>>();
+        let sliced_nullable = nullable.slice(1..);
+        assert_eq!(sliced_nullable.len(), 3);
+        assert_eq!(sliced_nullable.is_null(0), Some(true));
+        assert_eq!(sliced_nullable.index_checked(2), Some(&4));
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(mem::size_of::<Int8Array>(), mem::size_of::<Vec<i8>>());
@@ -0,0 +1,453 @@
+//! Array with variable-size list elements, stored as views.
+
+use std::{collections::VecDeque, iter};
+
+use crate::{
+    array::{Array, VariableSizeListArray},
+    bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
+    buffer::{Buffer as _, BufferType, VecBuffer},
+    offset::OffsetElement,
+    validity::{Nullability, Validity},
+    Index, IndexOwned, IntoOwned, Length, Slice,
+};
+
+/// Array with variable-size list elements, stored as views.
+///
+/// Every list is described by an offset and a size in the child array.
+/// Unlike the offsets of a [`VariableSizeListArray`], the offsets don't have
+/// to be increasing, which allows lists to be written out of order and to
+/// share ranges of the child array.
+pub struct ListViewArray<
+    T: Array,
+    const NULLABLE: bool = false,
+    OffsetItem: OffsetElement = i32,
+    Buffer: BufferType = VecBuffer,
+> where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+{
+    /// The child array with the values of the lists.
+    pub data: T,
+
+    /// The start offsets of the lists in the child array.
+    pub offsets:
+        <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<NULLABLE>>::Storage<Buffer>,
+
+    /// The sizes of the lists.
+    pub sizes: <Buffer as BufferType>::Buffer<OffsetItem>,
+}
+
+/// Array with variable-size list elements, stored as views, using `i64`
+/// offset and size values.
+pub type LargeListViewArray<T, const NULLABLE: bool = false, Buffer = VecBuffer> =
+    ListViewArray<T, NULLABLE, i64, Buffer>;
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Array
+    for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Vec<<T as Array>::Item>: Nullability<NULLABLE>,
+{
+    type Item = <Vec<<T as Array>::Item> as Nullability<NULLABLE>>::Item;
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Clone
+    for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    T: Clone,
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE> + Clone,
+    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<NULLABLE>>::Storage<Buffer>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            offsets: self.offsets.clone(),
+            sizes: self.sizes.clone(),
+        }
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Default
+    for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    T: Default,
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE> + Default,
+    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<NULLABLE>>::Storage<Buffer>: Default,
+{
+    fn default() -> Self {
+        Self {
+            data: T::default(),
+            offsets: Default::default(),
+            sizes: Default::default(),
+        }
+    }
+}
+
+impl<T: Array, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType> Extend<U>
+    for ListViewArray<T, false, OffsetItem, Buffer>
+where
+    T: Extend<<U as IntoIterator>::Item> + Length,
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+{
+    fn extend<I: IntoIterator<Item = U>>(&mut self, iter: I) {
+        let Self {
+            ref mut data,
+            ref mut offsets,
+            ref mut sizes,
+        } = *self;
+        iter.into_iter().for_each(|item| {
+            offsets.extend(iter::once(
+                OffsetItem::try_from(data.len()).expect("offset value overflow"),
+            ));
+            sizes.extend(iter::once(
+                OffsetItem::try_from(item.len()).expect("len overflow"),
+            ));
+            data.extend(item);
+        });
+    }
+}
+
+impl<T: Array, U: IntoIterator + Length, OffsetItem: OffsetElement, Buffer: BufferType>
+    Extend<Option<U>> for ListViewArray<T, true, OffsetItem, Buffer>
+where
+    T: Extend<<U as IntoIterator>::Item> + Length,
+    <Buffer as BufferType>::Buffer<OffsetItem>: Extend<OffsetItem>,
+    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<true>>::Storage<Buffer>:
+        Extend<(bool, OffsetItem)>,
+{
+    fn extend<I: IntoIterator<Item = Option<U>>>(&mut self, iter: I) {
+        let Self {
+            ref mut data,
+            ref mut offsets,
+            ref mut sizes,
+        } = *self;
+        iter.into_iter().for_each(|opt| {
+            offsets.extend(iter::once((
+                opt.is_some(),
+                OffsetItem::try_from(data.len()).expect("offset value overflow"),
+            )));
+            sizes.extend(iter::once(
+                OffsetItem::try_from(opt.len()).expect("len overflow"),
+            ));
+            if let Some(item) = opt {
+                data.extend(item);
+            }
+        });
+    }
+}
+
+impl<T: Array, U, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    FromIterator<U> for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Self: Default + Extend<U>,
+{
+    fn from_iter<I: IntoIterator<Item = U>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    From<ListViewArray<T, NULLABLE, OffsetItem, Buffer>>
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    ListViewArray<T, NULLABLE, OffsetItem, Buffer>: IntoIterator,
+    Self: FromIterator<<ListViewArray<T, NULLABLE, OffsetItem, Buffer> as IntoIterator>::Item>,
+{
+    /// Converts a [`ListViewArray`] into a [`VariableSizeListArray`], copying
+    /// the lists to a new child array in order.
+    fn from(value: ListViewArray<T, NULLABLE, OffsetItem, Buffer>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+/// An iterator over the items in a list of a [`ListViewArray`].
+pub struct ListViewSlice<'a, T> {
+    /// The child array.
+    data: &'a T,
+    /// The index of the next item.
+    index: usize,
+    /// The end of this list.
+    end: usize,
+}
+
+impl<T> Length for ListViewSlice<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.index
+    }
+}
+
+impl<'a, T: Index> Iterator for ListViewSlice<'a, T> {
+    type Item = <T as Index>::Item<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.index < self.end).then(|| {
+            let value = self.data.index_checked(self.index);
+            self.index += 1;
+            value
+        })
+    }
+}
+
+impl<'a, T: Index, U> IntoOwned<Vec<U>> for ListViewSlice<'a, T>
+where
+    <T as Index>::Item<'a>: IntoOwned<U>,
+{
+    fn into_owned(self) -> Vec<U> {
+        self.map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl<'a, T: Index, U> IntoOwned<VecDeque<U>> for ListViewSlice<'a, T>
+where
+    <T as Index>::Item<'a>: IntoOwned<U>,
+{
+    fn into_owned(self) -> VecDeque<U> {
+        self.map(IntoOwned::into_owned).collect()
+    }
+}
+
+/// Returns the list at `index` with the given offsets and sizes.
+///
+/// # Safety
+///
+/// Caller must ensure index is within bounds of the offsets and sizes.
+unsafe fn list<'a, T, OffsetItem: OffsetElement>(
+    data: &'a T,
+    offsets: &[OffsetItem],
+    sizes: &[OffsetItem],
+    index: usize,
+) -> ListViewSlice<'a, T> {
+    let start: usize = (*offsets.get_unchecked(index))
+        .try_into()
+        .expect("offset value out of range");
+    let size: usize = (*sizes.get_unchecked(index))
+        .try_into()
+        .expect("size value out of range");
+    ListViewSlice {
+        data,
+        index: start,
+        end: start + size,
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> Index
+    for ListViewArray<T, false, OffsetItem, Buffer>
+{
+    type Item<'a>
+        = ListViewSlice<'a, T>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        list(
+            &self.data,
+            self.offsets.as_slice(),
+            self.sizes.as_slice(),
+            index,
+        )
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> Index
+    for ListViewArray<T, true, OffsetItem, Buffer>
+{
+    type Item<'a>
+        = Option<ListViewSlice<'a, T>>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.offsets.is_valid_unchecked(index).then(|| {
+            list(
+                &self.data,
+                self.offsets.data.as_slice(),
+                self.sizes.as_slice(),
+                index,
+            )
+        })
+    }
+}
+
+/// An iterator over the items in a [`ListViewArray`].
+pub struct ListViewIntoIter<
+    T: Array,
+    const NULLABLE: bool,
+    OffsetItem: OffsetElement,
+    Buffer: BufferType,
+> where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+{
+    /// The array.
+    array: ListViewArray<T, NULLABLE, OffsetItem, Buffer>,
+    /// The index of the next item.
+    index: usize,
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Iterator
+    for ListViewIntoIter<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    ListViewArray<T, NULLABLE, OffsetItem, Buffer>: Array + Length,
+    for<'a> ListViewArray<T, NULLABLE, OffsetItem, Buffer>:
+        IndexOwned<'a, <ListViewArray<T, NULLABLE, OffsetItem, Buffer> as Array>::Item>,
+{
+    type Item = <ListViewArray<T, NULLABLE, OffsetItem, Buffer> as Array>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (self.index < self.array.len()).then(|| {
+            self.index += 1;
+            // Safety:
+            // - Bounds checked above.
+            unsafe { self.array.index_owned_unchecked(self.index - 1) }
+        })
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> IntoIterator
+    for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    ListViewIntoIter<T, NULLABLE, OffsetItem, Buffer>: Iterator,
+{
+    type Item = <ListViewIntoIter<T, NULLABLE, OffsetItem, Buffer> as Iterator>::Item;
+    type IntoIter = ListViewIntoIter<T, NULLABLE, OffsetItem, Buffer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ListViewIntoIter {
+            array: self,
+            index: 0,
+        }
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
+    for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+{
+    fn len(&self) -> usize {
+        self.sizes.len()
+    }
+}
+
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Slice
+    for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    T: Clone,
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE> + Slice,
+    <<Buffer as BufferType>::Buffer<OffsetItem> as Validity<NULLABLE>>::Storage<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            data: self.data.clone(),
+            offsets: self.offsets.slice_unchecked(offset, len),
+            sizes: self.sizes.slice_unchecked(offset, len),
+        }
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
+    for ListViewArray<T, true, OffsetItem, Buffer>
+{
+    type Buffer = Buffer;
+
+    fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+        self.offsets.bitmap_ref()
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> BitmapRefMut
+    for ListViewArray<T, true, OffsetItem, Buffer>
+{
+    fn bitmap_ref_mut(&mut self) -> &mut Bitmap<Self::Buffer> {
+        self.offsets.bitmap_ref_mut()
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
+    for ListViewArray<T, true, OffsetItem, Buffer>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{FixedSizePrimitiveArray, StringArray},
+        buffer::ArcSliceBuffer,
+    };
+
+    #[test]
+    fn from_iter() {
+        let input = vec![vec![1, 2], vec![], vec![3]];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<ListViewArray<FixedSizePrimitiveArray<u8>>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.data.as_ref(), [1, 2, 3]);
+        assert_eq!(array.offsets, [0, 2, 2]);
+        assert_eq!(array.sizes, [2, 0, 1]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn from_iter_nullable() {
+        let input = vec![Some(vec!["a".to_owned()]), None, Some(vec![])];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<LargeListViewArray<StringArray, true>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.is_valid(0), Some(true));
+        assert_eq!(array.is_null(1), Some(true));
+        assert_eq!(array.is_valid(2), Some(true));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn index() {
+        // Out of order lists that share ranges of the child array.
+        let array = ListViewArray::<FixedSizePrimitiveArray<u8>> {
+            data: [1, 2, 3, 4].into_iter().collect(),
+            offsets: vec![2, 0, 1],
+            sizes: vec![2, 3, 2],
+        };
+        assert_eq!(array.index_checked(0).copied().collect::<Vec<_>>(), [3, 4]);
+        assert_eq!(
+            array.index_checked(1).copied().collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(array.index_checked(2).copied().collect::<Vec<_>>(), [2, 3]);
+        assert!(array.index(3).is_none());
+    }
+
+    #[test]
+    fn into_variable_size_list() {
+        let array = ListViewArray::<FixedSizePrimitiveArray<u8>> {
+            data: [1, 2, 3, 4].into_iter().collect(),
+            offsets: vec![2, 0, 1],
+            sizes: vec![2, 3, 0],
+        };
+        let list = VariableSizeListArray::from(array);
+        assert_eq!(list.0.data.as_ref(), [3, 4, 1, 2, 3]);
+        assert_eq!(list.0.offsets, [0, 2, 5, 5]);
+    }
+
+    #[test]
+    fn slice() {
+        let array = [vec![1], vec![2, 3], vec![4]]
+            .into_iter()
+            .collect::<ListViewArray<FixedSizePrimitiveArray<u8>, false, i32, ArcSliceBuffer>>();
+        let sliced = array.slice(1..);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(
+            sliced.into_iter().collect::<Vec<_>>(),
+            [vec![2, 3], vec![4]]
+        );
+    }
+}
@@ -4,13 +4,19 @@ use crate::{
     buffer::BufferType,
     logical::{LogicalArray, LogicalArrayType},
     offset::{self, OffsetElement},
-    Length,
+    IntoOwned, Length,
 };
 use std::{collections::VecDeque, marker::PhantomData};
 
+mod binary_view;
+pub use binary_view::*;
+
 mod boolean;
 pub use boolean::*;
 
+mod dictionary;
+pub use dictionary::*;
+
 mod fixed_size_binary;
 pub use fixed_size_binary::*;
 
@@ -20,15 +26,27 @@ pub use fixed_size_list::*;
 mod fixed_size_primitive;
 pub use fixed_size_primitive::*;
 
+mod list_view;
+pub use list_view::*;
+
 mod null;
 pub use null::*;
 
+mod run_end_encoded;
+pub use run_end_encoded::*;
+
 mod string;
 pub use string::*;
 
+mod string_view;
+pub use string_view::*;
+
 mod r#struct;
 pub use r#struct::*;
 
+mod tuple;
+pub use tuple::*;
+
 pub mod union;
 pub use union::*;
 
@@ -127,6 +145,14 @@ impl_array_type!(f32, FixedSizePrimitiveArray<f32, false, Buffer>);
 impl_array_type!(Option<f32>, FixedSizePrimitiveArray<f32, true, Buffer>, f32);
 impl_array_type!(f64, FixedSizePrimitiveArray<f64, false, Buffer>);
 impl_array_type!(Option<f64>, FixedSizePrimitiveArray<f64, true, Buffer>, f64);
+#[cfg(feature = "half")]
+impl_array_type!(half::f16, FixedSizePrimitiveArray<half::f16, false, Buffer>);
+#[cfg(feature = "half")]
+impl_array_type!(
+    Option<half::f16>,
+    FixedSizePrimitiveArray<half::f16, true, Buffer>,
+    half::f16
+);
 
 impl_array_type!((), NullArray<(), false, Buffer>);
 impl_array_type!(Option<()>, NullArray<(), true, Buffer>, ());
@@ -159,6 +185,12 @@ impl<const N: usize> From<[u8; N]> for FixedSizeBinary<N> {
     }
 }
 
+impl<const N: usize> IntoOwned<FixedSizeBinary<N>> for [&u8; N] {
+    fn into_owned(self) -> FixedSizeBinary<N> {
+        FixedSizeBinary(self.map(|&byte| byte))
+    }
+}
+
 impl<const N: usize> From<FixedSizeBinary<N>> for [u8; N] {
     fn from(value: FixedSizeBinary<N>) -> Self {
         value.0
@@ -205,6 +237,12 @@ impl From<VariableSizeBinary> for Vec<u8> {
     }
 }
 
+impl IntoOwned<VariableSizeBinary> for VariableSizeBinary {
+    fn into_owned(self) -> VariableSizeBinary {
+        self
+    }
+}
+
 impl Length for VariableSizeBinary {
     fn len(&self) -> usize {
         self.0.len()
@@ -6,7 +6,7 @@ use crate::{
     buffer::{BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, IntoOwned, Length, Slice,
 };
 use std::{
     iter::{self, Repeat, Take},
@@ -37,6 +37,12 @@ unsafe impl Unit for () {
     type Item = Self;
 }
 
+impl<T: Unit> IntoOwned<T> for T {
+    fn into_owned(self) -> T {
+        self
+    }
+}
+
 /// A sequence of nulls.
 pub struct NullArray<T: Unit = (), const NULLABLE: bool = false, Buffer: BufferType = VecBuffer>(
     pub(crate) <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>,
@@ -111,7 +117,8 @@ where
     Nulls<T>: Validity<NULLABLE>,
     <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: Index,
 {
-    type Item<'a> = <<Nulls<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
+    type Item<'a>
+        = <<Nulls<T> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
     where
         Self: 'a;
 
@@ -144,6 +151,16 @@ where
     }
 }
 
+impl<T: Unit, const NULLABLE: bool, Buffer: BufferType> Slice for NullArray<T, NULLABLE, Buffer>
+where
+    Nulls<T>: Validity<NULLABLE>,
+    <Nulls<T> as Validity<NULLABLE>>::Storage<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 // TODO(mbrobbel): figure out why autotrait fails here
 /// Safety:
 /// - The inner field has a `Send` bound in the where clause.
@@ -223,7 +240,8 @@ impl<T: Unit> Extend<T> for Nulls<T> {
 }
 
 impl<T: Unit> Index for Nulls<T> {
-    type Item<'a> = T
+    type Item<'a>
+        = T
     where
         Self: 'a;
 
@@ -248,10 +266,19 @@ impl<T: Unit> Length for Nulls<T> {
     }
 }
 
+impl<T: Unit> Slice for Nulls<T> {
+    unsafe fn slice_unchecked(&self, _offset: usize, len: usize) -> Self {
+        Self {
+            len,
+            _ty: PhantomData,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{array::UnionType, offset::OffsetElement};
+    use crate::{array::UnionType, buffer::ArcSliceBuffer, offset::OffsetElement};
     use std::mem;
 
     #[test]
@@ -317,6 +344,22 @@ mod tests {
         assert_eq!(input, array.into_iter().collect::<Vec<_>>().as_slice());
     }
 
+    #[test]
+    fn slice() {
+        let array = [(); 4].iter().copied().collect::<NullArray>();
+        assert_eq!(array.slice(1..3).len(), 2);
+
+        let input = [Some(()), None, Some(()), None];
+        let array_nullable = input
+            .iter()
+            .copied()
+            .collect::<NullArray<(), true, ArcSliceBuffer>>();
+        let sliced = array_nullable.slice(1..);
+        assert_eq!(sliced.len(), 3);
+        assert_eq!(sliced.is_null(0), Some(true));
+        assert_eq!(sliced.is_valid(1), Some(true));
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(mem::size_of::<NullArray<()>>(), mem::size_of::<usize>());
@@ -0,0 +1,269 @@
+//! Array with run-end encoded values.
+
+use super::{Array, FixedSizePrimitiveArray};
+use crate::{
+    buffer::{BufferMut, BufferType, VecBuffer},
+    FixedSize, Index, IndexOwned, Length,
+};
+
+/// Types that can be used as run ends of a [`RunEndEncodedArray`].
+pub trait RunEnd: FixedSize {
+    /// Returns the run end for a run that ends at `index`, or `None` if
+    /// `index` can't be represented by this run end type.
+    fn from_index(index: usize) -> Option<Self>;
+
+    /// Returns the index at which the run with this run end ends.
+    fn to_index(self) -> usize;
+}
+
+/// Implement [`RunEnd`] for the given integer types.
+macro_rules! impl_run_end {
+    ($($ty:ty),+) => {
+        $(
+            impl RunEnd for $ty {
+                fn from_index(index: usize) -> Option<Self> {
+                    Self::try_from(index).ok()
+                }
+
+                fn to_index(self) -> usize {
+                    usize::try_from(self).expect("negative run end")
+                }
+            }
+        )+
+    };
+}
+
+impl_run_end!(i16, i32, i64);
+
+/// Array with run-end encoded values.
+///
+/// Consecutive equal items are stored once in the `values` array, with their
+/// (exclusive) end index stored in the `run_ends` array. Extending a run-end
+/// encoded array extends the last run when the items are equal to the last
+/// value.
+pub struct RunEndEncodedArray<R: RunEnd, V: Array, Buffer: BufferType = VecBuffer> {
+    /// The (exclusive) end indices of the runs.
+    pub run_ends: FixedSizePrimitiveArray<R, false, Buffer>,
+
+    /// The values of the runs.
+    pub values: V,
+}
+
+impl<R: RunEnd, V: Array, Buffer: BufferType> RunEndEncodedArray<R, V, Buffer> {
+    /// Returns the index of the run that contains the item at `index`.
+    fn physical_index(&self, index: usize) -> usize {
+        self.run_ends
+            .as_ref()
+            .partition_point(|run_end| run_end.to_index() <= index)
+    }
+}
+
+impl<R: RunEnd, V: Array, Buffer: BufferType> Array for RunEndEncodedArray<R, V, Buffer> {
+    type Item = <V as Array>::Item;
+}
+
+impl<R: RunEnd, V: Array, Buffer: BufferType> Clone for RunEndEncodedArray<R, V, Buffer>
+where
+    FixedSizePrimitiveArray<R, false, Buffer>: Clone,
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            run_ends: self.run_ends.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<R: RunEnd, V: Array, Buffer: BufferType> Default for RunEndEncodedArray<R, V, Buffer>
+where
+    FixedSizePrimitiveArray<R, false, Buffer>: Default,
+    V: Default,
+{
+    fn default() -> Self {
+        Self {
+            run_ends: FixedSizePrimitiveArray::default(),
+            values: V::default(),
+        }
+    }
+}
+
+impl<R: RunEnd, V: Array, Buffer: BufferType> Extend<<V as Array>::Item>
+    for RunEndEncodedArray<R, V, Buffer>
+where
+    <Buffer as BufferType>::Buffer<R>: BufferMut<R>,
+    FixedSizePrimitiveArray<R, false, Buffer>: Extend<R>,
+    V: Extend<<V as Array>::Item> + Length,
+    for<'a> V: IndexOwned<'a, <V as Array>::Item>,
+    <V as Array>::Item: Clone + PartialEq,
+{
+    fn extend<I: IntoIterator<Item = <V as Array>::Item>>(&mut self, iter: I) {
+        let mut len = self.len();
+        let mut last = self.values.len().checked_sub(1).map(|index| {
+            // Safety:
+            // - The index is in bounds of the values array.
+            unsafe { self.values.index_owned_unchecked(index) }
+        });
+        iter.into_iter().for_each(|item| {
+            len = len.checked_add(1).expect("len overflow");
+            let run_end = R::from_index(len).expect("run end overflow");
+            if last.as_ref() == Some(&item) {
+                if let Some(last_run_end) = self.run_ends.0.as_mut_slice().last_mut() {
+                    *last_run_end = run_end;
+                }
+            } else {
+                self.run_ends.extend(std::iter::once(run_end));
+                self.values.extend(std::iter::once(item.clone()));
+                last = Some(item);
+            }
+        });
+    }
+}
+
+impl<R: RunEnd, V: Array, T, Buffer: BufferType> FromIterator<T>
+    for RunEndEncodedArray<R, V, Buffer>
+where
+    Self: Default + Extend<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<R: RunEnd, V: Array + Index, Buffer: BufferType> Index for RunEndEncodedArray<R, V, Buffer> {
+    type Item<'a>
+        = <V as Index>::Item<'a>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.values.index_checked(self.physical_index(index))
+    }
+}
+
+/// An iterator over the items in a [`RunEndEncodedArray`].
+pub struct RunEndEncodedIntoIter<R: RunEnd, V: Array, Buffer: BufferType> {
+    /// The array.
+    array: RunEndEncodedArray<R, V, Buffer>,
+    /// The index of the next item.
+    index: usize,
+    /// The index of the run of the next item.
+    run: usize,
+}
+
+impl<R: RunEnd, V: Array + Length, Buffer: BufferType> Iterator
+    for RunEndEncodedIntoIter<R, V, Buffer>
+where
+    for<'a> V: IndexOwned<'a, <V as Array>::Item>,
+{
+    type Item = <V as Array>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let run_ends = self.array.run_ends.as_ref();
+        while run_ends
+            .get(self.run)
+            .is_some_and(|run_end| run_end.to_index() <= self.index)
+        {
+            self.run += 1;
+        }
+        (self.run < run_ends.len()).then(|| {
+            assert!(
+                self.run < self.array.values.len(),
+                "run index out of bounds"
+            );
+            self.index += 1;
+            // Safety:
+            // - Bounds checked above.
+            unsafe { self.array.values.index_owned_unchecked(self.run) }
+        })
+    }
+}
+
+impl<R: RunEnd, V: Array, Buffer: BufferType> IntoIterator for RunEndEncodedArray<R, V, Buffer>
+where
+    RunEndEncodedIntoIter<R, V, Buffer>: Iterator,
+{
+    type Item = <RunEndEncodedIntoIter<R, V, Buffer> as Iterator>::Item;
+    type IntoIter = RunEndEncodedIntoIter<R, V, Buffer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RunEndEncodedIntoIter {
+            array: self,
+            index: 0,
+            run: 0,
+        }
+    }
+}
+
+impl<R: RunEnd, V: Array, Buffer: BufferType> Length for RunEndEncodedArray<R, V, Buffer> {
+    fn len(&self) -> usize {
+        self.run_ends
+            .as_ref()
+            .last()
+            .map_or(0, |&run_end| run_end.to_index())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{FixedSizePrimitiveArray, StringArray};
+
+    #[test]
+    fn from_iter() {
+        let input = [1_u32, 1, 1, 2, 2, 1, 3, 3, 3, 3];
+        let array = input
+            .into_iter()
+            .collect::<RunEndEncodedArray<i32, FixedSizePrimitiveArray<u32>>>();
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.run_ends.as_ref(), [3, 5, 6, 10]);
+        assert_eq!(array.values.as_ref(), [1, 2, 1, 3]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn from_iter_nullable() {
+        let input = [None, None, Some("a"), Some("a"), None].map(|x| x.map(ToOwned::to_owned));
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<RunEndEncodedArray<i16, StringArray<true>>>();
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.run_ends.as_ref(), [2, 4, 5]);
+        assert_eq!(array.values.len(), 3);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn extend() {
+        let mut array = ["a", "a"]
+            .map(ToOwned::to_owned)
+            .into_iter()
+            .collect::<RunEndEncodedArray<i32, StringArray>>();
+        array.extend(["a", "b", "b"].map(ToOwned::to_owned));
+        assert_eq!(array.run_ends.as_ref(), [3, 5]);
+        assert_eq!(array.values.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "run end overflow")]
+    fn run_end_overflow() {
+        let _: RunEndEncodedArray<i16, FixedSizePrimitiveArray<u8>> =
+            (0..=i16::MAX).map(|_| 1).collect();
+    }
+
+    #[test]
+    fn index() {
+        let array = [1_u8, 1, 2, 3, 3, 3]
+            .into_iter()
+            .collect::<RunEndEncodedArray<i64, FixedSizePrimitiveArray<u8>>>();
+        assert_eq!(array.index_checked(0), &1);
+        assert_eq!(array.index_checked(1), &1);
+        assert_eq!(array.index_checked(2), &2);
+        assert_eq!(array.index_checked(3), &3);
+        assert_eq!(array.index_checked(5), &3);
+        assert_eq!(array.index(6), None);
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     buffer::{BufferType, VecBuffer},
     offset::OffsetElement,
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, Slice,
 };
 
 /// Array with string values.
@@ -186,7 +186,8 @@ where
 impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
     for StringArray<false, OffsetItem, Buffer>
 {
-    type Item<'a> = &'a str
+    type Item<'a>
+        = &'a str
     where
         Self: 'a;
 
@@ -198,7 +199,8 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
 impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
     for StringArray<true, OffsetItem, Buffer>
 {
-    type Item<'a> = Option<&'a str>
+    type Item<'a>
+        = Option<&'a str>
     where
         Self: 'a;
 
@@ -311,6 +313,17 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Slice
+    for StringArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for StringArray<true, OffsetItem, Buffer>
 {
@@ -339,7 +352,7 @@ mod tests {
     use super::*;
     use crate::{
         array::{union, ArrayType},
-        buffer::BufferRef,
+        buffer::{ArcSliceBuffer, BufferRef},
     };
 
     #[test]
@@ -398,6 +411,27 @@ mod tests {
         assert_eq!(output, input_nullable);
     }
 
+    #[test]
+    fn slice() {
+        let input = ["hello", " ", "world", "!"];
+        let array = input
+            .into_iter()
+            .collect::<StringArray<false, i32, ArcSliceBuffer>>();
+        let sliced = array.slice(2..);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.iter().collect::<Vec<_>>(), ["world", "!"]);
+
+        let input_nullable = [Some("hello"), None, Some("world")];
+        let array_nullable = input_nullable
+            .into_iter()
+            .collect::<StringArray<true, i32, ArcSliceBuffer>>();
+        let sliced_nullable = array_nullable.slice(1..);
+        assert_eq!(
+            sliced_nullable.iter().collect::<Vec<_>>(),
+            [None, Some("world")]
+        );
+    }
+
     #[test]
     fn convert_nullable() {
         let input = ["hello", " ", "world"];
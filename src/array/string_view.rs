@@ -0,0 +1,248 @@
+//! Array with string values, stored as views.
+
+use std::{iter::Map, str};
+
+use super::{binary_view, Array, BinaryViewArray, FixedSizePrimitiveArray};
+use crate::{
+    bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
+    buffer::{BufferType, VecBuffer},
+    validity::{Nullability, Validity},
+    Index, Length, Slice,
+};
+
+/// Array with string values, stored as views.
+///
+/// See [`BinaryViewArray`] for the layout of the views.
+pub struct StringViewArray<const NULLABLE: bool = false, Buffer: BufferType = VecBuffer>(
+    pub BinaryViewArray<NULLABLE, Buffer>,
+)
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>;
+
+impl<const NULLABLE: bool, Buffer: BufferType> Array for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    String: Nullability<NULLABLE>,
+{
+    type Item = <String as Nullability<NULLABLE>>::Item;
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Clone for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    BinaryViewArray<NULLABLE, Buffer>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Default for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    BinaryViewArray<NULLABLE, Buffer>: Default,
+{
+    fn default() -> Self {
+        Self(BinaryViewArray::default())
+    }
+}
+
+impl<T: AsRef<str>, Buffer: BufferType> Extend<T> for StringViewArray<false, Buffer>
+where
+    FixedSizePrimitiveArray<i128, false, Buffer>: Extend<i128>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Default + Extend<u8> + Length,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let BinaryViewArray {
+            ref mut views,
+            ref mut buffers,
+        } = self.0;
+        views.extend(
+            iter.into_iter()
+                .map(|item| binary_view::view(item.as_ref().as_bytes(), buffers)),
+        );
+    }
+}
+
+impl<T: AsRef<str>, Buffer: BufferType> Extend<Option<T>> for StringViewArray<true, Buffer>
+where
+    FixedSizePrimitiveArray<i128, true, Buffer>: Extend<Option<i128>>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Default + Extend<u8> + Length,
+{
+    fn extend<I: IntoIterator<Item = Option<T>>>(&mut self, iter: I) {
+        let BinaryViewArray {
+            ref mut views,
+            ref mut buffers,
+        } = self.0;
+        views.extend(
+            iter.into_iter()
+                .map(|opt| opt.map(|item| binary_view::view(item.as_ref().as_bytes(), buffers))),
+        );
+    }
+}
+
+impl<Buffer: BufferType> From<StringViewArray<false, Buffer>> for StringViewArray<true, Buffer>
+where
+    BinaryViewArray<false, Buffer>: Into<BinaryViewArray<true, Buffer>>,
+{
+    fn from(value: StringViewArray<false, Buffer>) -> Self {
+        Self(value.0.into())
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> From<StringViewArray<NULLABLE, Buffer>>
+    for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+{
+    fn from(value: StringViewArray<NULLABLE, Buffer>) -> Self {
+        value.0
+    }
+}
+
+impl<T, const NULLABLE: bool, Buffer: BufferType> FromIterator<T>
+    for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    Self: Default + Extend<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::default();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<Buffer: BufferType> Index for StringViewArray<false, Buffer> {
+    type Item<'a>
+        = &'a str
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        str::from_utf8_unchecked(self.0.index_unchecked(index))
+    }
+}
+
+impl<Buffer: BufferType> Index for StringViewArray<true, Buffer> {
+    type Item<'a>
+        = Option<&'a str>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0
+            .index_unchecked(index)
+            .map(|bytes| str::from_utf8_unchecked(bytes))
+    }
+}
+
+impl<Buffer: BufferType> IntoIterator for StringViewArray<false, Buffer>
+where
+    BinaryViewArray<false, Buffer>: IntoIterator<Item = Vec<u8>>,
+{
+    type Item = String;
+    type IntoIter = Map<
+        <BinaryViewArray<false, Buffer> as IntoIterator>::IntoIter,
+        fn(<BinaryViewArray<false, Buffer> as IntoIterator>::Item) -> String,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().map(|bytes| {
+            // SAFETY:
+            // - String arrays contain valid UTF8.
+            unsafe { String::from_utf8_unchecked(bytes) }
+        })
+    }
+}
+
+impl<Buffer: BufferType> IntoIterator for StringViewArray<true, Buffer>
+where
+    BinaryViewArray<true, Buffer>: IntoIterator<Item = Option<Vec<u8>>>,
+{
+    type Item = Option<String>;
+    type IntoIter = Map<
+        <BinaryViewArray<true, Buffer> as IntoIterator>::IntoIter,
+        fn(<BinaryViewArray<true, Buffer> as IntoIterator>::Item) -> Option<String>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().map(|opt| {
+            opt.map(|bytes| {
+                // SAFETY:
+                // - String arrays contain valid UTF8.
+                unsafe { String::from_utf8_unchecked(bytes) }
+            })
+        })
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Length for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    BinaryViewArray<NULLABLE, Buffer>: Length,
+{
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> Slice for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    BinaryViewArray<NULLABLE, Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
+impl<Buffer: BufferType> BitmapRef for StringViewArray<true, Buffer> {
+    type Buffer = Buffer;
+
+    fn bitmap_ref(&self) -> &Bitmap<Self::Buffer> {
+        self.0.bitmap_ref()
+    }
+}
+
+impl<Buffer: BufferType> BitmapRefMut for StringViewArray<true, Buffer> {
+    fn bitmap_ref_mut(&mut self) -> &mut Bitmap<Self::Buffer> {
+        self.0.bitmap_ref_mut()
+    }
+}
+
+impl<Buffer: BufferType> ValidityBitmap for StringViewArray<true, Buffer> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_iter() {
+        let input = ["hello", "a string that is not inlined", ""];
+        let array = input.into_iter().collect::<StringViewArray>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.0.buffers.len(), 1);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn from_iter_nullable() {
+        let input = [Some("hello".to_owned()), None, Some("world".repeat(3))];
+        let array = input.clone().into_iter().collect::<StringViewArray<true>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.is_null(1), Some(true));
+        assert_eq!(array.index_checked(2), Some("worldworldworld"));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn extend() {
+        let mut array = StringViewArray::<false>::default();
+        array.extend(["a", "b"]);
+        array.extend(["a string that is not inlined".to_owned()]);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index_checked(0), "a");
+        assert_eq!(array.index_checked(2), "a string that is not inlined");
+    }
+}
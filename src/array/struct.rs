@@ -6,7 +6,7 @@ use crate::{
     buffer::{BufferType, VecBuffer},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Length,
+    Index, Length, Slice,
 };
 
 /// Struct array types.
@@ -109,6 +109,21 @@ where
     }
 }
 
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Index
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Index,
+{
+    type Item<'a> = <<<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer> as Index>::Item<'a>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0.index_unchecked(index)
+    }
+}
+
 impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> IntoIterator
     for StructArray<T, NULLABLE, Buffer>
 where
@@ -156,6 +171,17 @@ where
     }
 }
 
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> Slice
+    for StructArray<T, NULLABLE, Buffer>
+where
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
+    <<T as StructArrayType>::Array<Buffer> as Validity<NULLABLE>>::Storage<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<T: StructArrayType, Buffer: BufferType> BitmapRef for StructArray<T, true, Buffer> {
     type Buffer = Buffer;
 
@@ -315,7 +341,7 @@ mod tests {
         type Array<Buffer: BufferType> = FooArray<'a, Buffer>;
     }
 
-    impl<'a, Buffer: BufferType> Length for FooArray<'a, Buffer>
+    impl<Buffer: BufferType> Length for FooArray<'_, Buffer>
     where
         <u32 as ArrayType<u32>>::Array<Buffer, offset::NA, union::NA>: Length,
     {
@@ -514,6 +540,129 @@ mod tests {
         assert_eq!(named_output_nullable, named_input_nullable);
     }
 
+    #[cfg(feature = "derive")]
+    #[test]
+    fn index() {
+        use crate::IntoOwned;
+
+        #[derive(crate::ArrayType, Copy, Clone, Debug, Default, PartialEq)]
+        struct Unit;
+
+        #[derive(crate::ArrayType, Clone, Debug, Default, PartialEq)]
+        struct Unnamed(u8, Option<u16>, String);
+
+        #[derive(crate::ArrayType, Clone, Debug, Default, PartialEq)]
+        struct Named {
+            a: u32,
+            b: Option<bool>,
+            c: Vec<u8>,
+        }
+
+        let unit_array = [Unit; 3].into_iter().collect::<StructArray<Unit>>();
+        assert_eq!(unit_array.index(2), Some(Unit));
+        assert_eq!(unit_array.index(3), None);
+        let unit_array_nullable = [Some(Unit), None]
+            .into_iter()
+            .collect::<StructArray<Unit, true>>();
+        assert_eq!(unit_array_nullable.index(0), Some(Some(Unit)));
+        assert_eq!(unit_array_nullable.index(1), Some(None));
+
+        let unnamed_array = [
+            Unnamed(1, None, "a".to_owned()),
+            Unnamed(2, Some(3), "bc".to_owned()),
+        ]
+        .into_iter()
+        .collect::<StructArray<Unnamed>>();
+        assert_eq!(
+            unnamed_array.index_checked(1),
+            UnnamedRef(&2, Some(&3), "bc")
+        );
+        assert!(unnamed_array.index(2).is_none());
+        let unnamed: Unnamed = unnamed_array.index_checked(0).into_owned();
+        assert_eq!(unnamed, Unnamed(1, None, "a".to_owned()));
+
+        let named_array_nullable = [
+            Some(Named {
+                a: 1,
+                b: Some(true),
+                c: vec![1, 2],
+            }),
+            None,
+            Some(Named {
+                a: 3,
+                b: None,
+                c: vec![],
+            }),
+        ]
+        .into_iter()
+        .collect::<StructArray<Named, true>>();
+        assert!(named_array_nullable.index_checked(1).is_none());
+        let row = named_array_nullable.index_checked(0).expect("valid");
+        assert_eq!((row.a, row.b), (&1, Some(true)));
+        assert_eq!(row.c.copied().collect::<Vec<_>>(), [1, 2]);
+        let NamedRef { a, b, c } = named_array_nullable.index_checked(2).expect("valid");
+        assert_eq!((a, b, c.len()), (&3, None, 0));
+        let named: Option<Named> = named_array_nullable.index_checked(0).into_owned();
+        assert_eq!(
+            named,
+            Some(Named {
+                a: 1,
+                b: Some(true),
+                c: vec![1, 2],
+            })
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn slice() {
+        use crate::buffer::ArcSliceBuffer;
+
+        #[derive(crate::ArrayType, Copy, Clone, Debug, Default, PartialEq)]
+        struct Unit;
+
+        #[derive(crate::ArrayType, Clone, Debug, Default, PartialEq)]
+        struct Unnamed(u8, Option<u16>, String);
+
+        #[derive(crate::ArrayType, Clone, Debug, Default, PartialEq)]
+        struct Named {
+            a: u32,
+            b: Option<bool>,
+            c: Unnamed,
+        }
+
+        let unit_array = [Unit; 3]
+            .into_iter()
+            .collect::<StructArray<Unit, false, ArcSliceBuffer>>();
+        assert_eq!(unit_array.slice(1..).len(), 2);
+
+        let named_array_nullable = [
+            Some(Named {
+                a: 1,
+                b: Some(true),
+                c: Unnamed(1, None, "a".to_owned()),
+            }),
+            None,
+            Some(Named {
+                a: 3,
+                b: None,
+                c: Unnamed(2, Some(3), "bc".to_owned()),
+            }),
+        ]
+        .into_iter()
+        .collect::<StructArray<Named, true, ArcSliceBuffer>>();
+        let sliced = named_array_nullable.slice(1..);
+        assert_eq!(sliced.len(), 2);
+        assert!(sliced.index_checked(0).is_none());
+        let NamedRef { a, b, c } = sliced.index_checked(1).expect("valid");
+        assert_eq!((a, b), (&3, None));
+        assert_eq!(c, UnnamedRef(&2, Some(&3), "bc"));
+        assert_eq!(
+            sliced.slice(1..).index_checked(0).map(|row| row.a),
+            Some(&3)
+        );
+    }
+
     #[cfg(feature = "derive")]
     #[test]
     fn nested_option_derived() {
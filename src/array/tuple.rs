@@ -0,0 +1,311 @@
+//! Array for tuples.
+
+use super::{union, ArrayType, StructArray, StructArrayType, UnionType};
+use crate::{
+    buffer::BufferType,
+    offset::{self, OffsetElement},
+    Index, IntoOwned, Length, Slice,
+};
+
+/// The arrays of the elements of a tuple.
+///
+/// This is the [`StructArrayType::Array`] of tuples, which are stored as
+/// [`StructArray`]s with one field per element. The fields are named after
+/// the index of their element, i.e. `"0"`, `"1"` and so on.
+#[derive(Clone, Default)]
+pub struct TupleArray<T>(pub T);
+
+/// Iterator over the items of a [`TupleArray`].
+pub struct TupleArrayIter<T>(T);
+
+/// The elements of tuples.
+///
+/// This maps an element to the type parameter of its [`ArrayType`]
+/// implementation, which is the inner type for nullable elements.
+pub trait TupleElement: Sized {
+    /// The type parameter of the [`ArrayType`] implementation of this element.
+    type Inner;
+}
+
+impl<T: ArrayType<T>> TupleElement for T {
+    type Inner = T;
+}
+
+impl<T> TupleElement for Option<T>
+where
+    Option<T>: ArrayType<T>,
+{
+    type Inner = T;
+}
+
+/// Implement [`ArrayType`], [`StructArrayType`] and the [`TupleArray`] traits
+/// for tuples with the given elements.
+macro_rules! impl_tuple {
+    ($($idx:tt: $ty:ident => $array:ident),+) => {
+        impl<$($ty: TupleElement + ArrayType<<$ty as TupleElement>::Inner>),+>
+            ArrayType<($($ty,)+)> for ($($ty,)+)
+        {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                StructArray<($($ty,)+), false, Buffer>;
+        }
+
+        impl<$($ty: TupleElement + ArrayType<<$ty as TupleElement>::Inner>),+>
+            ArrayType<($($ty,)+)> for Option<($($ty,)+)>
+        {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                StructArray<($($ty,)+), true, Buffer>;
+        }
+
+        impl<$($ty: TupleElement + ArrayType<<$ty as TupleElement>::Inner>),+>
+            StructArrayType for ($($ty,)+)
+        {
+            type Array<Buffer: BufferType> = TupleArray<(
+                $(<$ty as ArrayType<<$ty as TupleElement>::Inner>>::Array<
+                    Buffer,
+                    offset::NA,
+                    union::NA,
+                >,)+
+            )>;
+        }
+
+        impl<$($array: Length),+> Length for TupleArray<($($array,)+)> {
+            fn len(&self) -> usize {
+                self.0 .0.len()
+            }
+        }
+
+        impl<$($array: Slice),+> Slice for TupleArray<($($array,)+)> {
+            unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+                Self(($(self.0.$idx.slice_unchecked(offset, len),)+))
+            }
+        }
+
+        impl<$($ty, $array: Extend<$ty>),+> Extend<($($ty,)+)> for TupleArray<($($array,)+)> {
+            fn extend<_I: IntoIterator<Item = ($($ty,)+)>>(&mut self, iter: _I) {
+                iter.into_iter().for_each(|item| {
+                    $(self.0.$idx.extend(std::iter::once(item.$idx));)+
+                });
+            }
+        }
+
+        impl<$($ty, $array: Default + Extend<$ty>),+> FromIterator<($($ty,)+)>
+            for TupleArray<($($array,)+)>
+        {
+            fn from_iter<_I: IntoIterator<Item = ($($ty,)+)>>(iter: _I) -> Self {
+                let mut array = Self::default();
+                array.extend(iter);
+                array
+            }
+        }
+
+        impl<$($array: Iterator),+> Iterator for TupleArrayIter<($($array,)+)> {
+            type Item = ($(<$array as Iterator>::Item,)+);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(($(self.0.$idx.next()?,)+))
+            }
+        }
+
+        impl<$($array: IntoIterator),+> IntoIterator for TupleArray<($($array,)+)> {
+            type Item = ($(<$array as IntoIterator>::Item,)+);
+            type IntoIter = TupleArrayIter<($(<$array as IntoIterator>::IntoIter,)+)>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                TupleArrayIter(($(self.0.$idx.into_iter(),)+))
+            }
+        }
+
+        impl<$($array: Index),+> Index for TupleArray<($($array,)+)> {
+            type Item<'a> = ($(<$array as Index>::Item<'a>,)+)
+            where
+                Self: 'a;
+
+            unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+                ($(self.0.$idx.index_unchecked(index),)+)
+            }
+        }
+
+        impl<$($ty, $array: IntoOwned<$ty>),+> IntoOwned<($($ty,)+)> for ($($array,)+) {
+            fn into_owned(self) -> ($($ty,)+) {
+                ($(self.$idx.into_owned(),)+)
+            }
+        }
+    };
+}
+
+impl_tuple!(0: A => AA);
+impl_tuple!(0: A => AA, 1: B => BA);
+impl_tuple!(0: A => AA, 1: B => BA, 2: C => CA);
+impl_tuple!(0: A => AA, 1: B => BA, 2: C => CA, 3: D => DA);
+impl_tuple!(0: A => AA, 1: B => BA, 2: C => CA, 3: D => DA, 4: E => EA);
+impl_tuple!(0: A => AA, 1: B => BA, 2: C => CA, 3: D => DA, 4: E => EA, 5: F => FA);
+impl_tuple!(
+    0: A => AA,
+    1: B => BA,
+    2: C => CA,
+    3: D => DA,
+    4: E => EA,
+    5: F => FA,
+    6: G => GA
+);
+impl_tuple!(
+    0: A => AA,
+    1: B => BA,
+    2: C => CA,
+    3: D => DA,
+    4: E => EA,
+    5: F => FA,
+    6: G => GA,
+    7: H => HA
+);
+impl_tuple!(
+    0: A => AA,
+    1: B => BA,
+    2: C => CA,
+    3: D => DA,
+    4: E => EA,
+    5: F => FA,
+    6: G => GA,
+    7: H => HA,
+    8: I => IA
+);
+impl_tuple!(
+    0: A => AA,
+    1: B => BA,
+    2: C => CA,
+    3: D => DA,
+    4: E => EA,
+    5: F => FA,
+    6: G => GA,
+    7: H => HA,
+    8: I => IA,
+    9: J => JA
+);
+impl_tuple!(
+    0: A => AA,
+    1: B => BA,
+    2: C => CA,
+    3: D => DA,
+    4: E => EA,
+    5: F => FA,
+    6: G => GA,
+    7: H => HA,
+    8: I => IA,
+    9: J => JA,
+    10: K => KA
+);
+impl_tuple!(
+    0: A => AA,
+    1: B => BA,
+    2: C => CA,
+    3: D => DA,
+    4: E => EA,
+    5: F => FA,
+    6: G => GA,
+    7: H => HA,
+    8: I => IA,
+    9: J => JA,
+    10: K => KA,
+    11: L => LA
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{array::VariableSizeListArray, buffer::ArcSliceBuffer};
+
+    #[test]
+    fn from_iter() {
+        let input = [(1_u32, "a".to_owned()), (2, "b".to_owned())];
+        let array = input.into_iter().collect::<StructArray<(u32, String)>>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0 .0 .0 .0.as_slice(), [1, 2]);
+        assert_eq!(array.0 .0 .1.len(), 2);
+
+        let input_nullable = [Some((1_u8, true)), None];
+        let array_nullable = input_nullable
+            .into_iter()
+            .collect::<StructArray<(u8, bool), true>>();
+        assert_eq!(array_nullable.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let input = [(1_u32, "a".to_owned()), (2, "b".to_owned())];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<StructArray<(u32, String)>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_nullable = [Some((1_u8, [1_u16, 2], ())), None];
+        let array_nullable = input_nullable
+            .into_iter()
+            .collect::<StructArray<(u8, [u16; 2], ()), true>>();
+        assert_eq!(
+            array_nullable.into_iter().collect::<Vec<_>>(),
+            input_nullable
+        );
+    }
+
+    #[test]
+    fn nullable_elements() {
+        let input = [(1_u32, Some("a".to_owned())), (2, None)];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<StructArray<(u32, Option<String>)>>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.index_checked(0), (&1, Some("a")));
+        assert_eq!(array.index_checked(1), (&2, None));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_nullable = [Some((Some(1_u8), [Some(true), None])), None];
+        let array_nullable = input_nullable
+            .into_iter()
+            .collect::<StructArray<(Option<u8>, [Option<bool>; 2]), true>>();
+        assert_eq!(
+            array_nullable.into_iter().collect::<Vec<_>>(),
+            input_nullable
+        );
+    }
+
+    #[test]
+    fn index() {
+        let input = [(1_u32, vec![1_u8]), (2, vec![2, 3])];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<StructArray<(u32, Vec<u8>)>>();
+        let (a, b) = array.index_checked(1);
+        assert_eq!(a, &2);
+        assert_eq!(b.copied().collect::<Vec<_>>(), [2, 3]);
+        assert_eq!(
+            IntoOwned::<(u32, Vec<u8>)>::into_owned(array.index_checked(0)),
+            input[0]
+        );
+        assert!(array.index(2).is_none());
+    }
+
+    #[test]
+    fn slice() {
+        let input = [(1_u32, 1_u64), (2, 2), (3, 3)];
+        let array = input
+            .into_iter()
+            .collect::<StructArray<(u32, u64), false, ArcSliceBuffer>>();
+        let slice = array.slice(1..3);
+        assert_eq!(slice.len(), 2);
+        assert_eq!(slice.index_checked(0), (&2, &2));
+        assert_eq!(slice.index_checked(1), (&3, &3));
+    }
+
+    #[test]
+    fn nested() {
+        let input = vec![vec![(1_u32, (true, 2_i8))], vec![]];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<VariableSizeListArray<StructArray<(u32, (bool, i8))>, false>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+}
@@ -3,9 +3,9 @@
 use std::iter;
 
 use crate::{
-    buffer::{BufferType, VecBuffer},
+    buffer::{Buffer as _, BufferType, VecBuffer},
     offset::{self, OffsetElement},
-    Length,
+    Index, Length, Slice,
 };
 
 use super::{Array, ArrayType, Int32Array, Int8Array};
@@ -28,7 +28,15 @@ pub trait UnionType {
 pub struct DenseLayout;
 
 impl UnionType for DenseLayout {
-    type Array<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, Buffer: BufferType, OffsetItem: OffsetElement> = DenseUnionArray<T, VARIANTS, Buffer, OffsetItem> where for<'a> i8: From<&'a T>;
+    type Array<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    >
+        = DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+    where
+        for<'a> i8: From<&'a T>;
 }
 
 /// The sparse union layout.
@@ -36,7 +44,15 @@ impl UnionType for DenseLayout {
 pub struct SparseLayout;
 
 impl UnionType for SparseLayout {
-    type Array<T: UnionArrayType<VARIANTS>, const VARIANTS: usize, Buffer: BufferType, OffsetItem: OffsetElement> = SparseUnionArray<T, VARIANTS, Buffer, OffsetItem> where for<'a> i8: From<&'a T>;
+    type Array<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    >
+        = SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+    where
+        for<'a> i8: From<&'a T>;
 }
 
 /// Indicates that a [`UnionType`] generic is not applicable.
@@ -164,6 +180,43 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        UnionLayout: UnionType,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Index for UnionArray<T, VARIANTS, UnionLayout, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <UnionLayout as UnionType>::Array<T, VARIANTS, Buffer, OffsetItem>: Index,
+{
+    type Item<'a>
+        = <<UnionLayout as UnionType>::Array<T, VARIANTS, Buffer, OffsetItem> as Index>::Item<'a>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0.index_unchecked(index)
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        UnionLayout: UnionType,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Slice for UnionArray<T, VARIANTS, UnionLayout, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <UnionLayout as UnionType>::Array<T, VARIANTS, Buffer, OffsetItem>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -254,6 +307,53 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Index for DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    for<'a> <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>:
+        TypeIdIndex<'a, Enum = T>,
+{
+    type Item<'a>
+        = T
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        let type_id = *self.types.0.as_slice().get_unchecked(index);
+        let offset = usize::try_from(*self.offsets.0.as_slice().get_unchecked(index))
+            .expect("offset value out of range");
+        self.variants.index_unchecked(type_id, offset)
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Slice for DenseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>: Clone,
+    Int8Array<false, Buffer>: Slice,
+    Int32Array<false, Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        // The variants are shared, the offsets in the window still point to
+        // the values in the variant arrays.
+        Self {
+            variants: self.variants.clone(),
+            types: self.types.slice_unchecked(offset, len),
+            offsets: self.offsets.slice_unchecked(offset, len),
+        }
+    }
+}
+
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -375,6 +475,47 @@ where
     }
 }
 
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Index for SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    for<'a> <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>:
+        TypeIdIndex<'a, Enum = T>,
+{
+    type Item<'a>
+        = T
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        let type_id = *self.types.0.as_slice().get_unchecked(index);
+        self.variants.index_unchecked(type_id, index)
+    }
+}
+
+impl<
+        T: UnionArrayType<VARIANTS>,
+        const VARIANTS: usize,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+    > Slice for SparseUnionArray<T, VARIANTS, Buffer, OffsetItem>
+where
+    for<'a> i8: From<&'a T>,
+    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>: Slice,
+    Int8Array<false, Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            variants: self.variants.slice_unchecked(offset, len),
+            types: self.types.slice_unchecked(offset, len),
+        }
+    }
+}
+
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
@@ -415,6 +556,27 @@ pub trait UnionArrayIterators {
     fn new_variant_iters(self) -> Self::VariantIterators;
 }
 
+/// Types that return a constructed `enum` by indexing the
+/// variant arrays of a union array given the `type_id` for
+/// the variant.
+pub trait TypeIdIndex<'a> {
+    /// The rust enum
+    type Enum;
+
+    /// Returns the value of the variant for the `type_id`
+    /// at `index` in the array of that variant.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure `index` is in bounds of the array
+    /// of the variant for the `type_id`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `type_id` is not a valid variant.
+    unsafe fn index_unchecked(&'a self, type_id: i8, index: usize) -> Self::Enum;
+}
+
 /// Type holding the variant iterators of a union array
 type VarIters<T, const VARIANTS: usize, Buffer, OffsetItem, UnionLayout> = <<T as UnionArrayType<
     VARIANTS,
@@ -645,6 +807,20 @@ mod tests {
             }
         }
 
+        impl<'a, Buffer: BufferType, UnionLayout: UnionType> TypeIdIndex<'a>
+            for FooArray<Buffer, UnionLayout>
+        {
+            type Enum = Foo;
+
+            unsafe fn index_unchecked(&'a self, type_id: i8, index: usize) -> Self::Enum {
+                match type_id {
+                    0 => Foo::Bar(*self.bar.0.as_slice().get_unchecked(index)),
+                    1 => Foo::Baz(*self.baz.0.as_slice().get_unchecked(index)),
+                    _ => panic!("type id greater than number of variants"),
+                }
+            }
+        }
+
         impl From<&Foo> for i8 {
             fn from(value: &Foo) -> i8 {
                 match *value {
@@ -671,6 +847,10 @@ mod tests {
             assert_eq!(dense_array.0.variants.bar.0, [0, 3]);
             assert_eq!(dense_array.0.variants.baz.0, [1, 2]);
 
+            assert_eq!(dense_array.index_checked(2), Foo::Baz(2));
+            assert_eq!(dense_array.index_checked(3), Foo::Bar(3));
+            assert_eq!(dense_array.index(4), None);
+
             assert_eq!(dense_array.into_iter().collect::<Vec<_>>(), input);
         };
 
@@ -689,6 +869,10 @@ mod tests {
             );
             assert_eq!(sparse_array.0.variants.baz.0, [u32::default(), 1, 99]);
 
+            assert_eq!(sparse_array.index_checked(0), Foo::Bar(-78));
+            assert_eq!(sparse_array.index_checked(2), Foo::Baz(99));
+            assert_eq!(sparse_array.index(3), None);
+
             assert_eq!(sparse_array.into_iter().collect::<Vec<_>>(), input);
         };
     }
@@ -1092,6 +1276,8 @@ mod tests {
         assert_eq!(dense_array.0.offsets.0, &[0, 0, 0, 1]);
         assert_eq!(dense_array.0.variants.0 .0.bar.0, &[123]);
         assert_eq!(dense_array.0.variants.2 .0.len(), 2);
+        assert_eq!(dense_array.index_checked(2), Test::Foo { bar: 123 });
+        assert_eq!(dense_array.index_checked(3), Test::None);
         assert_eq!(dense_array.into_iter().collect::<Vec<_>>(), input.clone());
 
         let sparse_array = input.clone().into_iter().collect::<UnionArray<
@@ -1103,6 +1289,45 @@ mod tests {
         assert_eq!(sparse_array.0.types.0, &[2, 1, 0, 2]);
         assert_eq!(sparse_array.0.variants.0 .0.bar.0, &[0, 0, 123, 0]);
         assert_eq!(sparse_array.0.variants.2 .0.len(), 4);
+        assert_eq!(sparse_array.index_checked(1), Test::Bar(true));
+        assert_eq!(sparse_array.index_checked(2), Test::Foo { bar: 123 });
         assert_eq!(sparse_array.into_iter().collect::<Vec<_>>(), input);
     }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    #[rustversion::attr(nightly, allow(non_local_definitions))]
+    fn slice() {
+        use crate::{buffer::ArcSliceBuffer, ArrayType};
+
+        #[derive(ArrayType, Clone, Copy, Debug, PartialEq, Eq)]
+        enum Test {
+            Foo { bar: u8 },
+            Bar(bool),
+            None,
+        }
+
+        let input = [
+            Test::None,
+            Test::Bar(true),
+            Test::Foo { bar: 123 },
+            Test::None,
+        ];
+        let dense_array = input
+            .into_iter()
+            .collect::<UnionArray<Test, 3, DenseLayout, ArcSliceBuffer>>();
+        let dense_sliced = dense_array.slice(1..3);
+        assert_eq!(dense_sliced.len(), 2);
+        assert_eq!(dense_sliced.index_checked(0), Test::Bar(true));
+        assert_eq!(dense_sliced.index_checked(1), Test::Foo { bar: 123 });
+
+        let sparse_array = input
+            .into_iter()
+            .collect::<UnionArray<Test, 3, SparseLayout, ArcSliceBuffer>>();
+        let sparse_sliced = sparse_array.slice(2..);
+        assert_eq!(sparse_sliced.len(), 2);
+        assert_eq!(sparse_sliced.0.variants.0 .0.bar.0.as_ref(), [123, 0]);
+        assert_eq!(sparse_sliced.index_checked(0), Test::Foo { bar: 123 });
+        assert_eq!(sparse_sliced.index_checked(1), Test::None);
+    }
 }
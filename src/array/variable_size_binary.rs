@@ -6,7 +6,7 @@ use crate::{
     buffer::{Buffer, BufferType, VecBuffer},
     offset::{Offset, OffsetElement},
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, Slice,
 };
 
 /// Variable-size binary elements.
@@ -133,7 +133,8 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Index,
 {
-    type Item<'a> = &'a [u8]
+    type Item<'a>
+        = &'a [u8]
     where
         Self: 'a;
 
@@ -163,7 +164,8 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> Index
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Index,
 {
-    type Item<'a> = Option<&'a [u8]>
+    type Item<'a>
+        = Option<&'a [u8]>
     where
         Self: 'a;
 
@@ -223,6 +225,17 @@ where
     }
 }
 
+impl<const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Slice
+    for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<FixedSizePrimitiveArray<u8, false, Buffer>, NULLABLE, OffsetItem, Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for VariableSizeBinaryArray<true, OffsetItem, Buffer>
 {
@@ -249,7 +262,10 @@ impl<OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{array::VariableSizeBinary, buffer::BufferRef};
+    use crate::{
+        array::VariableSizeBinary,
+        buffer::{ArcSliceBuffer, BufferRef},
+    };
     use std::mem;
 
     #[test]
@@ -365,6 +381,19 @@ mod tests {
         assert!(array.index(4).is_none());
     }
 
+    #[test]
+    fn slice() {
+        let input: [&[u8]; 4] = [&[1], &[2, 3], &[4, 5, 6], &[7, 8, 9, 0]];
+        let array = input
+            .into_iter()
+            .collect::<VariableSizeBinaryArray<false, i32, ArcSliceBuffer>>();
+        let sliced = array.slice(1..3);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.index_checked(0), &[2, 3]);
+        assert_eq!(sliced.index_checked(1), &[4, 5, 6]);
+        assert!(sliced.index(2).is_none());
+    }
+
     #[test]
     fn convert() {
         let input = vec![Some("a".to_owned()), None, Some("b".to_owned())];
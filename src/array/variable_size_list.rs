@@ -6,7 +6,7 @@ use crate::{
     buffer::{BufferType, VecBuffer},
     offset::{Offset, OffsetElement},
     validity::{Nullability, Validity},
-    Index, Length,
+    Index, Length, Slice,
 };
 use std::fmt::{Debug, Formatter, Result};
 
@@ -117,7 +117,8 @@ where
     <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
     Offset<T, NULLABLE, OffsetItem, Buffer>: Index,
 {
-    type Item<'a> = <Offset<T, NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>
+    type Item<'a>
+        = <Offset<T, NULLABLE, OffsetItem, Buffer> as Index>::Item<'a>
     where
         Self: 'a;
 
@@ -137,6 +138,17 @@ where
     }
 }
 
+impl<T: Array, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Slice
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Offset<T, NULLABLE, OffsetItem, Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
 impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for VariableSizeListArray<T, true, OffsetItem, Buffer>
 {
@@ -163,7 +175,7 @@ impl<T: Array, OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::array::FixedSizePrimitiveArray;
+    use crate::{array::FixedSizePrimitiveArray, buffer::ArcSliceBuffer};
 
     #[test]
     fn from_iter() {
@@ -317,6 +329,57 @@ mod tests {
         assert!(array.index(2).is_none());
     }
 
+    #[test]
+    fn slice() {
+        let input = vec![vec![1, 2, 3], vec![], vec![4, 5], vec![6]];
+        let array = input.into_iter().collect::<VariableSizeListArray<
+            FixedSizePrimitiveArray<u8, false, ArcSliceBuffer>,
+            false,
+            i32,
+            ArcSliceBuffer,
+        >>();
+        let sliced = array.slice(1..3);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.index_checked(0).count(), 0);
+        assert_eq!(sliced.index_checked(1).copied().collect::<Vec<_>>(), [4, 5]);
+        assert_eq!(sliced.slice(1..).len(), 1);
+    }
+
+    #[test]
+    fn slice_extend() {
+        let input = vec![vec![1, 2], vec![3]];
+        let array = input.into_iter().collect::<VariableSizeListArray<
+            FixedSizePrimitiveArray<u8, false, ArcSliceBuffer>,
+            false,
+            i32,
+            ArcSliceBuffer,
+        >>();
+        let mut sliced = array.slice(0..1);
+        sliced.extend([vec![4]]);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.index_checked(0).copied().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(sliced.index_checked(1).copied().collect::<Vec<_>>(), [4]);
+        assert_eq!(array.index_checked(1).copied().collect::<Vec<_>>(), [3]);
+
+        let input_nullable = vec![Some(vec![1]), None, Some(vec![2, 3])];
+        let array_nullable = input_nullable.into_iter().collect::<VariableSizeListArray<
+            FixedSizePrimitiveArray<u8, false, ArcSliceBuffer>,
+            true,
+            i32,
+            ArcSliceBuffer,
+        >>();
+        let mut sliced_nullable = array_nullable.slice(1..2);
+        sliced_nullable.extend([Some(vec![4, 5])]);
+        assert_eq!(sliced_nullable.len(), 2);
+        assert!(sliced_nullable.index_checked(0).is_none());
+        assert_eq!(
+            sliced_nullable
+                .index_checked(1)
+                .map(|slice| slice.copied().collect::<Vec<_>>()),
+            Some(vec![4, 5])
+        );
+    }
+
     #[test]
     fn into_iter_nested() {
         let input = vec![
@@ -0,0 +1,245 @@
+//! Interop with [`arrow-rs`] binary view array.
+
+use std::sync::Arc;
+
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field};
+
+use crate::{
+    array::{BinaryViewArray, FixedSizePrimitiveArray},
+    bitmap::Bitmap,
+    buffer::BufferType,
+    nullable::Nullable,
+    validity::{Nullability, Validity},
+    Error,
+};
+
+impl<const NULLABLE: bool, Buffer: BufferType> crate::arrow::Array
+    for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    Vec<u8>: Nullability<NULLABLE>,
+{
+    type Array = arrow_array::BinaryViewArray;
+
+    fn as_field(name: &str) -> arrow_schema::Field {
+        Field::new(name, Self::data_type(), NULLABLE)
+    }
+
+    fn data_type() -> arrow_schema::DataType {
+        DataType::BinaryView
+    }
+}
+
+/// Returns the arrow views and data buffers of the given narrow views and
+/// data buffers.
+fn into_arrow_parts<Buffer: BufferType>(
+    narrow_views: <Buffer as BufferType>::Buffer<i128>,
+    buffers: Vec<FixedSizePrimitiveArray<u8, false, Buffer>>,
+) -> (ScalarBuffer<u128>, Vec<arrow_buffer::Buffer>)
+where
+    <Buffer as BufferType>::Buffer<i128>: Into<ScalarBuffer<i128>>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Into<ScalarBuffer<u8>>,
+{
+    let views: ScalarBuffer<i128> = narrow_views.into();
+    let len = views.len();
+    (
+        ScalarBuffer::new(views.into_inner(), 0, len),
+        buffers
+            .into_iter()
+            .map(|buffer| buffer.into().into_inner())
+            .collect(),
+    )
+}
+
+/// Returns the narrow views and data buffers of the given arrow views and
+/// data buffers.
+fn from_arrow_parts<Buffer: BufferType>(
+    views: ScalarBuffer<u128>,
+    buffers: Vec<arrow_buffer::Buffer>,
+) -> (
+    <Buffer as BufferType>::Buffer<i128>,
+    Vec<FixedSizePrimitiveArray<u8, false, Buffer>>,
+)
+where
+    <Buffer as BufferType>::Buffer<i128>: From<ScalarBuffer<i128>>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: From<ScalarBuffer<u8>>,
+{
+    (
+        ScalarBuffer::<i128>::from(views.into_inner()).into(),
+        buffers
+            .into_iter()
+            .map(|buffer| ScalarBuffer::from(buffer).into())
+            .collect(),
+    )
+}
+
+impl<Buffer: BufferType> From<BinaryViewArray<false, Buffer>> for arrow_array::BinaryViewArray
+where
+    <Buffer as BufferType>::Buffer<i128>: Into<ScalarBuffer<i128>>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Into<ScalarBuffer<u8>>,
+{
+    fn from(value: BinaryViewArray<false, Buffer>) -> Self {
+        let (views, buffers) = into_arrow_parts(value.views.0, value.buffers);
+        // Safety:
+        // - The narrow views are valid views into the data buffers
+        unsafe { arrow_array::BinaryViewArray::new_unchecked(views, buffers, None) }
+    }
+}
+
+impl<Buffer: BufferType> From<BinaryViewArray<true, Buffer>> for arrow_array::BinaryViewArray
+where
+    <Buffer as BufferType>::Buffer<i128>: Into<ScalarBuffer<i128>>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: Into<ScalarBuffer<u8>>,
+    Bitmap<Buffer>: Into<NullBuffer>,
+{
+    fn from(value: BinaryViewArray<true, Buffer>) -> Self {
+        let (views, buffers) = into_arrow_parts(value.views.0.data, value.buffers);
+        // Safety:
+        // - The narrow views are valid views into the data buffers
+        unsafe {
+            arrow_array::BinaryViewArray::new_unchecked(
+                views,
+                buffers,
+                Some(value.views.0.validity.into()),
+            )
+        }
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> From<BinaryViewArray<NULLABLE, Buffer>>
+    for Arc<dyn arrow_array::Array>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    arrow_array::BinaryViewArray: From<BinaryViewArray<NULLABLE, Buffer>>,
+{
+    fn from(value: BinaryViewArray<NULLABLE, Buffer>) -> Self {
+        Arc::new(arrow_array::BinaryViewArray::from(value))
+    }
+}
+
+impl<Buffer: BufferType> TryFrom<arrow_array::BinaryViewArray> for BinaryViewArray<false, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: From<ScalarBuffer<i128>>,
+    FixedSizePrimitiveArray<u8, false, Buffer>: From<ScalarBuffer<u8>>,
+{
+    type Error = Error;
+
+    fn try_from(value: arrow_array::BinaryViewArray) -> Result<Self, Self::Error> {
+        let (arrow_views, arrow_buffers, nulls_opt) = value.into_parts();
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        let (views, buffers) = from_arrow_parts(arrow_views, arrow_buffers);
+        Ok(BinaryViewArray {
+            views: FixedSizePrimitiveArray(views),
+            buffers,
+        })
+    }
+}
+
+impl<Buffer: BufferType> TryFrom<arrow_array::BinaryViewArray> for BinaryViewArray<true, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: From<ScalarBuffer<i128>> + crate::Length,
+    FixedSizePrimitiveArray<u8, false, Buffer>: From<ScalarBuffer<u8>>,
+    Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
+{
+    type Error = Error;
+
+    fn try_from(value: arrow_array::BinaryViewArray) -> Result<Self, Self::Error> {
+        let (arrow_views, arrow_buffers, nulls_opt) = value.into_parts();
+        let (views, buffers) = from_arrow_parts(arrow_views, arrow_buffers);
+        Ok(match nulls_opt {
+            Some(null_buffer) => BinaryViewArray {
+                views: FixedSizePrimitiveArray(Nullable {
+                    data: views,
+                    validity: null_buffer.into(),
+                }),
+                buffers,
+            },
+            None => BinaryViewArray::<false, Buffer> {
+                views: FixedSizePrimitiveArray(views),
+                buffers,
+            }
+            .into(),
+        })
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> TryFrom<Arc<dyn arrow_array::Array>>
+    for BinaryViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    Vec<u8>: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::BinaryViewArray, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::BinaryViewArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+    use arrow_array::Array as _;
+
+    const INPUT: [&[u8]; 3] = [b"hello", b"", b"a value that is not inlined"];
+    const INPUT_NULLABLE: [Option<&[u8]>; 3] =
+        [Some(b"a value that is not inlined"), None, Some(b"!")];
+
+    #[test]
+    fn from() {
+        let binary_view = INPUT.into_iter().collect::<BinaryViewArray>();
+        let array = arrow_array::BinaryViewArray::from(binary_view);
+        assert_eq!(array.data_buffers().len(), 1);
+        assert_eq!(array.iter().flatten().collect::<Vec<_>>(), INPUT);
+
+        let binary_view_nullable = INPUT_NULLABLE
+            .into_iter()
+            .collect::<BinaryViewArray<true>>();
+        let array_nullable: Arc<dyn arrow_array::Array> = binary_view_nullable.into();
+        assert_eq!(array_nullable.data_type(), &DataType::BinaryView);
+        assert_eq!(array_nullable.null_count(), 1);
+    }
+
+    #[test]
+    fn into() {
+        let array = arrow_array::BinaryViewArray::from_iter_values(INPUT);
+        let binary_view =
+            BinaryViewArray::<false, crate::arrow::buffer::ScalarBuffer>::try_from(array.clone())
+                .expect("conversion");
+        assert_eq!(binary_view.len(), 3);
+        assert_eq!(binary_view.index_checked(2), INPUT[2]);
+        assert_eq!(binary_view.into_iter().collect::<Vec<_>>(), INPUT);
+
+        let array_nullable = arrow_array::BinaryViewArray::from_iter(INPUT_NULLABLE);
+        assert_eq!(
+            BinaryViewArray::<false>::try_from(array_nullable.clone()).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+        let binary_view_nullable =
+            BinaryViewArray::<true>::try_from(array_nullable).expect("conversion");
+        assert_eq!(
+            binary_view_nullable.into_iter().collect::<Vec<_>>(),
+            INPUT_NULLABLE.map(|opt| opt.map(<[u8]>::to_vec))
+        );
+    }
+
+    #[test]
+    fn into_wrong_data_type() {
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::BinaryArray::from_iter_values(INPUT));
+        assert_eq!(
+            BinaryViewArray::<false>::try_from(array).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::BinaryView,
+                actual: DataType::Binary,
+            })
+        );
+    }
+}
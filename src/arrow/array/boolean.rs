@@ -10,7 +10,7 @@ use crate::{
     buffer::BufferType,
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Length,
+    Error, Length,
 };
 
 impl<const NULLABLE: bool, Buffer: BufferType> crate::arrow::Array
@@ -60,46 +60,53 @@ where
     }
 }
 
-/// Panics when there are nulls
-impl<Buffer: BufferType> From<arrow_array::BooleanArray> for BooleanArray<false, Buffer>
+impl<Buffer: BufferType> TryFrom<arrow_array::BooleanArray> for BooleanArray<false, Buffer>
 where
     Bitmap<Buffer>: From<arrow_buffer::BooleanBuffer>,
 {
-    fn from(value: arrow_array::BooleanArray) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::BooleanArray) -> Result<Self, Self::Error> {
         let (boolean_buffer, nulls_opt) = value.into_parts();
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => BooleanArray(boolean_buffer.into()),
-        }
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(BooleanArray(boolean_buffer.into()))
     }
 }
 
-impl<Buffer: BufferType> From<arrow_array::BooleanArray> for BooleanArray<true, Buffer>
+impl<Buffer: BufferType> TryFrom<arrow_array::BooleanArray> for BooleanArray<true, Buffer>
 where
     Bitmap<Buffer>:
         From<arrow_buffer::BooleanBuffer> + From<arrow_buffer::NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::BooleanArray) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::BooleanArray) -> Result<Self, Self::Error> {
         let (boolean_buffer, nulls_opt) = value.into_parts();
         let data = boolean_buffer.into();
-        match nulls_opt {
+        Ok(match nulls_opt {
             Some(null_buffer) => BooleanArray(Nullable {
                 data,
                 validity: null_buffer.into(),
             }),
             None => BooleanArray::<false, Buffer>(data).into(),
-        }
+        })
     }
 }
 
-impl<const NULLABLE: bool, Buffer: BufferType> From<Arc<dyn arrow_array::Array>>
+impl<const NULLABLE: bool, Buffer: BufferType> TryFrom<Arc<dyn arrow_array::Array>>
     for BooleanArray<NULLABLE, Buffer>
 where
     Bitmap<Buffer>: Validity<NULLABLE>,
-    Self: From<arrow_array::BooleanArray>,
+    bool: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::BooleanArray, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::BooleanArray::from(value.to_data()))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::BooleanArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
     }
 }
 
@@ -119,10 +126,15 @@ impl<Buffer: BufferType> PartialEq<arrow_array::BooleanArray> for BooleanArray<t
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::Array as _;
+
     use crate::{
         array::BooleanArray,
         bitmap::ValidityBitmap,
         buffer::{BufferType, VecBuffer},
+        Error,
     };
 
     const INPUT: [bool; 4] = [true, true, false, true];
@@ -153,17 +165,19 @@ mod tests {
         }
         fn into<Buffer: BufferType>()
         where
-            BooleanArray<false, Buffer>: From<arrow_array::BooleanArray>,
-            BooleanArray<true, Buffer>: From<arrow_array::BooleanArray>,
+            BooleanArray<false, Buffer>: TryFrom<arrow_array::BooleanArray, Error = Error>,
+            BooleanArray<true, Buffer>: TryFrom<arrow_array::BooleanArray, Error = Error>,
         {
             let array_arrow = arrow_array::BooleanArray::from(INPUT.to_vec());
             let array_arrow_nullable = arrow_array::BooleanArray::from(INPUT_NULLABLE.to_vec());
             assert_eq!(
-                BooleanArray::<false, Buffer>::from(array_arrow.clone()),
+                BooleanArray::<false, Buffer>::try_from(array_arrow.clone())
+                    .expect("array without nulls"),
                 array_arrow
             );
             assert_eq!(
-                BooleanArray::<true, Buffer>::from(array_arrow_nullable.clone()),
+                BooleanArray::<true, Buffer>::try_from(array_arrow_nullable.clone())
+                    .expect("nullable array"),
                 array_arrow_nullable
             );
         }
@@ -184,13 +198,44 @@ mod tests {
     #[test]
     fn into_nullable() {
         let array = arrow_array::BooleanArray::from(INPUT.to_vec());
-        assert!(!BooleanArray::<true>::from(array).any_null());
+        assert!(!BooleanArray::<true>::try_from(array)
+            .expect("nullable array")
+            .any_null());
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let array_nullable = arrow_array::BooleanArray::from(INPUT_NULLABLE.to_vec());
-        let _ = BooleanArray::<false>::from(array_nullable);
+        assert_eq!(
+            BooleanArray::<false>::try_from(array_nullable).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+
+        // A null buffer without nulls is accepted
+        let array_without_nulls = arrow_array::BooleanArray::new(
+            vec![true, false].into(),
+            Some(arrow_buffer::NullBuffer::new_valid(2)),
+        );
+        assert!(array_without_nulls.nulls().is_some());
+        assert_eq!(
+            BooleanArray::<false>::try_from(array_without_nulls)
+                .expect("array without nulls")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [true, false]
+        );
+    }
+
+    #[test]
+    fn into_wrong_data_type() {
+        let array: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::UInt8Array::from(vec![1]));
+        assert_eq!(
+            BooleanArray::<false>::try_from(array).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: arrow_schema::DataType::Boolean,
+                actual: arrow_schema::DataType::UInt8
+            })
+        );
     }
 }
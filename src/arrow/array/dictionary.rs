@@ -0,0 +1,328 @@
+//! Interop with [`arrow-rs`] dictionary array.
+
+use std::sync::Arc;
+
+use arrow_array::types::{ArrowDictionaryKeyType, ArrowPrimitiveType as _};
+use arrow_buffer::ArrowNativeType as _;
+use arrow_schema::{DataType, Field};
+
+use super::fixed_size_primitive::FixedSizeExt;
+use crate::{
+    array::{DictionaryArray, DictionaryKey, FixedSizePrimitiveArray},
+    buffer::BufferType,
+    validity::{Nullability, Validity},
+    Error, Length,
+};
+
+impl<
+        K: DictionaryKey + FixedSizeExt,
+        V: crate::arrow::Array,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+    > crate::arrow::Array for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    <V as crate::array::Array>::Item: Nullability<NULLABLE>,
+    <K as FixedSizeExt>::ArrowPrimitiveType: ArrowDictionaryKeyType,
+{
+    type Array = arrow_array::DictionaryArray<<K as FixedSizeExt>::ArrowPrimitiveType>;
+
+    fn as_field(name: &str) -> arrow_schema::Field {
+        Field::new(name, Self::data_type(), NULLABLE)
+    }
+
+    fn data_type() -> arrow_schema::DataType {
+        DataType::Dictionary(
+            Box::new(<K as FixedSizeExt>::ArrowPrimitiveType::DATA_TYPE),
+            Box::new(V::data_type()),
+        )
+    }
+}
+
+impl<
+        K: DictionaryKey + FixedSizeExt,
+        V: crate::array::Array,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+    > From<DictionaryArray<K, V, NULLABLE, Buffer>>
+    for arrow_array::DictionaryArray<<K as FixedSizeExt>::ArrowPrimitiveType>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    <K as FixedSizeExt>::ArrowPrimitiveType: ArrowDictionaryKeyType,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>:
+        Into<arrow_array::PrimitiveArray<<K as FixedSizeExt>::ArrowPrimitiveType>>,
+    V: Into<Arc<dyn arrow_array::Array>>,
+{
+    fn from(value: DictionaryArray<K, V, NULLABLE, Buffer>) -> Self {
+        arrow_array::DictionaryArray::new(value.keys.into(), value.values.into())
+    }
+}
+
+impl<
+        K: DictionaryKey + FixedSizeExt,
+        V: crate::array::Array,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+    > From<DictionaryArray<K, V, NULLABLE, Buffer>> for Arc<dyn arrow_array::Array>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    <K as FixedSizeExt>::ArrowPrimitiveType: ArrowDictionaryKeyType,
+    arrow_array::DictionaryArray<<K as FixedSizeExt>::ArrowPrimitiveType>:
+        From<DictionaryArray<K, V, NULLABLE, Buffer>>,
+{
+    fn from(value: DictionaryArray<K, V, NULLABLE, Buffer>) -> Self {
+        Arc::new(arrow_array::DictionaryArray::from(value))
+    }
+}
+
+impl<
+        K: DictionaryKey + FixedSizeExt,
+        V: crate::array::Array,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+    > TryFrom<arrow_array::DictionaryArray<<K as FixedSizeExt>::ArrowPrimitiveType>>
+    for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    <K as FixedSizeExt>::ArrowPrimitiveType: ArrowDictionaryKeyType,
+    FixedSizePrimitiveArray<K, NULLABLE, Buffer>: TryFrom<
+        arrow_array::PrimitiveArray<<K as FixedSizeExt>::ArrowPrimitiveType>,
+        Error = Error,
+    >,
+    V: TryFrom<Arc<dyn arrow_array::Array>, Error = Error> + Length,
+{
+    type Error = Error;
+
+    fn try_from(
+        value: arrow_array::DictionaryArray<<K as FixedSizeExt>::ArrowPrimitiveType>,
+    ) -> Result<Self, Self::Error> {
+        let (keys, values) = value.into_parts();
+        // All values must be addressable by the key type.
+        let len = arrow_array::Array::len(values.as_ref());
+        if let Some(last) = len
+            .checked_sub(1)
+            .filter(|&last| K::from_index(last).is_none())
+        {
+            return Err(Error::InvalidValue {
+                path: Vec::new(),
+                index: last,
+                message: format!("{len} dictionary values overflow the key type"),
+            });
+        }
+        // Keys of valid items must be indices into the values array.
+        if let Some((index, key)) = keys.iter().enumerate().find_map(|(index, key_opt)| {
+            key_opt
+                .filter(|key| key.to_usize().map_or(true, |key_index| key_index >= len))
+                .map(|key| (index, key))
+        }) {
+            return Err(Error::InvalidValue {
+                path: Vec::new(),
+                index,
+                message: format!("dictionary key {key:?} out of bounds for {len} values"),
+            });
+        }
+        Ok(DictionaryArray::new(keys.try_into()?, values.try_into()?))
+    }
+}
+
+impl<
+        K: DictionaryKey + FixedSizeExt,
+        V: crate::arrow::Array,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+    > TryFrom<Arc<dyn arrow_array::Array>> for DictionaryArray<K, V, NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<K>: Validity<NULLABLE>,
+    <V as crate::array::Array>::Item: Nullability<NULLABLE>,
+    <K as FixedSizeExt>::ArrowPrimitiveType: ArrowDictionaryKeyType,
+    Self: TryFrom<
+        arrow_array::DictionaryArray<<K as FixedSizeExt>::ArrowPrimitiveType>,
+        Error = Error,
+    >,
+{
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<
+            arrow_array::DictionaryArray<<K as FixedSizeExt>::ArrowPrimitiveType>,
+        >(
+            value.as_ref(), <Self as crate::arrow::Array>::data_type
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{array::StringArray, Index};
+    use arrow_array::{cast::AsArray, types::Int32Type, types::UInt8Type, Array as _};
+
+    #[test]
+    fn from() {
+        let dictionary = ["NL", "US", "NL"]
+            .map(ToOwned::to_owned)
+            .into_iter()
+            .collect::<DictionaryArray<u8, StringArray>>();
+        let array = arrow_array::DictionaryArray::<UInt8Type>::from(dictionary);
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.keys().values().as_ref(), [0, 1, 0]);
+        assert_eq!(
+            array
+                .values()
+                .as_string::<i32>()
+                .iter()
+                .flatten()
+                .collect::<Vec<_>>(),
+            ["NL", "US"]
+        );
+
+        let nullable = [Some("a"), None, Some("a")]
+            .map(|x| x.map(ToOwned::to_owned))
+            .into_iter()
+            .collect::<DictionaryArray<i32, StringArray, true>>();
+        let array_nullable: Arc<dyn arrow_array::Array> = nullable.into();
+        assert_eq!(
+            array_nullable.data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        assert_eq!(array_nullable.null_count(), 1);
+    }
+
+    #[test]
+    fn into() {
+        let array: arrow_array::DictionaryArray<Int32Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let mut dictionary =
+            DictionaryArray::<i32, StringArray, true>::try_from(array.clone()).expect("conversion");
+        assert_eq!(dictionary.len(), 4);
+        assert_eq!(dictionary.index_checked(0), Some("a"));
+        assert_eq!(dictionary.index_checked(1), None);
+        dictionary.extend([Some("b".to_owned()), Some("c".to_owned())]);
+        assert_eq!(dictionary.values.len(), 3);
+        assert_eq!(dictionary.index_checked(4), Some("b"));
+        assert_eq!(dictionary.index_checked(5), Some("c"));
+
+        assert_eq!(
+            DictionaryArray::<i32, StringArray>::try_from(array).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_invalid_keys() {
+        let values: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::StringArray::from(vec!["a"]));
+        // Safety:
+        // - Invalid keys are rejected by the conversion.
+        let negative = unsafe {
+            arrow_array::DictionaryArray::<Int32Type>::new_unchecked(
+                arrow_array::Int32Array::from(vec![0, -1]),
+                Arc::clone(&values),
+            )
+        };
+        assert_eq!(
+            DictionaryArray::<i32, StringArray>::try_from(negative).err(),
+            Some(Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: "dictionary key -1 out of bounds for 1 values".to_owned(),
+            })
+        );
+
+        // Safety:
+        // - Invalid keys are rejected by the conversion.
+        let out_of_bounds = unsafe {
+            arrow_array::DictionaryArray::<Int32Type>::new_unchecked(
+                arrow_array::Int32Array::from(vec![Some(0), None, Some(1)]),
+                values,
+            )
+        };
+        assert!(matches!(
+            DictionaryArray::<i32, StringArray, true>::try_from(out_of_bounds),
+            Err(Error::InvalidValue { index: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn into_too_many_values() {
+        let values: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::StringArray::from(
+            (0..300).map(|value| value.to_string()).collect::<Vec<_>>(),
+        ));
+        let array = arrow_array::DictionaryArray::<UInt8Type>::new(
+            arrow_array::UInt8Array::from(vec![0, 255]),
+            values,
+        );
+        assert_eq!(
+            DictionaryArray::<u8, StringArray>::try_from(array).err(),
+            Some(Error::InvalidValue {
+                path: Vec::new(),
+                index: 299,
+                message: "300 dictionary values overflow the key type".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    fn into_wrong_data_type() {
+        let array: arrow_array::DictionaryArray<Int32Type> = vec!["a", "b"].into_iter().collect();
+        let array_ref: Arc<dyn arrow_array::Array> = Arc::new(array);
+        assert_eq!(
+            DictionaryArray::<u8, StringArray>::try_from(array_ref).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Utf8)),
+                actual: DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn derived() {
+        use crate::array::StructArray;
+
+        #[derive(crate::ArrayType, Clone, Debug, PartialEq)]
+        struct Order {
+            id: u32,
+            #[narrow(dictionary)]
+            country: String,
+            #[narrow(dictionary)]
+            status: Option<String>,
+        }
+
+        let input = [
+            Order {
+                id: 1,
+                country: "NL".to_owned(),
+                status: Some("open".to_owned()),
+            },
+            Order {
+                id: 2,
+                country: "NL".to_owned(),
+                status: None,
+            },
+            Order {
+                id: 3,
+                country: "US".to_owned(),
+                status: Some("open".to_owned()),
+            },
+        ];
+        let array = input.clone().into_iter().collect::<StructArray<Order>>();
+        assert_eq!(array.0.country.values.len(), 2);
+        assert_eq!(array.0.status.values.len(), 1);
+
+        let fields = StructArray::<Order>::schema().fields().clone();
+        assert_eq!(
+            fields[1].data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        assert!(!fields[1].is_nullable());
+        assert!(fields[2].is_nullable());
+
+        let struct_array = arrow_array::StructArray::from(array);
+        let roundtrip: StructArray<Order> = struct_array.try_into().expect("struct array");
+        assert_eq!(roundtrip.into_iter().collect::<Vec<_>>(), input);
+    }
+}
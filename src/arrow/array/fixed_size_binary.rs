@@ -11,6 +11,7 @@ use crate::{
     buffer::BufferType,
     nullable::Nullable,
     validity::{Nullability, Validity},
+    Error,
 };
 
 impl<const N: usize, const NULLABLE: bool, Buffer: BufferType> crate::arrow::Array
@@ -37,14 +38,35 @@ where
     }
 }
 
-impl<const N: usize, const NULLABLE: bool, Buffer: BufferType> From<Arc<dyn arrow_array::Array>>
+impl<const N: usize, const NULLABLE: bool, Buffer: BufferType> TryFrom<Arc<dyn arrow_array::Array>>
     for FixedSizeBinaryArray<N, NULLABLE, Buffer>
 where
     FixedSizePrimitiveArray<u8, false, Buffer>: Validity<NULLABLE>,
-    Self: From<arrow_array::FixedSizeBinaryArray>,
+    [u8; N]: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::FixedSizeBinaryArray, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::FixedSizeBinaryArray::from(value.to_data()))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::FixedSizeBinaryArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
+    }
+}
+
+/// Returns an [`Error::DataType`] when the value length `n` of a fixed-size
+/// binary array is not `N`.
+fn check_value_length<const N: usize>(n: i32) -> Result<(), Error> {
+    let expected = i32::try_from(N).expect("overflow");
+    if n == expected {
+        Ok(())
+    } else {
+        Err(Error::DataType {
+            path: Vec::new(),
+            expected: DataType::FixedSizeBinary(expected),
+            actual: DataType::FixedSizeBinary(n),
+        })
     }
 }
 
@@ -102,41 +124,42 @@ where
     }
 }
 
-/// Panics when there are nulls
-impl<const N: usize, Buffer: BufferType> From<arrow_array::FixedSizeBinaryArray>
+impl<const N: usize, Buffer: BufferType> TryFrom<arrow_array::FixedSizeBinaryArray>
     for FixedSizeBinaryArray<N, false, Buffer>
 where
     FixedSizePrimitiveArray<u8, false, Buffer>: From<arrow_buffer::ScalarBuffer<u8>>,
 {
-    fn from(value: arrow_array::FixedSizeBinaryArray) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::FixedSizeBinaryArray) -> Result<Self, Self::Error> {
         let (n, values, nulls_opt) = value.into_parts();
-        assert_eq!(N, n.try_into().expect("size to cast to usize"));
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => FixedSizeBinaryArray(FixedSizeListArray(
-                arrow_buffer::ScalarBuffer::from(values).into(),
-            )),
-        }
+        check_value_length::<N>(n)?;
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(FixedSizeBinaryArray(FixedSizeListArray(
+            arrow_buffer::ScalarBuffer::from(values).into(),
+        )))
     }
 }
 
-impl<const N: usize, Buffer: BufferType> From<arrow_array::FixedSizeBinaryArray>
+impl<const N: usize, Buffer: BufferType> TryFrom<arrow_array::FixedSizeBinaryArray>
     for FixedSizeBinaryArray<N, true, Buffer>
 where
     FixedSizePrimitiveArray<u8, false, Buffer>: From<arrow_buffer::ScalarBuffer<u8>>,
     Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::FixedSizeBinaryArray) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::FixedSizeBinaryArray) -> Result<Self, Self::Error> {
         let (n, values, nulls_opt) = value.into_parts();
-        assert_eq!(N, n.try_into().expect("size to cast to usize"));
+        check_value_length::<N>(n)?;
         let data = arrow_buffer::ScalarBuffer::from(values).into();
-        match nulls_opt {
+        Ok(match nulls_opt {
             Some(null_buffer) => FixedSizeBinaryArray(FixedSizeListArray(Nullable {
                 data,
                 validity: null_buffer.into(),
             })),
             None => FixedSizeBinaryArray::<N, false, Buffer>(FixedSizeListArray(data)).into(),
-        }
+        })
     }
 }
 
@@ -184,15 +207,35 @@ mod tests {
     fn into_nullable() {
         let fixed_size_binary_array =
             arrow_array::FixedSizeBinaryArray::try_from_iter(INPUT.into_iter()).expect("");
-        assert!(!FixedSizeBinaryArray::<2, true>::from(fixed_size_binary_array).any_null());
+        assert!(
+            !FixedSizeBinaryArray::<2, true>::try_from(fixed_size_binary_array)
+                .expect("nullable array")
+                .any_null()
+        );
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let fixed_size_binary_array_nullable =
             arrow_array::FixedSizeBinaryArray::from(vec![None, Some([1_u8, 2, 3].as_slice())]);
-        let _ = FixedSizeBinaryArray::<3, false>::from(fixed_size_binary_array_nullable);
+        assert_eq!(
+            FixedSizeBinaryArray::<3, false>::try_from(fixed_size_binary_array_nullable).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_wrong_size() {
+        let fixed_size_binary_array =
+            arrow_array::FixedSizeBinaryArray::try_from_iter(INPUT.into_iter()).expect("");
+        assert_eq!(
+            FixedSizeBinaryArray::<3, false>::try_from(fixed_size_binary_array).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::FixedSizeBinary(3),
+                actual: DataType::FixedSizeBinary(2)
+            })
+        );
     }
 
     #[test]
@@ -200,7 +243,8 @@ mod tests {
         let fixed_size_binary_array =
             arrow_array::FixedSizeBinaryArray::try_from_iter(INPUT.into_iter()).expect("");
         assert_eq!(
-            FixedSizeBinaryArray::<2, false>::from(fixed_size_binary_array)
+            FixedSizeBinaryArray::<2, false>::try_from(fixed_size_binary_array)
+                .expect("array without nulls")
                 .into_iter()
                 .flatten()
                 .collect::<Vec<_>>(),
@@ -213,7 +257,8 @@ mod tests {
         let fixed_size_binary_array_nullable =
             arrow_array::FixedSizeBinaryArray::from(fixed_size_binary_array_nullable_input);
         assert_eq!(
-            FixedSizeBinaryArray::<2, true>::from(fixed_size_binary_array_nullable)
+            FixedSizeBinaryArray::<2, true>::try_from(fixed_size_binary_array_nullable)
+                .expect("nullable array")
                 .into_iter()
                 .collect::<Vec<_>>(),
             INPUT_NULLABLE
@@ -11,7 +11,7 @@ use crate::{
     buffer::BufferType,
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Length,
+    Error, Length,
 };
 
 impl<const N: usize, T: crate::arrow::Array, const NULLABLE: bool, Buffer: BufferType>
@@ -41,14 +41,20 @@ where
     }
 }
 
-impl<const N: usize, T: Array, const NULLABLE: bool, Buffer: BufferType>
-    From<Arc<dyn arrow_array::Array>> for FixedSizeListArray<N, T, NULLABLE, Buffer>
+impl<const N: usize, T: crate::arrow::Array, const NULLABLE: bool, Buffer: BufferType>
+    TryFrom<Arc<dyn arrow_array::Array>> for FixedSizeListArray<N, T, NULLABLE, Buffer>
 where
     T: Validity<NULLABLE>,
-    Self: From<arrow_array::FixedSizeListArray>,
+    [<T as Array>::Item; N]: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::FixedSizeListArray, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::FixedSizeListArray::from(value.to_data()))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::FixedSizeListArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
     }
 }
 
@@ -128,41 +134,66 @@ where
     }
 }
 
-/// Panics when there are nulls
 impl<const N: usize, T: crate::arrow::Array, Buffer: BufferType>
-    From<arrow_array::FixedSizeListArray> for FixedSizeListArray<N, T, false, Buffer>
+    TryFrom<arrow_array::FixedSizeListArray> for FixedSizeListArray<N, T, false, Buffer>
 where
-    T: From<Arc<dyn arrow_array::Array>>,
+    T: TryFrom<Arc<dyn arrow_array::Array>, Error = Error>,
 {
-    fn from(value: arrow_array::FixedSizeListArray) -> Self {
-        let (_field, size, values, nulls_opt) = value.into_parts();
-        let n = usize::try_from(size).expect("size to cast to usize");
-        assert_eq!(N, n);
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => FixedSizeListArray(values.into()),
-        }
+    type Error = Error;
+
+    fn try_from(value: arrow_array::FixedSizeListArray) -> Result<Self, Self::Error> {
+        check_value_length::<N, T, false, Buffer>(&value)?;
+        let (field, _size, values, nulls_opt) = value.into_parts();
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(FixedSizeListArray(
+            values
+                .try_into()
+                .map_err(|error: Error| error.with_field(field.name()))?,
+        ))
     }
 }
 
 impl<const N: usize, T: crate::arrow::Array, Buffer: BufferType>
-    From<arrow_array::FixedSizeListArray> for FixedSizeListArray<N, T, true, Buffer>
+    TryFrom<arrow_array::FixedSizeListArray> for FixedSizeListArray<N, T, true, Buffer>
 where
-    T: From<Arc<dyn arrow_array::Array>> + Length,
+    T: TryFrom<Arc<dyn arrow_array::Array>, Error = Error> + Length,
     Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::FixedSizeListArray) -> Self {
-        let (_field, size, values, nulls_opt) = value.into_parts();
-        let n = usize::try_from(size).expect("size to cast to usize");
-        assert_eq!(N, n);
-        let data = values.into();
-        match nulls_opt {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::FixedSizeListArray) -> Result<Self, Self::Error> {
+        check_value_length::<N, T, true, Buffer>(&value)?;
+        let (field, _size, values, nulls_opt) = value.into_parts();
+        let data = values
+            .try_into()
+            .map_err(|error: Error| error.with_field(field.name()))?;
+        Ok(match nulls_opt {
             Some(null_buffer) => FixedSizeListArray(Nullable {
                 data,
                 validity: null_buffer.into(),
             }),
             None => FixedSizeListArray::<N, T, false, Buffer>(data).into(),
-        }
+        })
+    }
+}
+
+/// Returns an [`Error::DataType`] when the value length of `array` is not `N`.
+fn check_value_length<const N: usize, T, const NULLABLE: bool, Buffer: BufferType>(
+    array: &arrow_array::FixedSizeListArray,
+) -> Result<(), Error>
+where
+    T: crate::arrow::Array + Validity<NULLABLE>,
+    [<T as Array>::Item; N]: Nullability<NULLABLE>,
+{
+    if usize::try_from(array.value_length()).is_ok_and(|n| n == N) {
+        Ok(())
+    } else {
+        Err(Error::DataType {
+            path: Vec::new(),
+            expected:
+                <FixedSizeListArray<N, T, NULLABLE, Buffer> as crate::arrow::Array>::data_type(),
+            actual: arrow_array::Array::data_type(array).clone(),
+        })
     }
 }
 
@@ -192,7 +223,7 @@ mod tests {
                 .flatten()
                 .flat_map(|dyn_array| {
                     let array: Uint32Array<false, crate::arrow::buffer::ScalarBuffer> =
-                        dyn_array.into();
+                        dyn_array.try_into().expect("uint32 array");
                     array.into_iter().copied().collect::<Vec<_>>()
                 })
                 .collect::<Vec<_>>(),
@@ -207,10 +238,13 @@ mod tests {
                 .iter()
                 .flatten()
                 .flat_map(|dyn_array| {
-                    StringArray::<false, i32, crate::arrow::buffer::ScalarBuffer>::from(dyn_array)
-                        .into_iter()
-                        .map(ToOwned::to_owned)
-                        .collect::<Vec<_>>()
+                    StringArray::<false, i32, crate::arrow::buffer::ScalarBuffer>::try_from(
+                        dyn_array,
+                    )
+                    .expect("string array")
+                    .into_iter()
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>()
                 })
                 .collect::<Vec<_>>(),
             INPUT_NULLABLE
@@ -236,24 +270,59 @@ mod tests {
             Uint32Array<false, crate::arrow::buffer::ScalarBuffer>,
             true,
             crate::arrow::buffer::ScalarBuffer,
-        >::from(fixed_size_list_array)
+        >::try_from(fixed_size_list_array)
+        .expect("nullable array")
         .any_null());
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let fixed_size_list_array_nullable =
             arrow_array::FixedSizeListArray::from_iter_primitive::<UInt32Type, _, _>(
                 vec![Some(vec![Some(0), Some(1), Some(2)]), None],
                 3,
             );
-        let _ = FixedSizeListArray::<
-            3,
-            Uint32Array<false, crate::arrow::buffer::ScalarBuffer>,
-            false,
-            crate::arrow::buffer::ScalarBuffer,
-        >::from(fixed_size_list_array_nullable);
+        assert_eq!(
+            FixedSizeListArray::<
+                3,
+                Uint32Array<false, crate::arrow::buffer::ScalarBuffer>,
+                false,
+                crate::arrow::buffer::ScalarBuffer,
+            >::try_from(fixed_size_list_array_nullable)
+            .err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_invalid() {
+        let fixed_size_list_array = arrow_array::FixedSizeListArray::from_iter_primitive::<
+            UInt32Type,
+            _,
+            _,
+        >(vec![Some(vec![Some(0), None, Some(2)])], 3);
+        assert!(matches!(
+            FixedSizeListArray::<
+                2,
+                Uint32Array<false, crate::arrow::buffer::ScalarBuffer>,
+                false,
+                crate::arrow::buffer::ScalarBuffer,
+            >::try_from(fixed_size_list_array.clone())
+            .err(),
+            Some(Error::DataType { .. })
+        ));
+        assert_eq!(
+            FixedSizeListArray::<
+                3,
+                Uint32Array<false, crate::arrow::buffer::ScalarBuffer>,
+                false,
+                crate::arrow::buffer::ScalarBuffer,
+            >::try_from(fixed_size_list_array)
+            .err(),
+            Some(Error::Nullability {
+                path: vec!["item".to_owned()]
+            })
+        );
     }
 
     #[test]
@@ -272,7 +341,8 @@ mod tests {
                 Uint32Array<false, crate::arrow::buffer::ScalarBuffer>,
                 false,
                 crate::arrow::buffer::ScalarBuffer,
-            >::from(fixed_size_list_array)
+            >::try_from(fixed_size_list_array)
+            .expect("array without nulls")
             .into_iter()
             .flatten()
             .copied()
@@ -291,7 +361,8 @@ mod tests {
                 2,
                 StringArray<false, i32, crate::arrow::buffer::ScalarBuffer>,
                 true,
-            >::from(fixed_size_list_array_nullable)
+            >::try_from(fixed_size_list_array_nullable)
+            .expect("nullable array")
             .into_iter()
             .collect::<Vec<_>>(),
             INPUT_NULLABLE
@@ -17,7 +17,7 @@ use crate::{
     buffer::{Buffer, BufferType},
     nullable::Nullable,
     validity::{Nullability, Validity},
-    FixedSize, Length,
+    Error, FixedSize, Length,
 };
 
 /// Mapping between [`FixedSize`] types and [`arrow_array::types::ArrowPrimitiveType`].
@@ -50,6 +50,10 @@ impl FixedSizeExt for i32 {
 impl FixedSizeExt for i64 {
     type ArrowPrimitiveType = Int64Type;
 }
+#[cfg(feature = "half")]
+impl FixedSizeExt for half::f16 {
+    type ArrowPrimitiveType = arrow_array::types::Float16Type;
+}
 impl FixedSizeExt for f32 {
     type ArrowPrimitiveType = Float32Type;
 }
@@ -107,48 +111,59 @@ where
     }
 }
 
-/// Panics when there are nulls.
 impl<T: FixedSize, U: arrow_array::types::ArrowPrimitiveType<Native = T>, Buffer: BufferType>
-    From<arrow_array::PrimitiveArray<U>> for FixedSizePrimitiveArray<T, false, Buffer>
+    TryFrom<arrow_array::PrimitiveArray<U>> for FixedSizePrimitiveArray<T, false, Buffer>
 where
     <Buffer as BufferType>::Buffer<T>: From<arrow_buffer::ScalarBuffer<T>>,
 {
-    fn from(value: arrow_array::PrimitiveArray<U>) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::PrimitiveArray<U>) -> Result<Self, Self::Error> {
         let (_data_type, values, nulls_opt) = value.into_parts();
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => FixedSizePrimitiveArray(values.into()),
-        }
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(FixedSizePrimitiveArray(values.into()))
     }
 }
 
 impl<T: FixedSize, U: arrow_array::types::ArrowPrimitiveType<Native = T>, Buffer: BufferType>
-    From<arrow_array::PrimitiveArray<U>> for FixedSizePrimitiveArray<T, true, Buffer>
+    TryFrom<arrow_array::PrimitiveArray<U>> for FixedSizePrimitiveArray<T, true, Buffer>
 where
     <Buffer as BufferType>::Buffer<T>: From<arrow_buffer::ScalarBuffer<T>>,
     Bitmap<Buffer>: From<arrow_buffer::NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::PrimitiveArray<U>) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::PrimitiveArray<U>) -> Result<Self, Self::Error> {
         let (_data_type, values, nulls_opt) = value.into_parts();
         let data = values.into();
-        match nulls_opt {
+        Ok(match nulls_opt {
             Some(null_buffer) => FixedSizePrimitiveArray(Nullable {
                 data,
                 validity: null_buffer.into(),
             }),
             None => FixedSizePrimitiveArray::<T, false, Buffer>(data).into(),
-        }
+        })
     }
 }
 
-impl<const NULLABLE: bool, T: FixedSizeExt, Buffer: BufferType> From<Arc<dyn arrow_array::Array>>
+impl<const NULLABLE: bool, T: FixedSizeExt, Buffer: BufferType> TryFrom<Arc<dyn arrow_array::Array>>
     for FixedSizePrimitiveArray<T, NULLABLE, Buffer>
 where
     <Buffer as BufferType>::Buffer<T>: Validity<NULLABLE>,
-    Self: From<arrow_array::PrimitiveArray<<T as FixedSizeExt>::ArrowPrimitiveType>>,
+    T: Nullability<NULLABLE>,
+    Self: TryFrom<
+        arrow_array::PrimitiveArray<<T as FixedSizeExt>::ArrowPrimitiveType>,
+        Error = Error,
+    >,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::PrimitiveArray::from(value.to_data()))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<
+            arrow_array::PrimitiveArray<<T as FixedSizeExt>::ArrowPrimitiveType>,
+        >(
+            value.as_ref(), <Self as crate::arrow::Array>::data_type
+        )?)
     }
 }
 
@@ -202,7 +217,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use std::fmt::Debug;
+    use std::{fmt::Debug, sync::Arc};
 
     use arrow_array::types::UInt32Type;
 
@@ -210,6 +225,7 @@ mod tests {
         array::FixedSizePrimitiveArray,
         bitmap::ValidityBitmap,
         buffer::{BufferType, VecBuffer},
+        Error,
     };
 
     const INPUT: [u32; 4] = [1, 2, 3, 4];
@@ -246,8 +262,8 @@ mod tests {
         fn into<Buffer: BufferType>()
         where
             FixedSizePrimitiveArray<u32, false, Buffer>:
-                From<arrow_array::PrimitiveArray<UInt32Type>> + Debug,
-            FixedSizePrimitiveArray<u32, true, Buffer>: From<arrow_array::PrimitiveArray<UInt32Type>>
+                TryFrom<arrow_array::PrimitiveArray<UInt32Type>, Error = Error> + Debug,
+            FixedSizePrimitiveArray<u32, true, Buffer>: TryFrom<arrow_array::PrimitiveArray<UInt32Type>, Error = Error>
                 + Debug
                 + PartialEq<arrow_array::PrimitiveArray<UInt32Type>>,
         {
@@ -255,11 +271,15 @@ mod tests {
             let array_arrow_nullable =
                 arrow_array::PrimitiveArray::<UInt32Type>::from(INPUT_NULLABLE.to_vec());
             assert_eq!(
-                FixedSizePrimitiveArray::<u32, false, Buffer>::from(array_arrow.clone()),
+                FixedSizePrimitiveArray::<u32, false, Buffer>::try_from(array_arrow.clone())
+                    .expect("array without nulls"),
                 array_arrow
             );
             assert_eq!(
-                FixedSizePrimitiveArray::<u32, true, Buffer>::from(array_arrow_nullable.clone()),
+                FixedSizePrimitiveArray::<u32, true, Buffer>::try_from(
+                    array_arrow_nullable.clone()
+                )
+                .expect("nullable array"),
                 array_arrow_nullable
             );
         }
@@ -275,15 +295,62 @@ mod tests {
         let array = INPUT
             .into_iter()
             .collect::<arrow_array::PrimitiveArray<UInt32Type>>();
-        assert!(!FixedSizePrimitiveArray::<u32, true>::from(array).any_null());
+        assert!(!FixedSizePrimitiveArray::<u32, true>::try_from(array)
+            .expect("nullable array")
+            .any_null());
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let array_nullable = INPUT_NULLABLE
             .into_iter()
             .collect::<arrow_array::PrimitiveArray<UInt32Type>>();
-        let _ = FixedSizePrimitiveArray::<u32, false>::from(array_nullable);
+        assert_eq!(
+            FixedSizePrimitiveArray::<u32, false>::try_from(array_nullable).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_wrong_data_type() {
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::Int32Array::from(vec![1, 2]));
+        assert_eq!(
+            FixedSizePrimitiveArray::<u32, true>::try_from(array).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: arrow_schema::DataType::UInt32,
+                actual: arrow_schema::DataType::Int32
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "half")]
+    fn half() {
+        use half::f16;
+
+        use crate::array::Float16Array;
+
+        let input = [Some(f16::from_f32(1.5)), None, Some(f16::MAX)];
+        let array: Arc<dyn arrow_array::Array> =
+            input.into_iter().collect::<Float16Array<true>>().into();
+        assert_eq!(array.data_type(), &arrow_schema::DataType::Float16);
+        assert_eq!(
+            array
+                .as_any()
+                .downcast_ref::<arrow_array::Float16Array>()
+                .expect("float16 array")
+                .iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+        assert_eq!(
+            Float16Array::<true>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
     }
 }
@@ -0,0 +1,257 @@
+//! Interop with [`arrow-rs`] list view array.
+
+use std::sync::Arc;
+
+use arrow_array::OffsetSizeTrait;
+use arrow_buffer::{NullBuffer, ScalarBuffer};
+use arrow_schema::{DataType, Field};
+
+use crate::{
+    array::{Array, ListViewArray},
+    arrow::OffsetElement,
+    bitmap::Bitmap,
+    buffer::BufferType,
+    nullable::Nullable,
+    validity::{Nullability, Validity},
+    Error, Length,
+};
+
+impl<
+        T: crate::arrow::Array,
+        const NULLABLE: bool,
+        OffsetItem: OffsetElement + OffsetSizeTrait,
+        Buffer: BufferType,
+    > crate::arrow::Array for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Vec<<T as Array>::Item>: Nullability<NULLABLE>,
+{
+    type Array = arrow_array::GenericListViewArray<OffsetItem>;
+
+    fn as_field(name: &str) -> arrow_schema::Field {
+        Field::new(name, Self::data_type(), NULLABLE)
+    }
+
+    fn data_type() -> arrow_schema::DataType {
+        if OffsetItem::LARGE {
+            DataType::LargeListView(Arc::new(T::as_field("item")))
+        } else {
+            DataType::ListView(Arc::new(T::as_field("item")))
+        }
+    }
+}
+
+impl<
+        T: crate::arrow::Array,
+        const NULLABLE: bool,
+        OffsetItem: OffsetElement + OffsetSizeTrait,
+        Buffer: BufferType,
+    > TryFrom<Arc<dyn arrow_array::Array>> for ListViewArray<T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    Vec<<T as Array>::Item>: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::GenericListViewArray<OffsetItem>, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<
+            arrow_array::GenericListViewArray<OffsetItem>,
+        >(
+            value.as_ref(), <Self as crate::arrow::Array>::data_type
+        )?)
+    }
+}
+
+impl<
+        T: crate::arrow::Array,
+        const NULLABLE: bool,
+        OffsetItem: OffsetElement + OffsetSizeTrait,
+        Buffer: BufferType,
+    > From<ListViewArray<T, NULLABLE, OffsetItem, Buffer>> for Arc<dyn arrow_array::Array>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    arrow_array::GenericListViewArray<OffsetItem>:
+        From<ListViewArray<T, NULLABLE, OffsetItem, Buffer>>,
+{
+    fn from(value: ListViewArray<T, NULLABLE, OffsetItem, Buffer>) -> Self {
+        Arc::new(arrow_array::GenericListViewArray::from(value))
+    }
+}
+
+impl<T: crate::arrow::Array, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
+    From<ListViewArray<T, false, OffsetItem, Buffer>>
+    for arrow_array::GenericListViewArray<OffsetItem>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Into<ScalarBuffer<OffsetItem>>,
+    T: Into<Arc<dyn arrow_array::Array>>,
+{
+    fn from(value: ListViewArray<T, false, OffsetItem, Buffer>) -> Self {
+        arrow_array::GenericListViewArray::new(
+            Arc::new(T::as_field("item")),
+            value.offsets.into(),
+            value.sizes.into(),
+            value.data.into(),
+            None,
+        )
+    }
+}
+
+impl<T: crate::arrow::Array, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
+    From<ListViewArray<T, true, OffsetItem, Buffer>>
+    for arrow_array::GenericListViewArray<OffsetItem>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Into<ScalarBuffer<OffsetItem>>,
+    Bitmap<Buffer>: Into<NullBuffer>,
+    T: Into<Arc<dyn arrow_array::Array>>,
+{
+    fn from(value: ListViewArray<T, true, OffsetItem, Buffer>) -> Self {
+        arrow_array::GenericListViewArray::new(
+            Arc::new(T::as_field("item")),
+            value.offsets.data.into(),
+            value.sizes.into(),
+            value.data.into(),
+            Some(value.offsets.validity.into()),
+        )
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
+    TryFrom<arrow_array::GenericListViewArray<OffsetItem>>
+    for ListViewArray<T, false, OffsetItem, Buffer>
+where
+    T: TryFrom<Arc<dyn arrow_array::Array>, Error = Error>,
+    <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
+{
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericListViewArray<OffsetItem>) -> Result<Self, Self::Error> {
+        let (field, offsets, sizes, values, nulls_opt) = value.into_parts();
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(ListViewArray {
+            data: values
+                .try_into()
+                .map_err(|error: Error| error.with_field(field.name()))?,
+            offsets: offsets.into(),
+            sizes: sizes.into(),
+        })
+    }
+}
+
+impl<T: Array, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
+    TryFrom<arrow_array::GenericListViewArray<OffsetItem>>
+    for ListViewArray<T, true, OffsetItem, Buffer>
+where
+    T: TryFrom<Arc<dyn arrow_array::Array>, Error = Error>,
+    <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
+    Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
+{
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericListViewArray<OffsetItem>) -> Result<Self, Self::Error> {
+        let (field, offsets_buffer, sizes, values, nulls_opt) = value.into_parts();
+        let validity = match nulls_opt {
+            Some(null_buffer) => null_buffer.into(),
+            None => Bitmap::new_valid(sizes.len()),
+        };
+        Ok(ListViewArray {
+            data: values
+                .try_into()
+                .map_err(|error: Error| error.with_field(field.name()))?,
+            offsets: Nullable {
+                data: offsets_buffer.into(),
+                validity,
+            },
+            sizes: sizes.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{FixedSizePrimitiveArray, StringArray, VariableSizeListArray},
+        bitmap::ValidityBitmap,
+        Index,
+    };
+    use arrow_array::{cast::AsArray, types::UInt8Type, Array as _};
+
+    #[test]
+    fn from() {
+        let list_view = ListViewArray::<FixedSizePrimitiveArray<u8>> {
+            data: [1, 2, 3, 4].into_iter().collect(),
+            offsets: vec![2, 0],
+            sizes: vec![2, 3],
+        };
+        let array = arrow_array::ListViewArray::from(list_view);
+        assert_eq!(array.value_offsets(), [2, 0]);
+        assert_eq!(array.value_sizes(), [2, 3]);
+        assert_eq!(
+            array.value(1).as_primitive::<UInt8Type>().values().as_ref(),
+            [1, 2, 3]
+        );
+
+        let list_view_nullable =
+            [Some(vec!["a"]), None]
+                .into_iter()
+                .collect::<ListViewArray<StringArray, true, i64>>();
+        let array_nullable: Arc<dyn arrow_array::Array> = list_view_nullable.into();
+        assert_eq!(
+            array_nullable.data_type(),
+            &DataType::LargeListView(Arc::new(Field::new("item", DataType::Utf8, false)))
+        );
+        assert_eq!(array_nullable.null_count(), 1);
+    }
+
+    #[test]
+    fn into() {
+        let array = arrow_array::ListViewArray::new(
+            Arc::new(Field::new("item", DataType::UInt8, false)),
+            ScalarBuffer::from(vec![1, 0, 0]),
+            ScalarBuffer::from(vec![2, 1, 0]),
+            Arc::new(arrow_array::UInt8Array::from(vec![1, 2, 3])),
+            Some(NullBuffer::from(vec![true, true, false])),
+        );
+        let list_view = ListViewArray::<FixedSizePrimitiveArray<u8>, true>::try_from(array.clone())
+            .expect("conversion");
+        assert_eq!(list_view.len(), 3);
+        assert!(list_view.is_null(2).expect("in bounds"));
+        assert_eq!(
+            list_view
+                .index_checked(0)
+                .map(|list| list.copied().collect::<Vec<_>>()),
+            Some(vec![2, 3])
+        );
+        assert_eq!(
+            VariableSizeListArray::from(list_view)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(vec![2, 3]), Some(vec![1]), None]
+        );
+
+        assert_eq!(
+            ListViewArray::<FixedSizePrimitiveArray<u8>>::try_from(array).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_wrong_data_type() {
+        let field = Arc::new(Field::new("item", DataType::UInt8, false));
+        let array: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::ListArray::new(
+            Arc::clone(&field),
+            arrow_buffer::OffsetBuffer::new(ScalarBuffer::from(vec![0, 1])),
+            Arc::new(arrow_array::UInt8Array::from(vec![1])),
+            None,
+        ));
+        assert_eq!(
+            ListViewArray::<FixedSizePrimitiveArray<u8>>::try_from(array).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::ListView(Arc::clone(&field)),
+                actual: DataType::List(field),
+            })
+        );
+    }
+}
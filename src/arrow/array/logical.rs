@@ -2,7 +2,8 @@
 
 use std::sync::Arc;
 
-use arrow_array::OffsetSizeTrait;
+use arrow_array::{cast::AsArray as _, OffsetSizeTrait};
+use arrow_schema::{DataType, Field, FieldRef};
 
 use crate::{
     array::{ArrayType, UnionType},
@@ -10,9 +11,115 @@ use crate::{
     logical::{LogicalArray, LogicalArrayType},
     offset::OffsetElement,
     validity::Nullability,
+    Error,
 };
 
-// TODO(mbrobbel): add field metadata trait
+/// Returns `array` with its data type replaced by `data_type`.
+///
+/// Fixed-size lists of a different size are reshaped, see [`reshape`].
+///
+/// # Errors
+///
+/// Returns an [`Error::DataType`] when the physical layout of `array` doesn't
+/// match `data_type`.
+fn with_data_type(
+    array: &dyn arrow_array::Array,
+    data_type: &DataType,
+) -> Result<Arc<dyn arrow_array::Array>, Error> {
+    let data_type_error = || Error::DataType {
+        path: Vec::new(),
+        expected: data_type.clone(),
+        actual: array.data_type().clone(),
+    };
+    if let (&DataType::FixedSizeList(_, size), &DataType::FixedSizeList(ref field, target_size)) =
+        (array.data_type(), data_type)
+    {
+        if size != target_size {
+            return array
+                .as_fixed_size_list_opt()
+                .and_then(|fixed_size_list| reshape(fixed_size_list, field, target_size))
+                .ok_or_else(data_type_error);
+        }
+    }
+    array
+        .to_data()
+        .into_builder()
+        .data_type(data_type.clone())
+        .build_aligned()
+        .map(arrow_array::make_array)
+        .map_err(|_| data_type_error())
+}
+
+/// Returns the fixed-size list `array` as a fixed-size list of `size` items
+/// of `field`, or `None` when `array` can't be reshaped to `field`.
+///
+/// This converts between nested fixed-size lists, e.g. of `[[T; N]; M]`
+/// items, and flat fixed-size lists of their `M * N` values, which share the
+/// same values array.
+fn reshape(
+    array: &arrow_array::FixedSizeListArray,
+    field: &Field,
+    size: i32,
+) -> Option<Arc<dyn arrow_array::Array>> {
+    let values: Arc<dyn arrow_array::Array> = match *field.data_type() {
+        DataType::FixedSizeList(ref item, item_size) => {
+            // Values with nulls are rejected by the conversion of the items.
+            let nullable = item.is_nullable() || array.values().null_count() != 0;
+            Arc::new(
+                arrow_array::FixedSizeListArray::try_new(
+                    Arc::new(item.as_ref().clone().with_nullable(nullable)),
+                    item_size,
+                    Arc::clone(array.values()),
+                    None,
+                )
+                .ok()?,
+            )
+        }
+        _ => Arc::clone(array.values().as_fixed_size_list_opt()?.values()),
+    };
+    let fixed_size_list: Arc<dyn arrow_array::Array> = Arc::new(
+        arrow_array::FixedSizeListArray::try_new(
+            Arc::new(field.clone().with_data_type(values.data_type().clone())),
+            size,
+            values,
+            arrow_array::Array::nulls(array).cloned(),
+        )
+        .ok()?,
+    );
+    Some(fixed_size_list)
+}
+
+/// Returns `data_type` with the time zones of its timestamps that are
+/// equivalent to UTC, e.g. `+00:00` or `Etc/UTC`, replaced by `UTC`.
+///
+/// Writers use different names for UTC, so these are considered equal when
+/// arrays are converted.
+fn normalize_utc(data_type: &DataType) -> DataType {
+    let normalize_field = |field: &FieldRef| {
+        Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_data_type(normalize_utc(field.data_type())),
+        )
+    };
+    match *data_type {
+        DataType::Timestamp(unit, Some(ref time_zone))
+            if matches!(time_zone.as_ref(), "UTC" | "+00:00" | "Z" | "Etc/UTC") =>
+        {
+            DataType::Timestamp(unit, Some("UTC".into()))
+        }
+        DataType::Struct(ref fields) => {
+            DataType::Struct(fields.iter().map(normalize_field).collect())
+        }
+        DataType::List(ref field) => DataType::List(normalize_field(field)),
+        DataType::LargeList(ref field) => DataType::LargeList(normalize_field(field)),
+        DataType::FixedSizeList(ref field, size) => {
+            DataType::FixedSizeList(normalize_field(field), size)
+        }
+        _ => data_type.clone(),
+    }
+}
 
 impl<
         T: LogicalArrayType<T>,
@@ -36,25 +143,50 @@ where
         >>::Array<Buffer, OffsetItem, UnionLayout> as crate::arrow::Array>::Array;
 
     fn as_field(name: &str) -> arrow_schema::Field {
-        <<<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
-            <T as LogicalArrayType<T>>::ArrayType,
-        >>::Array<Buffer, OffsetItem, UnionLayout> as crate::arrow::Array>::as_field(name)
+        let field =
+            <<<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
+                <T as LogicalArrayType<T>>::ArrayType,
+            >>::Array<Buffer, OffsetItem, UnionLayout> as crate::arrow::Array>::as_field(
+                name
+            );
+        let mut metadata = field.metadata().clone();
+        if let Some((extension_name, extension_metadata)) = T::extension_type() {
+            metadata.extend(crate::arrow::extension_type(
+                extension_name,
+                extension_metadata,
+            ));
+        }
+        field
+            .with_data_type(Self::data_type())
+            .with_metadata(metadata)
     }
 
     fn data_type() -> arrow_schema::DataType {
-        <<<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
-            <T as LogicalArrayType<T>>::ArrayType,
-        >>::Array<Buffer, OffsetItem, UnionLayout> as crate::arrow::Array>::data_type()
+        T::data_type(<<<<T as LogicalArrayType<T>>::ArrayType as Nullability<
+            NULLABLE,
+        >>::Item as ArrayType<<T as LogicalArrayType<T>>::ArrayType>>::Array<
+            Buffer,
+            OffsetItem,
+            UnionLayout,
+        > as crate::arrow::Array>::data_type())
     }
 }
 
+/// Converts an array without a field.
+///
+/// Time zones of timestamps that are equivalent to UTC are accepted for
+/// logical types that store their timestamps in UTC.
+///
+/// Extension types are stored in the metadata of fields, so they can't be
+/// checked here. Use the conversion of `(FieldRef, Arc<dyn Array>)` to reject
+/// arrays of fields with a mismatching extension type.
 impl<
         T: LogicalArrayType<T>,
         const NULLABLE: bool,
         Buffer: BufferType,
         OffsetItem: OffsetElement,
         UnionLayout: UnionType,
-    > From<Arc<dyn arrow_array::Array>>
+    > TryFrom<Arc<dyn arrow_array::Array>>
     for LogicalArray<T, NULLABLE, Buffer, OffsetItem, UnionLayout>
 where
     Option<T>: ArrayType<T>,
@@ -63,10 +195,66 @@ where
         ArrayType<<T as LogicalArrayType<T>>::ArrayType>,
     <<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
         <T as LogicalArrayType<T>>::ArrayType,
-    >>::Array<Buffer, OffsetItem, UnionLayout>: From<Arc<dyn arrow_array::Array>>,
+    >>::Array<Buffer, OffsetItem, UnionLayout>:
+        TryFrom<Arc<dyn arrow_array::Array>, Error = Error> + crate::arrow::Array,
+{
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        let array_data_type =
+            <<<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
+                <T as LogicalArrayType<T>>::ArrayType,
+            >>::Array<Buffer, OffsetItem, UnionLayout> as crate::arrow::Array>::data_type(
+            );
+        let data_type = T::data_type(array_data_type.clone());
+        if data_type == array_data_type {
+            T::validate(value.as_ref())?;
+            value.try_into().map(Self)
+        } else if normalize_utc(value.data_type()) == normalize_utc(&data_type) {
+            T::validate(value.as_ref())?;
+            with_data_type(value.as_ref(), &array_data_type)?
+                .try_into()
+                .map(Self)
+        } else {
+            Err(Error::DataType {
+                path: Vec::new(),
+                expected: data_type,
+                actual: value.data_type().clone(),
+            })
+        }
+    }
+}
+
+/// Converts the array of a field, e.g. a column of a record batch.
+///
+/// Different from the conversion of arrays without a field, this rejects
+/// fields with a mismatching extension type.
+impl<
+        T: LogicalArrayType<T>,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+        UnionLayout: UnionType,
+    > TryFrom<(FieldRef, Arc<dyn arrow_array::Array>)>
+    for LogicalArray<T, NULLABLE, Buffer, OffsetItem, UnionLayout>
+where
+    Option<T>: ArrayType<T>,
+    <T as LogicalArrayType<T>>::ArrayType: Nullability<NULLABLE>,
+    <<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item:
+        ArrayType<<T as LogicalArrayType<T>>::ArrayType>,
+    Self: TryFrom<Arc<dyn arrow_array::Array>, Error = Error> + crate::arrow::Array,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self(value.into())
+    type Error = Error;
+
+    fn try_from(
+        (field, array): (FieldRef, Arc<dyn arrow_array::Array>),
+    ) -> Result<Self, Self::Error> {
+        crate::arrow::check_extension_type(
+            &<Self as crate::arrow::Array>::as_field(field.name()),
+            &field,
+        )
+        .and_then(|()| array.try_into())
+        .map_err(|error| error.with_field(field.name()))
     }
 }
 
@@ -88,7 +276,13 @@ where
     >>::Array<Buffer, OffsetItem, UnionLayout>: Into<Arc<dyn arrow_array::Array>>,
 {
     fn from(value: LogicalArray<T, NULLABLE, Buffer, OffsetItem, UnionLayout>) -> Self {
-        Arc::new(value.0.into())
+        let array: Arc<dyn arrow_array::Array> = value.0.into();
+        let data_type = T::data_type(array.data_type().clone());
+        if array.data_type() == &data_type {
+            array
+        } else {
+            with_data_type(array.as_ref(), &data_type).expect("matching physical layout")
+        }
     }
 }
 
@@ -167,6 +361,36 @@ where
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn with_data_type_mismatch() {
+        use std::sync::Arc;
+
+        use arrow_schema::{DataType, Field};
+
+        use super::with_data_type;
+        use crate::Error;
+
+        let int_array = arrow_array::Int32Array::from(vec![1, 2]);
+        assert!(matches!(
+            with_data_type(&int_array, &DataType::Utf8),
+            Err(Error::DataType { .. })
+        ));
+
+        let list_array = arrow_array::FixedSizeListArray::new(
+            Arc::new(Field::new("item", DataType::Int32, false)),
+            2,
+            Arc::new(arrow_array::Int32Array::from(vec![1, 2, 3, 4])),
+            None,
+        );
+        assert!(matches!(
+            with_data_type(
+                &list_array,
+                &DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Int32, false)), 4)
+            ),
+            Err(Error::DataType { .. })
+        ));
+    }
+
     #[test]
     #[cfg(feature = "derive")]
     fn optional_variable_size_list_logical() {
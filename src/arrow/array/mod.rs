@@ -1,14 +1,20 @@
 //! Interop with [`arrow-array`].
 
+mod binary_view;
 mod boolean;
+mod dictionary;
 mod fixed_size_binary;
 mod fixed_size_list;
 mod fixed_size_primitive;
 mod string;
+mod string_view;
 mod r#struct;
 pub use r#struct::StructArrayTypeFields;
+mod list_view;
 mod logical;
 mod null;
+mod run_end_encoded;
+mod tuple;
 mod union;
 pub use union::UnionArrayTypeFields;
 mod variable_size_binary;
@@ -6,7 +6,7 @@ use crate::{
     array::{NullArray, Nulls, Unit},
     buffer::BufferType,
     validity::{Nullability, Validity},
-    Length,
+    Error, Length,
 };
 use arrow_array::Array;
 use arrow_schema::{DataType, Field};
@@ -28,12 +28,17 @@ where
     }
 }
 
-impl<T: Unit, Buffer: BufferType> From<Arc<dyn arrow_array::Array>> for NullArray<T, false, Buffer>
-where
-    Self: From<arrow_array::NullArray>,
+impl<T: Unit, Buffer: BufferType> TryFrom<Arc<dyn arrow_array::Array>>
+    for NullArray<T, false, Buffer>
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::NullArray::from(value.to_data()))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        crate::arrow::downcast::<arrow_array::NullArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )
+        .map(Self::from)
     }
 }
 
@@ -100,9 +105,10 @@ mod tests {
             .is_null());
         assert_eq!(arrow_array_nested.len(), 4);
         let inner_unit = Arc::clone(arrow_array_nested.column(0).as_struct().column(0));
-        let narrow_array_inner = NullArray::<Unit>::from(inner_unit);
+        let narrow_array_inner = NullArray::<Unit>::try_from(inner_unit).expect("null array");
         assert_eq!(narrow_array_inner.len(), 4);
-        let narrow_array_nested = StructArray::<NestedUnit>::from(arrow_array_nested);
+        let narrow_array_nested =
+            StructArray::<NestedUnit>::try_from(arrow_array_nested).expect("struct array");
         assert_eq!(narrow_array_nested.len(), 4);
     }
 
@@ -0,0 +1,215 @@
+//! Interop with [`arrow-rs`] run array.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    types::{ArrowPrimitiveType as _, RunEndIndexType},
+    Array as _,
+};
+use arrow_buffer::ArrowNativeType as _;
+use arrow_schema::{DataType, Field};
+
+use super::fixed_size_primitive::FixedSizeExt;
+use crate::{
+    array::{FixedSizePrimitiveArray, RunEnd, RunEndEncodedArray},
+    buffer::BufferType,
+    Error,
+};
+
+impl<R: RunEnd + FixedSizeExt, V: crate::arrow::Array, Buffer: BufferType> crate::arrow::Array
+    for RunEndEncodedArray<R, V, Buffer>
+where
+    <R as FixedSizeExt>::ArrowPrimitiveType: RunEndIndexType,
+{
+    type Array = arrow_array::RunArray<<R as FixedSizeExt>::ArrowPrimitiveType>;
+
+    fn as_field(name: &str) -> arrow_schema::Field {
+        Field::new(name, Self::data_type(), V::as_field("values").is_nullable())
+    }
+
+    fn data_type() -> arrow_schema::DataType {
+        DataType::RunEndEncoded(
+            Arc::new(Field::new(
+                "run_ends",
+                <R as FixedSizeExt>::ArrowPrimitiveType::DATA_TYPE,
+                false,
+            )),
+            Arc::new(Field::new("values", V::data_type(), true)),
+        )
+    }
+}
+
+impl<R: RunEnd + FixedSizeExt, V: crate::array::Array, Buffer: BufferType>
+    From<RunEndEncodedArray<R, V, Buffer>>
+    for arrow_array::RunArray<<R as FixedSizeExt>::ArrowPrimitiveType>
+where
+    <R as FixedSizeExt>::ArrowPrimitiveType: RunEndIndexType,
+    FixedSizePrimitiveArray<R, false, Buffer>:
+        Into<arrow_array::PrimitiveArray<<R as FixedSizeExt>::ArrowPrimitiveType>>,
+    V: Into<Arc<dyn arrow_array::Array>>,
+{
+    fn from(value: RunEndEncodedArray<R, V, Buffer>) -> Self {
+        let values: Arc<dyn arrow_array::Array> = value.values.into();
+        arrow_array::RunArray::try_new(&value.run_ends.into(), values.as_ref())
+            .expect("valid run-end encoded array")
+    }
+}
+
+impl<R: RunEnd + FixedSizeExt, V: crate::array::Array, Buffer: BufferType>
+    From<RunEndEncodedArray<R, V, Buffer>> for Arc<dyn arrow_array::Array>
+where
+    <R as FixedSizeExt>::ArrowPrimitiveType: RunEndIndexType,
+    arrow_array::RunArray<<R as FixedSizeExt>::ArrowPrimitiveType>:
+        From<RunEndEncodedArray<R, V, Buffer>>,
+{
+    fn from(value: RunEndEncodedArray<R, V, Buffer>) -> Self {
+        Arc::new(arrow_array::RunArray::from(value))
+    }
+}
+
+impl<R: RunEnd + FixedSizeExt, V: crate::array::Array, Buffer: BufferType>
+    TryFrom<arrow_array::RunArray<<R as FixedSizeExt>::ArrowPrimitiveType>>
+    for RunEndEncodedArray<R, V, Buffer>
+where
+    <R as FixedSizeExt>::ArrowPrimitiveType: RunEndIndexType,
+    FixedSizePrimitiveArray<R, false, Buffer>: FromIterator<R>,
+    V: TryFrom<Arc<dyn arrow_array::Array>, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(
+        value: arrow_array::RunArray<<R as FixedSizeExt>::ArrowPrimitiveType>,
+    ) -> Result<Self, Self::Error> {
+        // Sliced run arrays share the run ends and values of the array they
+        // were sliced from, so only the runs in the slice are converted.
+        let run_end_buffer = value.run_ends();
+        let offset = run_end_buffer.offset();
+        let len = run_end_buffer.len();
+        let start = value.get_start_physical_index();
+        let end = if len == 0 {
+            start
+        } else {
+            value.get_end_physical_index() + 1
+        };
+        let run_ends = run_end_buffer.values()[start..end]
+            .iter()
+            .map(|&run_end| {
+                R::from_index(run_end.as_usize().saturating_sub(offset).min(len))
+                    .expect("run end in range")
+            })
+            .collect();
+        let values = value.values().slice(start, end - start);
+        Ok(Self {
+            run_ends,
+            values: values.try_into()?,
+        })
+    }
+}
+
+impl<R: RunEnd + FixedSizeExt, V: crate::arrow::Array, Buffer: BufferType>
+    TryFrom<Arc<dyn arrow_array::Array>> for RunEndEncodedArray<R, V, Buffer>
+where
+    <R as FixedSizeExt>::ArrowPrimitiveType: RunEndIndexType,
+    Self: TryFrom<arrow_array::RunArray<<R as FixedSizeExt>::ArrowPrimitiveType>, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<
+            arrow_array::RunArray<<R as FixedSizeExt>::ArrowPrimitiveType>,
+        >(
+            value.as_ref(), <Self as crate::arrow::Array>::data_type
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        array::{StringArray, Uint32Array},
+        Index, Length,
+    };
+    use arrow_array::{
+        cast::AsArray,
+        types::{Int16Type, Int32Type},
+    };
+
+    #[test]
+    fn from() {
+        let run_end_encoded = [1_u32, 1, 1, 2, 2]
+            .into_iter()
+            .collect::<RunEndEncodedArray<i32, Uint32Array>>();
+        let array = arrow_array::RunArray::<Int32Type>::from(run_end_encoded);
+        assert_eq!(array.len(), 5);
+        assert_eq!(array.run_ends().values(), [3, 5]);
+        assert_eq!(
+            array
+                .values()
+                .as_primitive::<arrow_array::types::UInt32Type>()
+                .values()
+                .as_ref(),
+            [1, 2]
+        );
+
+        let nullable = [Some("a"), None, None]
+            .map(|x| x.map(ToOwned::to_owned))
+            .into_iter()
+            .collect::<RunEndEncodedArray<i16, StringArray<true>>>();
+        let array_nullable: Arc<dyn arrow_array::Array> = nullable.into();
+        assert_eq!(
+            array_nullable.data_type(),
+            &<RunEndEncodedArray<i16, StringArray<true>> as crate::arrow::Array>::data_type()
+        );
+        assert_eq!(
+            array_nullable.logical_nulls().map(|x| x.null_count()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn into() {
+        let array: arrow_array::RunArray<Int16Type> =
+            vec!["a", "a", "b", "c", "c", "c"].into_iter().collect();
+        let run_end_encoded =
+            RunEndEncodedArray::<i16, StringArray>::try_from(array.clone()).expect("conversion");
+        assert_eq!(run_end_encoded.len(), 6);
+        assert_eq!(run_end_encoded.run_ends.as_ref(), [2, 3, 6]);
+        assert_eq!(run_end_encoded.index_checked(3), "c");
+
+        let sliced = RunEndEncodedArray::<i16, StringArray>::try_from(array.slice(1, 3))
+            .expect("conversion");
+        assert_eq!(sliced.run_ends.as_ref(), [1, 2, 3]);
+        assert_eq!(sliced.into_iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+
+        let empty = RunEndEncodedArray::<i16, StringArray>::try_from(array.slice(2, 0))
+            .expect("conversion");
+        assert!(empty.is_empty());
+        assert!(empty.values.is_empty());
+    }
+
+    #[test]
+    fn into_wrong_data_type() {
+        let array: arrow_array::RunArray<Int32Type> = vec!["a", "b"].into_iter().collect();
+        let actual = array.data_type().clone();
+        let array_ref: Arc<dyn arrow_array::Array> = Arc::new(array);
+        assert_eq!(
+            RunEndEncodedArray::<i16, StringArray>::try_from(array_ref).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: <RunEndEncodedArray<i16, StringArray> as crate::arrow::Array>::data_type(
+                ),
+                actual,
+            })
+        );
+    }
+
+    #[test]
+    fn into_nullable_values() {
+        let array: arrow_array::RunArray<Int32Type> = vec![Some("a"), None].into_iter().collect();
+        assert_eq!(
+            RunEndEncodedArray::<i32, StringArray>::try_from(array).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+}
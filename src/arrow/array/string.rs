@@ -14,6 +14,7 @@ use crate::{
     nullable::Nullable,
     offset::Offset,
     validity::{Nullability, Validity},
+    Error,
 };
 
 impl<const NULLABLE: bool, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
@@ -38,15 +39,20 @@ where
 }
 
 impl<const NULLABLE: bool, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<Arc<dyn arrow_array::Array>> for StringArray<NULLABLE, OffsetItem, Buffer>
+    TryFrom<Arc<dyn arrow_array::Array>> for StringArray<NULLABLE, OffsetItem, Buffer>
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
-    Self: From<arrow_array::GenericStringArray<OffsetItem>>,
+    String: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::GenericStringArray<OffsetItem>, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::GenericStringArray::<OffsetItem>::from(
-            value.to_data(),
-        ))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<
+            arrow_array::GenericStringArray<OffsetItem>,
+        >(
+            value.as_ref(), <Self as crate::arrow::Array>::data_type
+        )?)
     }
 }
 
@@ -110,37 +116,38 @@ where
     }
 }
 
-/// Panics when there are nulls
 impl<OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<arrow_array::GenericStringArray<OffsetItem>> for StringArray<false, OffsetItem, Buffer>
+    TryFrom<arrow_array::GenericStringArray<OffsetItem>> for StringArray<false, OffsetItem, Buffer>
 where
     FixedSizePrimitiveArray<u8, false, Buffer>: From<ScalarBuffer<u8>>,
     <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
 {
-    fn from(value: arrow_array::GenericStringArray<OffsetItem>) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericStringArray<OffsetItem>) -> Result<Self, Self::Error> {
         let (offsets, values, nulls_opt) = value.into_parts();
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => StringArray(VariableSizeBinaryArray(Offset {
-                data: ScalarBuffer::from(values).into(),
-                offsets: offsets.into_inner().into(),
-            })),
-        }
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(StringArray(VariableSizeBinaryArray(Offset {
+            data: ScalarBuffer::from(values).into(),
+            offsets: offsets.into_inner().into(),
+        })))
     }
 }
 
 impl<OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<arrow_array::GenericStringArray<OffsetItem>> for StringArray<true, OffsetItem, Buffer>
+    TryFrom<arrow_array::GenericStringArray<OffsetItem>> for StringArray<true, OffsetItem, Buffer>
 where
     FixedSizePrimitiveArray<u8, false, Buffer>: From<ScalarBuffer<u8>>,
     <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
     Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::GenericStringArray<OffsetItem>) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericStringArray<OffsetItem>) -> Result<Self, Self::Error> {
         let (offsets_buffer, values, nulls_opt) = value.into_parts();
         let data = ScalarBuffer::from(values).into();
         let offsets = offsets_buffer.into_inner().into();
-        match nulls_opt {
+        Ok(match nulls_opt {
             Some(null_buffer) => StringArray(VariableSizeBinaryArray(Offset {
                 data,
                 offsets: Nullable {
@@ -153,13 +160,17 @@ where
                 offsets,
             }))
             .into(),
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{array::StringArray, bitmap::ValidityBitmap};
+    use std::sync::Arc;
+
+    use arrow_schema::DataType;
+
+    use crate::{array::StringArray, bitmap::ValidityBitmap, Error};
 
     const INPUT: [&str; 3] = ["hello", "world", "!"];
     const INPUT_NULLABLE: [Option<&str>; 3] = [Some("hello"), None, Some("!")];
@@ -194,19 +205,43 @@ mod tests {
             .map(Option::Some)
             .collect::<arrow_array::StringArray>();
         assert!(
-            !StringArray::<true, i32, crate::arrow::buffer::ScalarBuffer>::from(string_array)
+            !StringArray::<true, i32, crate::arrow::buffer::ScalarBuffer>::try_from(string_array)
+                .expect("nullable array")
                 .any_null()
         );
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let string_array_nullable = INPUT_NULLABLE
             .into_iter()
             .collect::<arrow_array::StringArray>();
-        let _: StringArray<false, i32, crate::arrow::buffer::ScalarBuffer> =
-            string_array_nullable.into();
+        assert_eq!(
+            StringArray::<false, i32, crate::arrow::buffer::ScalarBuffer>::try_from(
+                string_array_nullable
+            )
+            .err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_wrong_offset() {
+        let string_array: Arc<dyn arrow_array::Array> = Arc::new(
+            INPUT
+                .into_iter()
+                .map(Some)
+                .collect::<arrow_array::StringArray>(),
+        );
+        assert_eq!(
+            StringArray::<false, i64, crate::arrow::buffer::ScalarBuffer>::try_from(string_array)
+                .err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::LargeUtf8,
+                actual: DataType::Utf8
+            })
+        );
     }
 
     #[test]
@@ -216,14 +251,15 @@ mod tests {
             .map(ToOwned::to_owned)
             .map(Option::Some)
             .collect::<arrow_array::StringArray>();
-        let _: StringArray<false, i32, crate::arrow::buffer::ScalarBuffer> = string_array.into();
+        let _: StringArray<false, i32, crate::arrow::buffer::ScalarBuffer> =
+            string_array.try_into().expect("array without nulls");
         // todo(mbrobbel): intoiterator for stringarray
 
         let string_array_nullable = INPUT_NULLABLE
             .into_iter()
             .collect::<arrow_array::StringArray>();
         let _: StringArray<true, i32, crate::arrow::buffer::ScalarBuffer> =
-            string_array_nullable.into();
+            string_array_nullable.try_into().expect("nullable array");
         // todo(mbrobbel): intoiterator for stringarray
     }
 }
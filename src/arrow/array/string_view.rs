@@ -0,0 +1,153 @@
+//! Interop with [`arrow-rs`] string view array.
+
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field};
+
+use crate::{
+    array::{BinaryViewArray, StringViewArray},
+    buffer::BufferType,
+    validity::{Nullability, Validity},
+    Error,
+};
+
+impl<const NULLABLE: bool, Buffer: BufferType> crate::arrow::Array
+    for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    String: Nullability<NULLABLE>,
+{
+    type Array = arrow_array::StringViewArray;
+
+    fn as_field(name: &str) -> arrow_schema::Field {
+        Field::new(name, Self::data_type(), NULLABLE)
+    }
+
+    fn data_type() -> arrow_schema::DataType {
+        DataType::Utf8View
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> From<StringViewArray<NULLABLE, Buffer>>
+    for arrow_array::StringViewArray
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    arrow_array::BinaryViewArray: From<BinaryViewArray<NULLABLE, Buffer>>,
+{
+    fn from(value: StringViewArray<NULLABLE, Buffer>) -> Self {
+        let array = arrow_array::BinaryViewArray::from(value.0);
+        // Safety:
+        // - String arrays contain valid UTF8.
+        unsafe { array.to_string_view_unchecked() }
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> From<StringViewArray<NULLABLE, Buffer>>
+    for Arc<dyn arrow_array::Array>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    arrow_array::StringViewArray: From<StringViewArray<NULLABLE, Buffer>>,
+{
+    fn from(value: StringViewArray<NULLABLE, Buffer>) -> Self {
+        Arc::new(arrow_array::StringViewArray::from(value))
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> TryFrom<arrow_array::StringViewArray>
+    for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    BinaryViewArray<NULLABLE, Buffer>: TryFrom<arrow_array::BinaryViewArray, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: arrow_array::StringViewArray) -> Result<Self, Self::Error> {
+        Ok(Self(value.to_binary_view().try_into()?))
+    }
+}
+
+impl<const NULLABLE: bool, Buffer: BufferType> TryFrom<Arc<dyn arrow_array::Array>>
+    for StringViewArray<NULLABLE, Buffer>
+where
+    <Buffer as BufferType>::Buffer<i128>: Validity<NULLABLE>,
+    String: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::StringViewArray, Error = Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::StringViewArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bitmap::ValidityBitmap, Index, Length};
+    use arrow_array::Array as _;
+
+    const INPUT: [&str; 3] = ["hello", "a string that is not inlined", "!"];
+    const INPUT_NULLABLE: [Option<&str>; 3] =
+        [Some("hello"), None, Some("a string that is not inlined")];
+
+    #[test]
+    fn from() {
+        let string_view = INPUT.into_iter().collect::<StringViewArray>();
+        assert_eq!(
+            arrow_array::StringViewArray::from(string_view)
+                .iter()
+                .flatten()
+                .collect::<Vec<_>>(),
+            INPUT
+        );
+
+        let string_view_nullable = INPUT_NULLABLE
+            .into_iter()
+            .collect::<StringViewArray<true>>();
+        let array_nullable: Arc<dyn arrow_array::Array> = string_view_nullable.into();
+        assert_eq!(array_nullable.data_type(), &DataType::Utf8View);
+        assert_eq!(
+            array_nullable
+                .as_any()
+                .downcast_ref::<arrow_array::StringViewArray>()
+                .expect("string view array")
+                .iter()
+                .collect::<Vec<_>>(),
+            INPUT_NULLABLE
+        );
+    }
+
+    #[test]
+    fn into() {
+        let array = arrow_array::StringViewArray::from_iter(INPUT_NULLABLE);
+        let string_view =
+            StringViewArray::<true, crate::arrow::buffer::ScalarBuffer>::try_from(array.clone())
+                .expect("conversion");
+        assert_eq!(string_view.len(), 3);
+        assert!(string_view.is_null(1).expect("in bounds"));
+        assert_eq!(string_view.index_checked(2), INPUT_NULLABLE[2]);
+
+        let array_ref: Arc<dyn arrow_array::Array> = Arc::new(array);
+        assert_eq!(
+            StringViewArray::<false>::try_from(array_ref).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_wrong_data_type() {
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::StringArray::from_iter_values(INPUT));
+        assert_eq!(
+            StringViewArray::<false>::try_from(array).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::Utf8View,
+                actual: DataType::Utf8,
+            })
+        );
+    }
+}
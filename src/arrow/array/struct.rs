@@ -11,7 +11,7 @@ use crate::{
     buffer::BufferType,
     nullable::Nullable,
     validity::{Nullability, Validity},
-    Length,
+    Error, Length,
 };
 
 /// Arrow schema interop trait for the fields of a struct array type.
@@ -40,14 +40,20 @@ where
     }
 }
 
-impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> From<Arc<dyn arrow_array::Array>>
-    for StructArray<T, NULLABLE, Buffer>
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType>
+    TryFrom<Arc<dyn arrow_array::Array>> for StructArray<T, NULLABLE, Buffer>
 where
-    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
-    Self: From<arrow_array::StructArray>,
+    <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE> + StructArrayTypeFields,
+    T: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::StructArray, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::StructArray::from(value.to_data()))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::StructArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
     }
 }
 
@@ -101,59 +107,115 @@ where
     }
 }
 
-/// Panics when there are nulls
-impl<T: StructArrayType, Buffer: BufferType> From<arrow_array::StructArray>
+/// Returns the `arrays` of the `fields` of a struct array with `len` rows,
+/// matched by name and in the order of the fields of `T`.
+///
+/// Arrays of fields that are not fields of `T` are ignored. Missing nullable
+/// fields of `T` are filled with nulls. Fields with a mismatching extension
+/// type are rejected.
+fn project<T: StructArrayTypeFields>(
+    fields: &Fields,
+    arrays: &[Arc<dyn arrow_array::Array>],
+    len: usize,
+) -> Result<Vec<Arc<dyn arrow_array::Array>>, Error> {
+    T::fields()
+        .iter()
+        .map(|field| match fields.find(field.name()) {
+            Some((idx, actual)) => crate::arrow::check_extension_type(field, actual)
+                .map(|()| Arc::clone(&arrays[idx]))
+                .map_err(|error| error.with_field(field.name())),
+            None if field.is_nullable() => {
+                null_array(field.data_type(), len).map_err(|error| error.with_field(field.name()))
+            }
+            None => Err(Error::MissingField {
+                path: Vec::new(),
+                name: field.name().to_owned(),
+            }),
+        })
+        .collect()
+}
+
+/// Returns an array of `len` nulls with the given `data_type`.
+///
+/// Different from [`arrow_array::new_null_array`], only the returned array
+/// has a null buffer. The child arrays of nested data types are (zeroed)
+/// arrays without nulls, which allows the conversion of the returned array
+/// into arrays with non-nullable children.
+///
+/// # Errors
+///
+/// Returns an [`Error::Arrow`] when the array can't be built.
+fn null_array(data_type: &DataType, len: usize) -> Result<Arc<dyn arrow_array::Array>, Error> {
+    let data = arrow_array::new_null_array(data_type, len).to_data();
+    let child_data = data
+        .child_data()
+        .iter()
+        .map(|child| without_nulls(&arrow_array::make_array(child.clone())))
+        .map(|child| child.map(|child_array| child_array.to_data()))
+        .collect::<Result<_, _>>()?;
+    let array_data = data.into_builder().child_data(child_data).build()?;
+    Ok(arrow_array::make_array(array_data))
+}
+
+/// Returns `array` (and its children) without null buffers.
+///
+/// # Errors
+///
+/// Returns an [`Error::Arrow`] when the array can't be built.
+fn without_nulls(array: &dyn arrow_array::Array) -> Result<Arc<dyn arrow_array::Array>, Error> {
+    let data = array.to_data();
+    let child_data = data
+        .child_data()
+        .iter()
+        .map(|child| without_nulls(&arrow_array::make_array(child.clone())))
+        .map(|child| child.map(|child_array| child_array.to_data()))
+        .collect::<Result<_, _>>()?;
+    let array_data = data
+        .into_builder()
+        .nulls(None)
+        .child_data(child_data)
+        .build()?;
+    Ok(arrow_array::make_array(array_data))
+}
+
+impl<T: StructArrayType, Buffer: BufferType> TryFrom<arrow_array::StructArray>
     for StructArray<T, false, Buffer>
 where
     <T as StructArrayType>::Array<Buffer>:
-        From<Vec<Arc<dyn arrow_array::Array>>> + StructArrayTypeFields,
+        TryFrom<Vec<Arc<dyn arrow_array::Array>>, Error = Error> + StructArrayTypeFields,
 {
-    fn from(value: arrow_array::StructArray) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::StructArray) -> Result<Self, Self::Error> {
+        let len = arrow_array::Array::len(&value);
         let (fields, arrays, nulls_opt) = value.into_parts();
-        // Project
-        let projected = <<T as StructArrayType>::Array<Buffer> as StructArrayTypeFields>::NAMES
-            .iter()
-            .map(|field| {
-                fields
-                    .find(field)
-                    .unwrap_or_else(|| panic!("expected struct array with field: {field}"))
-            })
-            .map(|(idx, _)| Arc::clone(&arrays[idx]))
-            .collect::<Vec<_>>();
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => StructArray(projected.into()),
-        }
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        let projected = project::<<T as StructArrayType>::Array<Buffer>>(&fields, &arrays, len)?;
+        Ok(StructArray(projected.try_into()?))
     }
 }
 
-impl<T: StructArrayType, Buffer: BufferType> From<arrow_array::StructArray>
+impl<T: StructArrayType, Buffer: BufferType> TryFrom<arrow_array::StructArray>
     for StructArray<T, true, Buffer>
 where
     <T as StructArrayType>::Array<Buffer>:
-        From<Vec<Arc<dyn arrow_array::Array>>> + Length + StructArrayTypeFields,
+        TryFrom<Vec<Arc<dyn arrow_array::Array>>, Error = Error> + Length + StructArrayTypeFields,
     Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::StructArray) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::StructArray) -> Result<Self, Self::Error> {
+        let len = arrow_array::Array::len(&value);
         let (fields, arrays, nulls_opt) = value.into_parts();
-        // Project
-        let projected = <<T as StructArrayType>::Array<Buffer> as StructArrayTypeFields>::NAMES
-            .iter()
-            .map(|field| {
-                fields
-                    .find(field)
-                    .unwrap_or_else(|| panic!("expected struct array with field: {field}"))
-            })
-            .map(|(idx, _)| Arc::clone(&arrays[idx]))
-            .collect::<Vec<_>>();
-        let data = projected.into();
-        match nulls_opt {
+        let projected = project::<<T as StructArrayType>::Array<Buffer>>(&fields, &arrays, len)?;
+        let data = projected.try_into()?;
+        Ok(match nulls_opt {
             Some(null_buffer) => StructArray(Nullable {
                 data,
                 validity: null_buffer.into(),
             }),
             None => StructArray::<T, false, Buffer>(data).into(),
-        }
+        })
     }
 }
 
@@ -168,14 +230,16 @@ where
     }
 }
 
-impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> From<arrow_array::RecordBatch>
+impl<T: StructArrayType, const NULLABLE: bool, Buffer: BufferType> TryFrom<arrow_array::RecordBatch>
     for StructArray<T, NULLABLE, Buffer>
 where
     <T as StructArrayType>::Array<Buffer>: Validity<NULLABLE>,
-    Self: From<arrow_array::StructArray>,
+    Self: TryFrom<arrow_array::StructArray, Error = Error>,
 {
-    fn from(value: arrow_array::RecordBatch) -> Self {
-        Self::from(arrow_array::StructArray::from(value))
+    type Error = Error;
+
+    fn try_from(value: arrow_array::RecordBatch) -> Result<Self, Self::Error> {
+        Self::try_from(arrow_array::StructArray::from(value))
     }
 }
 
@@ -305,18 +369,24 @@ mod tests {
             >::new(value.a.into())]
         }
     }
-    impl<Buffer: BufferType> From<Vec<Arc<dyn arrow_array::Array>>> for FooArray<Buffer>
+    impl<Buffer: BufferType> TryFrom<Vec<Arc<dyn arrow_array::Array>>> for FooArray<Buffer>
     where
         <u32 as ArrayType<u32>>::Array<Buffer, offset::NA, union::NA>:
-            From<Arc<dyn arrow_array::Array>>,
+            TryFrom<Arc<dyn arrow_array::Array>, Error = Error>,
     {
-        fn from(value: Vec<Arc<dyn arrow_array::Array>>) -> Self {
-            let mut arrays = value.into_iter();
-            let result = Self {
-                a: arrays.next().expect("an array").into(),
-            };
-            assert!(arrays.next().is_none());
-            result
+        type Error = Error;
+
+        fn try_from(value: Vec<Arc<dyn arrow_array::Array>>) -> Result<Self, Self::Error> {
+            let [a]: [_; 1] = value
+                .try_into()
+                .map_err(|arrays: Vec<_>| Error::FieldCount {
+                    path: Vec::new(),
+                    expected: 1,
+                    actual: arrays.len(),
+                })?;
+            Ok(Self {
+                a: a.try_into().map_err(|error: Error| error.with_field("a"))?,
+            })
         }
     }
 
@@ -345,7 +415,9 @@ mod tests {
         );
 
         // And convert back
-        let roundtrip: StructArray<Foo, true, ScalarBuffer> = struct_array_arrow_nullable.into();
+        let roundtrip: StructArray<Foo, true, ScalarBuffer> = struct_array_arrow_nullable
+            .try_into()
+            .expect("nullable struct array");
         assert_eq!(roundtrip.0.data.a.0, [1234, u32::default()]);
     }
 
@@ -355,17 +427,49 @@ mod tests {
             .into_iter()
             .collect::<StructArray<Foo, false, BufferBuilder>>();
         let struct_array_arrow = arrow_array::StructArray::from(struct_array);
-        assert!(!StructArray::<Foo, true, ScalarBuffer>::from(struct_array_arrow).any_null());
+        assert!(
+            !StructArray::<Foo, true, ScalarBuffer>::try_from(struct_array_arrow)
+                .expect("nullable struct array")
+                .any_null()
+        );
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let struct_array_nullable = [Some(Foo { a: 1234 }), None]
             .into_iter()
             .collect::<StructArray<Foo, true, BufferBuilder>>();
         let struct_array_arrow_nullable = arrow_array::StructArray::from(struct_array_nullable);
-        let _ = StructArray::<Foo, false, ScalarBuffer>::from(struct_array_arrow_nullable);
+        assert_eq!(
+            StructArray::<Foo, false, ScalarBuffer>::try_from(struct_array_arrow_nullable).err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_invalid_field() {
+        let column: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::StringArray::from(vec!["a", "b"]));
+        let struct_array_arrow = arrow_array::StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Utf8, false)),
+            column,
+        )]);
+        let record_batch = arrow_array::RecordBatch::from(struct_array_arrow);
+        let error = StructArray::<Foo, false, ScalarBuffer>::try_from(record_batch)
+            .err()
+            .expect("data type mismatch");
+        assert_eq!(
+            error,
+            Error::DataType {
+                path: vec!["a".to_owned()],
+                expected: DataType::UInt32,
+                actual: DataType::Utf8
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "field `a`: expected data type UInt32, found Utf8"
+        );
     }
 
     #[test]
@@ -375,7 +479,8 @@ mod tests {
             .collect::<StructArray<Foo, false, BufferBuilder>>();
         let struct_array_arrow = arrow_array::StructArray::from(struct_array);
         assert_eq!(
-            StructArray::<Foo, false, ScalarBuffer>::from(struct_array_arrow)
+            StructArray::<Foo, false, ScalarBuffer>::try_from(struct_array_arrow)
+                .expect("struct array")
                 .0
                 .a
                 .0,
@@ -386,7 +491,8 @@ mod tests {
             .collect::<StructArray<Foo, true, BufferBuilder>>();
         let struct_array_arrow_nullable = arrow_array::StructArray::from(struct_array_nullable);
         assert_eq!(
-            StructArray::<Foo, true, ScalarBuffer>::from(struct_array_arrow_nullable)
+            StructArray::<Foo, true, ScalarBuffer>::try_from(struct_array_arrow_nullable)
+                .expect("nullable struct array")
                 .0
                 .data
                 .a
@@ -429,7 +535,8 @@ mod tests {
         let struct_array_arrow = arrow_array::StructArray::from(struct_array);
         assert_eq!(struct_array_arrow.len(), 2);
 
-        let struct_array_roundtrip: StructArray<Foo<i32>> = struct_array_arrow.into();
+        let struct_array_roundtrip: StructArray<Foo<i32>> =
+            struct_array_arrow.try_into().expect("struct array");
         assert_eq!(struct_array_roundtrip.len(), 2);
     }
 
@@ -465,7 +572,6 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "expected struct array with field: c")]
     #[cfg(feature = "derive")]
     fn projected() {
         #[derive(narrow_derive::ArrayType)]
@@ -497,13 +603,84 @@ mod tests {
         .collect::<StructArray<Foo>>();
 
         let arrow_array = arrow_array::StructArray::from(foo_array);
-        let bar_array = StructArray::<Bar>::from(arrow_array);
+        let bar_array = StructArray::<Bar>::try_from(arrow_array).expect("projected struct array");
         assert_eq!(
             bar_array.clone().into_iter().collect::<Vec<_>>(),
             [Bar { b: false, a: 1 }, Bar { b: true, a: 2 }]
         );
 
         let bar_arrow_array = arrow_array::StructArray::from(bar_array);
-        let _ = StructArray::<Foo>::from(bar_arrow_array);
+        assert_eq!(
+            StructArray::<Foo>::try_from(bar_arrow_array).err(),
+            Some(Error::MissingField {
+                path: Vec::new(),
+                name: "c".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    fn schema_evolution() {
+        #[derive(narrow_derive::ArrayType)]
+        struct Old {
+            a: u32,
+            z: bool,
+        }
+
+        #[derive(narrow_derive::ArrayType, Debug, PartialEq)]
+        struct Inner {
+            x: u32,
+            y: String,
+        }
+
+        #[derive(narrow_derive::ArrayType, Debug, PartialEq)]
+        struct New {
+            b: Option<String>,
+            a: u32,
+            c: Option<Vec<u16>>,
+            d: Option<Inner>,
+            e: Option<[u8; 2]>,
+        }
+
+        #[derive(narrow_derive::ArrayType, Debug)]
+        struct Required {
+            a: u32,
+            f: u64,
+        }
+
+        let old_array = [Old { a: 1, z: true }, Old { a: 2, z: false }]
+            .into_iter()
+            .collect::<StructArray<Old>>();
+        let record_batch = arrow_array::RecordBatch::from(old_array);
+
+        let new_array = StructArray::<New>::try_from(record_batch.clone()).expect("new array");
+        assert_eq!(
+            new_array.into_iter().collect::<Vec<_>>(),
+            [
+                New {
+                    b: None,
+                    a: 1,
+                    c: None,
+                    d: None,
+                    e: None
+                },
+                New {
+                    b: None,
+                    a: 2,
+                    c: None,
+                    d: None,
+                    e: None
+                }
+            ]
+        );
+
+        assert_eq!(
+            StructArray::<Required>::try_from(record_batch).err(),
+            Some(Error::MissingField {
+                path: Vec::new(),
+                name: "f".to_owned()
+            })
+        );
     }
 }
@@ -0,0 +1,156 @@
+//! Interop with [`arrow-rs`] struct arrays for tuples.
+
+use std::sync::Arc;
+
+use arrow_schema::Fields;
+
+use crate::{array::TupleArray, arrow::StructArrayTypeFields, Error};
+
+/// Implement the [`arrow-rs`] struct array traits for the [`TupleArray`] of
+/// tuples with the given elements.
+macro_rules! impl_tuple {
+    ($($idx:tt: $array:ident),+) => {
+        impl<$($array: crate::arrow::Array),+> StructArrayTypeFields for TupleArray<($($array,)+)> {
+            const NAMES: &'static [&'static str] = &[$(stringify!($idx),)+];
+
+            fn fields() -> Fields {
+                Fields::from([$(Arc::new($array::as_field(stringify!($idx))),)+])
+            }
+        }
+
+        impl<$($array: Into<Arc<dyn arrow_array::Array>>),+> From<TupleArray<($($array,)+)>>
+            for Vec<Arc<dyn arrow_array::Array>>
+        {
+            fn from(value: TupleArray<($($array,)+)>) -> Self {
+                vec![$(value.0.$idx.into(),)+]
+            }
+        }
+
+        impl<$($array: TryFrom<Arc<dyn arrow_array::Array>, Error = Error>),+>
+            TryFrom<Vec<Arc<dyn arrow_array::Array>>> for TupleArray<($($array,)+)>
+        {
+            type Error = Error;
+
+            fn try_from(value: Vec<Arc<dyn arrow_array::Array>>) -> Result<Self, Self::Error> {
+                let expected = [$(stringify!($idx),)+].len();
+                if value.len() != expected {
+                    return Err(Error::FieldCount {
+                        path: Vec::new(),
+                        expected,
+                        actual: value.len(),
+                    });
+                }
+                let mut arrays = value.into_iter();
+                Ok(Self(($(
+                    $array::try_from(arrays.next().expect("field count checked"))
+                        .map_err(|error| error.with_field(stringify!($idx)))?,
+                )+)))
+            }
+        }
+    };
+}
+
+impl_tuple!(0: A);
+impl_tuple!(0: A, 1: B);
+impl_tuple!(0: A, 1: B, 2: C);
+impl_tuple!(0: A, 1: B, 2: C, 3: D);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E, 5: F);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K);
+impl_tuple!(0: A, 1: B, 2: C, 3: D, 4: E, 5: F, 6: G, 7: H, 8: I, 9: J, 10: K, 11: L);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{cast::AsArray as _, types::UInt32Type, Array as _};
+    use arrow_schema::DataType;
+
+    use crate::{array::StructArray, Error};
+
+    #[test]
+    fn from() {
+        let input = [(1_u32, "a".to_owned()), (2, "b".to_owned())];
+        let struct_array = arrow_array::StructArray::from(
+            input
+                .clone()
+                .into_iter()
+                .collect::<StructArray<(u32, String)>>(),
+        );
+        assert_eq!(struct_array.column_names(), ["0", "1"]);
+        assert_eq!(struct_array.column(0).data_type(), &DataType::UInt32);
+        assert_eq!(struct_array.column(1).data_type(), &DataType::Utf8);
+        assert_eq!(
+            struct_array.column(0).as_primitive::<UInt32Type>().values(),
+            &[1, 2]
+        );
+
+        let record_batch = arrow_array::RecordBatch::from(struct_array);
+        assert_eq!(
+            StructArray::<(u32, String)>::try_from(record_batch)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+
+    #[test]
+    fn nullable() {
+        let input = [Some((1_u8, (true, vec![1_u16]))), None];
+        let array: Arc<dyn arrow_array::Array> = input
+            .clone()
+            .into_iter()
+            .collect::<StructArray<(u8, (bool, Vec<u16>)), true>>()
+            .into();
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(
+            StructArray::<(u8, (bool, Vec<u16>)), true>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+
+    #[test]
+    fn nullable_elements() {
+        let input = [(1_u32, Some("a".to_owned())), (2, None)];
+        let struct_array = arrow_array::StructArray::from(
+            input
+                .clone()
+                .into_iter()
+                .collect::<StructArray<(u32, Option<String>)>>(),
+        );
+        assert!(!struct_array.fields()[0].is_nullable());
+        assert!(struct_array.fields()[1].is_nullable());
+        assert_eq!(struct_array.column(1).null_count(), 1);
+        assert_eq!(
+            StructArray::<(u32, Option<String>)>::try_from(struct_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+
+    #[test]
+    fn data_type_mismatch() {
+        let array: Arc<dyn arrow_array::Array> = [(1_u32, 2_u64)]
+            .into_iter()
+            .collect::<StructArray<(u32, u64)>>()
+            .into();
+        assert_eq!(
+            StructArray::<(u32, u32)>::try_from(array).err(),
+            Some(Error::DataType {
+                path: vec!["1".to_owned()],
+                expected: DataType::UInt32,
+                actual: DataType::UInt64,
+            })
+        );
+    }
+}
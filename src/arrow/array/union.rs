@@ -11,6 +11,7 @@ use crate::{
     },
     buffer::BufferType,
     offset::OffsetElement,
+    Error,
 };
 
 /// Mapping between [`UnionType`] and [`UnionMode`].
@@ -170,27 +171,43 @@ where
     }
 }
 
+/// Returns an [`Error::DataType`] when the layout of `array` does not match
+/// the layout of `T`.
+fn check_mode<T: crate::arrow::Array, UnionLayout: UnionLayoutExt>(
+    array: &arrow_array::UnionArray,
+) -> Result<(), Error> {
+    match arrow_array::Array::data_type(array) {
+        &DataType::Union(_, mode) if mode == UnionLayout::MODE => Ok(()),
+        data_type => Err(Error::DataType {
+            path: Vec::new(),
+            expected: T::data_type(),
+            actual: data_type.clone(),
+        }),
+    }
+}
+
 impl<
         T: UnionArrayType<VARIANTS>,
         const VARIANTS: usize,
         Buffer: BufferType,
         OffsetItem: OffsetElement,
-    > From<arrow_array::UnionArray> for UnionArray<T, VARIANTS, SparseLayout, Buffer, OffsetItem>
+    > TryFrom<arrow_array::UnionArray> for UnionArray<T, VARIANTS, SparseLayout, Buffer, OffsetItem>
 where
     for<'a> i8: From<&'a T>,
     FixedSizePrimitiveArray<i8, false, Buffer>: From<arrow_buffer::ScalarBuffer<i8>>,
     <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>:
-        FromIterator<Arc<dyn arrow_array::Array>>,
+        TryFrom<Vec<Arc<dyn arrow_array::Array>>, Error = Error>,
+    Self: crate::arrow::Array,
 {
-    fn from(value: arrow_array::UnionArray) -> Self {
-        let (_union_fields, type_ids, offsets_opt, variants) = value.into_parts();
-        match offsets_opt {
-            Some(_) => panic!("expected array without offsets"),
-            None => Self(SparseUnionArray {
-                variants: variants.into_iter().collect(),
-                types: type_ids.into(),
-            }),
-        }
+    type Error = Error;
+
+    fn try_from(value: arrow_array::UnionArray) -> Result<Self, Self::Error> {
+        check_mode::<Self, SparseLayout>(&value)?;
+        let (_union_fields, type_ids, _offsets, variants) = value.into_parts();
+        Ok(Self(SparseUnionArray {
+            variants: variants.try_into()?,
+            types: type_ids.into(),
+        }))
     }
 }
 
@@ -199,17 +216,19 @@ impl<
         const VARIANTS: usize,
         Buffer: BufferType,
         OffsetItem: OffsetElement,
-    > From<Arc<dyn arrow_array::Array>>
+    > TryFrom<Arc<dyn arrow_array::Array>>
     for UnionArray<T, VARIANTS, SparseLayout, Buffer, OffsetItem>
 where
     for<'a> i8: From<&'a T>,
-    FixedSizePrimitiveArray<i8, false, Buffer>: From<arrow_buffer::ScalarBuffer<i8>>,
-    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, SparseLayout>:
-        FromIterator<Arc<dyn arrow_array::Array>>,
+    Self: crate::arrow::Array + TryFrom<arrow_array::UnionArray, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        let array = arrow_array::UnionArray::from(value.to_data());
-        Self::from(array)
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::UnionArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
     }
 }
 
@@ -218,24 +237,25 @@ impl<
         const VARIANTS: usize,
         Buffer: BufferType,
         OffsetItem: OffsetElement,
-    > From<arrow_array::UnionArray> for UnionArray<T, VARIANTS, DenseLayout, Buffer, OffsetItem>
+    > TryFrom<arrow_array::UnionArray> for UnionArray<T, VARIANTS, DenseLayout, Buffer, OffsetItem>
 where
     for<'a> i8: From<&'a T>,
     FixedSizePrimitiveArray<i8, false, Buffer>: From<arrow_buffer::ScalarBuffer<i8>>,
     FixedSizePrimitiveArray<i32, false, Buffer>: From<arrow_buffer::ScalarBuffer<i32>>,
     <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>:
-        FromIterator<Arc<dyn arrow_array::Array>>,
+        TryFrom<Vec<Arc<dyn arrow_array::Array>>, Error = Error>,
+    Self: crate::arrow::Array,
 {
-    fn from(value: arrow_array::UnionArray) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::UnionArray) -> Result<Self, Self::Error> {
+        check_mode::<Self, DenseLayout>(&value)?;
         let (_union_fields, type_ids, offsets_opt, variants) = value.into_parts();
-        match offsets_opt {
-            None => panic!("expected array with offsets"),
-            Some(offsets) => Self(DenseUnionArray {
-                variants: variants.into_iter().collect(),
-                offsets: offsets.into(),
-                types: type_ids.into(),
-            }),
-        }
+        Ok(Self(DenseUnionArray {
+            variants: variants.try_into()?,
+            offsets: offsets_opt.expect("dense union array has offsets").into(),
+            types: type_ids.into(),
+        }))
     }
 }
 
@@ -244,17 +264,19 @@ impl<
         const VARIANTS: usize,
         Buffer: BufferType,
         OffsetItem: OffsetElement,
-    > From<Arc<dyn arrow_array::Array>> for UnionArray<T, VARIANTS, DenseLayout, Buffer, OffsetItem>
+    > TryFrom<Arc<dyn arrow_array::Array>>
+    for UnionArray<T, VARIANTS, DenseLayout, Buffer, OffsetItem>
 where
     for<'a> i8: From<&'a T>,
-    FixedSizePrimitiveArray<i8, false, Buffer>: From<arrow_buffer::ScalarBuffer<i8>>,
-    FixedSizePrimitiveArray<i32, false, Buffer>: From<arrow_buffer::ScalarBuffer<i32>>,
-    <T as UnionArrayType<VARIANTS>>::Array<Buffer, OffsetItem, DenseLayout>:
-        FromIterator<Arc<dyn arrow_array::Array>>,
+    Self: crate::arrow::Array + TryFrom<arrow_array::UnionArray, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        let array = arrow_array::UnionArray::from(value.to_data());
-        Self::from(array)
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<arrow_array::UnionArray>(
+            value.as_ref(),
+            <Self as crate::arrow::Array>::data_type,
+        )?)
     }
 }
 
@@ -282,7 +304,7 @@ mod tests {
         let input = [Wrap(FooBar::Foo), Wrap(FooBar::Bar(123))];
         let struct_array = input.clone().into_iter().collect::<StructArray<Wrap>>();
         let record_batch = RecordBatch::from(struct_array);
-        let read = StructArray::<Wrap>::from(record_batch);
+        let read = StructArray::<Wrap>::try_from(record_batch).expect("struct array");
         assert_eq!(read.into_iter().collect::<Vec<_>>(), input);
     }
 
@@ -323,20 +345,21 @@ mod tests {
             .into_iter()
             .collect::<UnionArray<FooBar, 3, SparseLayout>>();
         let union_array_arrow = arrow_array::UnionArray::from(sparse_union_array);
-        let narrow_union_array: UnionArray<FooBar, 3, SparseLayout> = union_array_arrow.into();
+        let narrow_union_array: UnionArray<FooBar, 3, SparseLayout> =
+            union_array_arrow.try_into().expect("sparse union array");
         assert_eq!(narrow_union_array.len(), 4);
 
         let dense_union_array = input
             .into_iter()
             .collect::<UnionArray<FooBar, 3, DenseLayout>>();
         let dense_union_array_arrow = arrow_array::UnionArray::from(dense_union_array);
-        let narrow_dense_union_array: UnionArray<FooBar, 3, DenseLayout> =
-            dense_union_array_arrow.into();
+        let narrow_dense_union_array: UnionArray<FooBar, 3, DenseLayout> = dense_union_array_arrow
+            .try_into()
+            .expect("dense union array");
         assert_eq!(narrow_dense_union_array.len(), 4);
     }
 
     #[test]
-    #[should_panic(expected = "expected array with offsets")]
     fn into_dense() {
         let input = [
             FooBar::Foo,
@@ -349,11 +372,18 @@ mod tests {
             .into_iter()
             .collect::<UnionArray<FooBar, 3, SparseLayout>>();
         let union_array_arrow = arrow_array::UnionArray::from(union_array);
-        let _: UnionArray<FooBar, 3, DenseLayout> = union_array_arrow.into();
+        let actual = arrow_array::Array::data_type(&union_array_arrow).clone();
+        assert_eq!(
+            UnionArray::<FooBar, 3, DenseLayout>::try_from(union_array_arrow).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: <UnionArray<FooBar, 3, DenseLayout> as crate::arrow::Array>::data_type(),
+                actual
+            })
+        );
     }
 
     #[test]
-    #[should_panic(expected = "expected array without offsets")]
     fn into_sparse() {
         let input = [
             FooBar::Foo,
@@ -366,11 +396,18 @@ mod tests {
             .into_iter()
             .collect::<UnionArray<FooBar, 3, DenseLayout>>();
         let union_array_arrow = arrow_array::UnionArray::from(union_array);
-        let _array: UnionArray<FooBar, 3, SparseLayout> = union_array_arrow.into();
+        let actual = arrow_array::Array::data_type(&union_array_arrow).clone();
+        assert_eq!(
+            UnionArray::<FooBar, 3, SparseLayout>::try_from(union_array_arrow).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: <UnionArray<FooBar, 3, SparseLayout> as crate::arrow::Array>::data_type(),
+                actual
+            })
+        );
     }
 
     #[test]
-    #[should_panic(expected = "NullArray data type should be Null")]
     fn wrong_conversion() {
         #[derive(crate::ArrayType)]
         enum Bar {
@@ -388,11 +425,17 @@ mod tests {
             .into_iter()
             .collect::<UnionArray<FooBar, 3, DenseLayout>>();
         let union_array_arrow = arrow_array::UnionArray::from(union_array);
-        let _array: UnionArray<Bar, 2, DenseLayout> = union_array_arrow.into();
+        assert_eq!(
+            UnionArray::<Bar, 2, DenseLayout>::try_from(union_array_arrow).err(),
+            Some(Error::FieldCount {
+                path: Vec::new(),
+                expected: 2,
+                actual: 3
+            })
+        );
     }
 
     #[test]
-    #[should_panic(expected = "not enough variant data arrays, expected 6")]
     fn wrong_variants() {
         #[derive(crate::ArrayType)]
         enum One {
@@ -412,6 +455,13 @@ mod tests {
             .into_iter()
             .collect::<UnionArray<One, 1, SparseLayout>>();
         let union_array_arrow = arrow_array::UnionArray::from(union_array);
-        let _array: UnionArray<Bar, 6, SparseLayout> = union_array_arrow.into();
+        assert_eq!(
+            UnionArray::<Bar, 6, SparseLayout>::try_from(union_array_arrow).err(),
+            Some(Error::FieldCount {
+                path: Vec::new(),
+                expected: 6,
+                actual: 1
+            })
+        );
     }
 }
@@ -14,6 +14,7 @@ use crate::{
     nullable::Nullable,
     offset::Offset,
     validity::{Nullability, Validity},
+    Error,
 };
 
 impl<const NULLABLE: bool, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
@@ -38,15 +39,20 @@ where
 }
 
 impl<const NULLABLE: bool, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<Arc<dyn arrow_array::Array>> for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
+    TryFrom<Arc<dyn arrow_array::Array>> for VariableSizeBinaryArray<NULLABLE, OffsetItem, Buffer>
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
-    Self: From<arrow_array::GenericBinaryArray<OffsetItem>>,
+    Vec<u8>: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::GenericBinaryArray<OffsetItem>, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::GenericBinaryArray::<OffsetItem>::from(
-            value.to_data(),
-        ))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<
+            arrow_array::GenericBinaryArray<OffsetItem>,
+        >(
+            value.as_ref(), <Self as crate::arrow::Array>::data_type
+        )?)
     }
 }
 
@@ -112,39 +118,40 @@ where
     }
 }
 
-/// Panics when there are nulls
 impl<OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<arrow_array::GenericBinaryArray<OffsetItem>>
+    TryFrom<arrow_array::GenericBinaryArray<OffsetItem>>
     for VariableSizeBinaryArray<false, OffsetItem, Buffer>
 where
     FixedSizePrimitiveArray<u8, false, Buffer>: From<ScalarBuffer<u8>>,
     <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
 {
-    fn from(value: arrow_array::GenericBinaryArray<OffsetItem>) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericBinaryArray<OffsetItem>) -> Result<Self, Self::Error> {
         let (offsets, values, nulls_opt) = value.into_parts();
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => VariableSizeBinaryArray(Offset {
-                data: ScalarBuffer::from(values).into(),
-                offsets: offsets.into_inner().into(),
-            }),
-        }
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(VariableSizeBinaryArray(Offset {
+            data: ScalarBuffer::from(values).into(),
+            offsets: offsets.into_inner().into(),
+        }))
     }
 }
 
 impl<OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<arrow_array::GenericBinaryArray<OffsetItem>>
+    TryFrom<arrow_array::GenericBinaryArray<OffsetItem>>
     for VariableSizeBinaryArray<true, OffsetItem, Buffer>
 where
     FixedSizePrimitiveArray<u8, false, Buffer>: From<ScalarBuffer<u8>>,
     <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
     Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::GenericBinaryArray<OffsetItem>) -> Self {
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericBinaryArray<OffsetItem>) -> Result<Self, Self::Error> {
         let (offsets_buffer, values, nulls_opt) = value.into_parts();
         let data = ScalarBuffer::from(values).into();
         let offsets = offsets_buffer.into_inner().into();
-        match nulls_opt {
+        Ok(match nulls_opt {
             Some(null_buffer) => VariableSizeBinaryArray(Offset {
                 data,
                 offsets: Nullable {
@@ -154,13 +161,13 @@ where
             }),
             None => VariableSizeBinaryArray::<false, OffsetItem, Buffer>(Offset { data, offsets })
                 .into(),
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{array::VariableSizeBinaryArray, bitmap::ValidityBitmap};
+    use crate::{array::VariableSizeBinaryArray, bitmap::ValidityBitmap, Error};
 
     fn input() -> [Vec<u8>; 3] {
         [vec![0, 1, 2], vec![3], vec![]]
@@ -200,21 +207,26 @@ mod tests {
             .map(Option::Some)
             .collect::<arrow_array::BinaryArray>();
         assert!(
-            !VariableSizeBinaryArray::<true, i32, crate::arrow::buffer::ScalarBuffer>::from(
+            !VariableSizeBinaryArray::<true, i32, crate::arrow::buffer::ScalarBuffer>::try_from(
                 vsb_array
             )
+            .expect("nullable array")
             .any_null()
         );
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let vsb_array_nullable = input_nullable()
             .into_iter()
             .collect::<arrow_array::BinaryArray>();
-        let _: VariableSizeBinaryArray<false, i32, crate::arrow::buffer::ScalarBuffer> =
-            vsb_array_nullable.into();
+        assert_eq!(
+            VariableSizeBinaryArray::<false, i32, crate::arrow::buffer::ScalarBuffer>::try_from(
+                vsb_array_nullable
+            )
+            .err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
     }
 
     #[test]
@@ -224,14 +236,14 @@ mod tests {
             .map(Option::Some)
             .collect::<arrow_array::BinaryArray>();
         let _: VariableSizeBinaryArray<false, i32, crate::arrow::buffer::ScalarBuffer> =
-            vsb_array.into();
+            vsb_array.try_into().expect("array without nulls");
         // todo(mbrobbel): intoiterator for Binaryarray
 
         let vsb_array_nullable = input_nullable()
             .into_iter()
             .collect::<arrow_array::BinaryArray>();
         let _: VariableSizeBinaryArray<true, i32, crate::arrow::buffer::ScalarBuffer> =
-            vsb_array_nullable.into();
+            vsb_array_nullable.try_into().expect("nullable array");
         // todo(mbrobbel): intoiterator for Binaryarray
     }
 }
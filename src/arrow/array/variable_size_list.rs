@@ -14,6 +14,7 @@ use crate::{
     nullable::Nullable,
     offset::Offset,
     validity::{Nullability, Validity},
+    Error,
 };
 
 impl<
@@ -42,19 +43,25 @@ where
 }
 
 impl<
-        T: Array,
+        T: crate::arrow::Array,
         const NULLABLE: bool,
         OffsetItem: OffsetElement + OffsetSizeTrait,
         Buffer: BufferType,
-    > From<Arc<dyn arrow_array::Array>> for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
+    > TryFrom<Arc<dyn arrow_array::Array>>
+    for VariableSizeListArray<T, NULLABLE, OffsetItem, Buffer>
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
-    Self: From<arrow_array::GenericListArray<OffsetItem>>,
+    Vec<T>: Nullability<NULLABLE>,
+    Self: TryFrom<arrow_array::GenericListArray<OffsetItem>, Error = Error>,
 {
-    fn from(value: Arc<dyn arrow_array::Array>) -> Self {
-        Self::from(arrow_array::GenericListArray::<OffsetItem>::from(
-            value.to_data(),
-        ))
+    type Error = Error;
+
+    fn try_from(value: Arc<dyn arrow_array::Array>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::arrow::downcast::<
+            arrow_array::GenericListArray<OffsetItem>,
+        >(
+            value.as_ref(), <Self as crate::arrow::Array>::data_type
+        )?)
     }
 }
 
@@ -134,39 +141,44 @@ where
     }
 }
 
-/// Panics when there are nulls
 impl<T: Array, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<arrow_array::GenericListArray<OffsetItem>>
+    TryFrom<arrow_array::GenericListArray<OffsetItem>>
     for VariableSizeListArray<T, false, OffsetItem, Buffer>
 where
-    T: From<Arc<dyn arrow_array::Array>>,
+    T: TryFrom<Arc<dyn arrow_array::Array>, Error = Error>,
     <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
 {
-    fn from(value: arrow_array::GenericListArray<OffsetItem>) -> Self {
-        let (_field, offsets, values, nulls_opt) = value.into_parts();
-        match nulls_opt {
-            Some(_) => panic!("expected array without a null buffer"),
-            None => VariableSizeListArray(Offset {
-                data: values.into(),
-                offsets: offsets.into_inner().into(),
-            }),
-        }
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericListArray<OffsetItem>) -> Result<Self, Self::Error> {
+        let (field, offsets, values, nulls_opt) = value.into_parts();
+        crate::arrow::non_nullable(nulls_opt.as_ref())?;
+        Ok(VariableSizeListArray(Offset {
+            data: values
+                .try_into()
+                .map_err(|error: Error| error.with_field(field.name()))?,
+            offsets: offsets.into_inner().into(),
+        }))
     }
 }
 
 impl<T: Array, OffsetItem: OffsetElement + OffsetSizeTrait, Buffer: BufferType>
-    From<arrow_array::GenericListArray<OffsetItem>>
+    TryFrom<arrow_array::GenericListArray<OffsetItem>>
     for VariableSizeListArray<T, true, OffsetItem, Buffer>
 where
-    T: From<Arc<dyn arrow_array::Array>>,
+    T: TryFrom<Arc<dyn arrow_array::Array>, Error = Error>,
     <Buffer as BufferType>::Buffer<OffsetItem>: From<ScalarBuffer<OffsetItem>>,
     Bitmap<Buffer>: From<NullBuffer> + FromIterator<bool>,
 {
-    fn from(value: arrow_array::GenericListArray<OffsetItem>) -> Self {
-        let (_field, offsets_buffer, values, nulls_opt) = value.into_parts();
-        let data = values.into();
+    type Error = Error;
+
+    fn try_from(value: arrow_array::GenericListArray<OffsetItem>) -> Result<Self, Self::Error> {
+        let (field, offsets_buffer, values, nulls_opt) = value.into_parts();
+        let data = values
+            .try_into()
+            .map_err(|error: Error| error.with_field(field.name()))?;
         let offsets = offsets_buffer.into_inner().into();
-        match nulls_opt {
+        Ok(match nulls_opt {
             Some(null_buffer) => VariableSizeListArray(Offset {
                 data,
                 offsets: Nullable {
@@ -176,7 +188,7 @@ where
             }),
             None => VariableSizeListArray::<T, false, OffsetItem, Buffer>(Offset { data, offsets })
                 .into(),
-        }
+        })
     }
 }
 
@@ -192,7 +204,7 @@ mod tests {
         array::{StringArray, Uint16Array, VariableSizeListArray},
         arrow::buffer::ScalarBuffer,
         bitmap::ValidityBitmap,
-        Length,
+        Error, Length,
     };
 
     const INPUT: [&[u16]; 3] = [&[1, 2], &[3], &[4]];
@@ -222,32 +234,53 @@ mod tests {
                 .map(|opt| opt.iter().copied().map(Option::Some))
                 .map(Option::Some),
         );
-        assert!(!VariableSizeListArray::<Uint16Array<false, ScalarBuffer>, true, i32, ScalarBuffer>::from(list_array).any_null());
+        assert!(!VariableSizeListArray::<Uint16Array<false, ScalarBuffer>, true, i32, ScalarBuffer>::try_from(list_array).expect("nullable array").any_null());
     }
 
     #[test]
-    #[should_panic(expected = "expected array without a null buffer")]
     fn into_non_nullable() {
         let mut list_builder =
             ListBuilder::with_capacity(StringBuilder::new(), INPUT_NULLABLE.len());
-        INPUT_NULLABLE.into_iter().for_each(|opt| match opt {
-            Some(items) => {
-                for item in items {
-                    list_builder.values().append_value(item);
+        for opt in INPUT_NULLABLE {
+            match opt {
+                Some(items) => {
+                    for item in items {
+                        list_builder.values().append_value(item);
+                    }
+                    list_builder.append(true);
+                }
+                None => {
+                    list_builder.append(false);
                 }
-                list_builder.append(true);
-            }
-            None => {
-                list_builder.append(false);
             }
-        });
+        }
         let list_array_nullable = list_builder.finish();
-        let _: VariableSizeListArray<
-            StringArray<false, i32, ScalarBuffer>,
-            false,
-            i32,
-            ScalarBuffer,
-        > = list_array_nullable.into();
+        assert_eq!(
+            VariableSizeListArray::<
+                StringArray<false, i32, ScalarBuffer>,
+                false,
+                i32,
+                ScalarBuffer,
+            >::try_from(list_array_nullable)
+            .err(),
+            Some(Error::Nullability { path: Vec::new() })
+        );
+    }
+
+    #[test]
+    fn into_non_nullable_item() {
+        let list_array = arrow_array::ListArray::from_iter_primitive::<UInt16Type, _, _>(vec![
+            Some(vec![Some(1), None]),
+        ]);
+        assert_eq!(
+            VariableSizeListArray::<Uint16Array<false, ScalarBuffer>, false, i32, ScalarBuffer>::try_from(
+                list_array
+            )
+            .err(),
+            Some(Error::Nullability {
+                path: vec!["item".to_owned()]
+            })
+        );
     }
 
     #[test]
@@ -259,27 +292,29 @@ mod tests {
                 .map(Option::Some),
         );
         let _: VariableSizeListArray<Uint16Array<false, ScalarBuffer>, false, i32, ScalarBuffer> =
-            list_array.into();
+            list_array.try_into().expect("array without nulls");
 
         let mut list_builder =
             ListBuilder::with_capacity(StringBuilder::new(), INPUT_NULLABLE.len());
-        INPUT_NULLABLE.into_iter().for_each(|opt| match opt {
-            Some(items) => {
-                for item in items {
-                    list_builder.values().append_value(item);
+        for opt in INPUT_NULLABLE {
+            match opt {
+                Some(items) => {
+                    for item in items {
+                        list_builder.values().append_value(item);
+                    }
+                    list_builder.append(true);
+                }
+                None => {
+                    list_builder.append(false);
                 }
-                list_builder.append(true);
-            }
-            None => {
-                list_builder.append(false);
             }
-        });
+        }
         let list_array_nullable = list_builder.finish();
         let _: VariableSizeListArray<
             StringArray<false, i32, ScalarBuffer>,
             true,
             i32,
             ScalarBuffer,
-        > = list_array_nullable.into();
+        > = list_array_nullable.try_into().expect("nullable array");
     }
 }
@@ -2,7 +2,7 @@
 
 use crate::{
     buffer::{Buffer, BufferType},
-    FixedSize, Index, Length,
+    FixedSize, Index, Length, Slice,
 };
 
 /// A [`BufferType`] implementation for [`ScalarBuffer`].
@@ -20,7 +20,8 @@ impl<T: FixedSize> Buffer<T> for arrow_buffer::ScalarBuffer<T> {
 }
 
 impl<T: FixedSize> Index for arrow_buffer::ScalarBuffer<T> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -34,3 +35,24 @@ impl<T: FixedSize> Length for arrow_buffer::ScalarBuffer<T> {
         self.as_ref().len()
     }
 }
+
+impl<T: FixedSize> Slice for arrow_buffer::ScalarBuffer<T> {
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        arrow_buffer::ScalarBuffer::slice(self, offset, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice() {
+        let buffer = [1_u32, 2, 3, 4]
+            .into_iter()
+            .collect::<arrow_buffer::ScalarBuffer<_>>();
+        let sliced = Slice::slice(&buffer, 1..3);
+        assert_eq!(sliced.as_slice(), [2, 3]);
+        assert_eq!(sliced.as_ptr(), buffer[1..].as_ptr());
+    }
+}
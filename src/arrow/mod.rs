@@ -9,6 +9,10 @@ mod bitmap;
 
 pub mod buffer;
 
+use std::collections::BTreeMap;
+
+use crate::Error;
+
 /// Extension trait of [`Array`] for [`arrow-rs`] interop.
 pub trait Array: crate::array::Array + Sized {
     /// The corresponding arrow array
@@ -35,3 +39,234 @@ impl OffsetElement for i32 {
 impl OffsetElement for i64 {
     const LARGE: bool = true;
 }
+
+/// Downcasts `array` to the concrete [`arrow_array::Array`] `T`.
+///
+/// Returns an [`Error::DataType`] with the data type returned by `expected`
+/// when the runtime type of `array` does not match `T`.
+pub(crate) fn downcast<T: arrow_array::Array + Clone + 'static>(
+    array: &dyn arrow_array::Array,
+    expected: fn() -> arrow_schema::DataType,
+) -> Result<T, Error> {
+    array
+        .as_any()
+        .downcast_ref::<T>()
+        .cloned()
+        .ok_or_else(|| Error::DataType {
+            path: Vec::new(),
+            expected: expected(),
+            actual: array.data_type().clone(),
+        })
+}
+
+/// Returns an [`Error::Nullability`] when `nulls` has nulls.
+///
+/// Null buffers without nulls are accepted for non-nullable arrays.
+pub(crate) fn non_nullable(nulls: Option<&arrow_buffer::NullBuffer>) -> Result<(), Error> {
+    match nulls {
+        Some(null_buffer) if null_buffer.null_count() != 0 => {
+            Err(Error::Nullability { path: Vec::new() })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The field metadata key of the name of an extension type.
+const EXTENSION_TYPE_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The field metadata key of the serialized metadata of an extension type.
+const EXTENSION_TYPE_METADATA_KEY: &str = "ARROW:extension:metadata";
+
+/// Returns the field metadata for the [extension type] `name` with the
+/// serialized `metadata`.
+///
+/// [extension type]: https://arrow.apache.org/docs/format/Columnar.html#extension-types
+pub(crate) fn extension_type(
+    name: &str,
+    metadata: Option<String>,
+) -> std::collections::HashMap<String, String> {
+    std::iter::once((EXTENSION_TYPE_NAME_KEY.to_owned(), name.to_owned()))
+        .chain(metadata.map(|value| (EXTENSION_TYPE_METADATA_KEY.to_owned(), value)))
+        .collect()
+}
+
+/// Returns the name and the (non-empty) serialized metadata of the extension
+/// type of `field`, if any.
+fn field_extension_type(field: &arrow_schema::Field) -> Option<(&str, Option<&str>)> {
+    let metadata = field.metadata();
+    metadata.get(EXTENSION_TYPE_NAME_KEY).map(|name| {
+        (
+            name.as_str(),
+            metadata
+                .get(EXTENSION_TYPE_METADATA_KEY)
+                .map(String::as_str)
+                .filter(|value| !value.is_empty()),
+        )
+    })
+}
+
+/// Returns the members of the serialized JSON object `json`, as their keys
+/// and their values without whitespace, or `None` if `json` is not a JSON
+/// object.
+///
+/// This only splits the top-level object, the values are not parsed.
+fn json_object_members(json: &str) -> Option<BTreeMap<&str, String>> {
+    // Split the object at the delimiters outside of strings and nested
+    // values.
+    let mut members = Vec::new();
+    let (mut depth, mut in_string, mut escaped) = (0_usize, false, false);
+    let mut start = None;
+    for (index, character) in json.char_indices() {
+        match character {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            _ if in_string => {}
+            '{' | '[' => {
+                depth += 1;
+                if depth == 1 && character == '{' {
+                    start = Some(index + 1);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    members.push(json.get(start.take()?..index)?);
+                }
+            }
+            ',' if depth == 1 => {
+                members.push(json.get(start?..index)?);
+                start = Some(index + 1);
+            }
+            _ => {}
+        }
+    }
+    if in_string || depth != 0 || !json.trim_start().starts_with('{') {
+        return None;
+    }
+    members
+        .into_iter()
+        .filter(|member| !member.trim().is_empty())
+        .map(|member| {
+            let (quoted_key, value) = member.split_once(':')?;
+            let key = quoted_key.trim().strip_prefix('"')?.strip_suffix('"')?;
+            Some((key, compact_json(value)))
+        })
+        .collect()
+}
+
+/// Returns the serialized JSON value `json` without whitespace outside of
+/// strings.
+fn compact_json(json: &str) -> String {
+    let (mut in_string, mut escaped) = (false, false);
+    json.chars()
+        .filter(|&character| {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' if in_string => escaped = true,
+                '"' => in_string = !in_string,
+                _ if !in_string && character.is_whitespace() => return false,
+                _ => {}
+            }
+            true
+        })
+        .collect()
+}
+
+/// Returns `true` when the serialized metadata `actual` of an extension type
+/// matches the expected serialized metadata `expected`.
+///
+/// Metadata that are JSON objects match when `actual` has all members of
+/// `expected`, independent of their order and whitespace. This accepts the
+/// optional members of e.g. `arrow.fixed_shape_tensor` metadata, like
+/// `dim_names`. Other metadata must be equal.
+fn extension_metadata_matches(expected: Option<&str>, actual: Option<&str>) -> bool {
+    match (expected, actual) {
+        (Some(expected_json), Some(actual_json)) => {
+            match (
+                json_object_members(expected_json),
+                json_object_members(actual_json),
+            ) {
+                (Some(expected_members), Some(actual_members)) => expected_members
+                    .iter()
+                    .all(|(key, value)| actual_members.get(key) == Some(value)),
+                _ => expected_json == actual_json,
+            }
+        }
+        // Empty JSON objects are equivalent to no metadata.
+        (None, Some(actual_json)) => {
+            json_object_members(actual_json).is_some_and(|members| members.is_empty())
+        }
+        (Some(_), None) => false,
+        (None, None) => true,
+    }
+}
+
+/// Returns an [`Error::ExtensionType`] when the extension type of
+/// `actual_field` does not match the extension type of `expected_field`.
+///
+/// Fields without an extension type are accepted, which allows conversions
+/// of untagged storage arrays.
+pub(crate) fn check_extension_type(
+    expected_field: &arrow_schema::Field,
+    actual_field: &arrow_schema::Field,
+) -> Result<(), Error> {
+    let format = |(name, metadata): (&str, Option<&str>)| match metadata {
+        Some(value) => format!("{name} {value}"),
+        None => name.to_owned(),
+    };
+    match (
+        field_extension_type(expected_field),
+        field_extension_type(actual_field),
+    ) {
+        (Some(expected), Some(actual))
+            if expected.0 != actual.0 || !extension_metadata_matches(expected.1, actual.1) =>
+        {
+            Err(Error::ExtensionType {
+                path: Vec::new(),
+                expected: format(expected),
+                actual: format(actual),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_object_members() {
+        assert_eq!(
+            super::json_object_members(
+                r#" { "shape" : [ 2, 3 ], "dim_names": ["x", "y, z"], "a\"b": {"c": 1} } "#
+            ),
+            Some(BTreeMap::from([
+                ("shape", "[2,3]".to_owned()),
+                ("dim_names", r#"["x","y, z"]"#.to_owned()),
+                (r#"a\"b"#, r#"{"c":1}"#.to_owned()),
+            ]))
+        );
+        assert_eq!(super::json_object_members("{}"), Some(BTreeMap::new()));
+        assert_eq!(super::json_object_members("[1]"), None);
+        assert_eq!(super::json_object_members(r#"{"a": 1"#), None);
+    }
+
+    #[test]
+    fn extension_metadata_matches() {
+        let expected = Some(r#"{"shape":[2,3]}"#);
+        assert!(super::extension_metadata_matches(
+            expected,
+            Some(r#"{"dim_names": ["x", "y"], "shape": [2, 3]}"#)
+        ));
+        assert!(!super::extension_metadata_matches(
+            expected,
+            Some(r#"{"shape":[3,2]}"#)
+        ));
+        assert!(!super::extension_metadata_matches(expected, None));
+        assert!(super::extension_metadata_matches(None, Some("{ }")));
+        assert!(!super::extension_metadata_matches(None, Some("metadata")));
+        assert!(!super::extension_metadata_matches(Some("a"), Some("b")));
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::{
     buffer::{Buffer, BufferMut, BufferRef, BufferRefMut, BufferType, VecBuffer},
-    Index, Length,
+    Index, Length, Slice,
 };
 use std::{
     any,
@@ -227,8 +227,11 @@ where
             let last_byte = &mut self.buffer.as_mut_slice()[last_byte_index];
             for bit_position in 8 - trailing_bits..8 {
                 if let Some(x) = items.next() {
+                    // The padding bits of a slice are not cleared.
                     if *x.borrow() {
                         *last_byte |= 1 << bit_position;
+                    } else {
+                        *last_byte &= !(1 << bit_position);
                     }
                 }
             }
@@ -265,7 +268,8 @@ where
 }
 
 impl<Buffer: BufferType> Index for Bitmap<Buffer> {
-    type Item<'a> = bool
+    type Item<'a>
+        = bool
     where
         Self: 'a;
 
@@ -348,11 +352,31 @@ impl<const N: usize, Buffer: BufferType> PartialEq<[bool; N]> for Bitmap<Buffer>
     }
 }
 
+impl<Buffer: BufferType> Slice for Bitmap<Buffer>
+where
+    <Buffer as BufferType>::Buffer<u8>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        // Keep the bytes that contain the bits of the slice, and carry the
+        // remaining bit offset.
+        let start = self.offset + offset;
+        let first_byte = start / 8;
+        let last_byte = (start + len + 7) / 8;
+        Self {
+            buffer: self
+                .buffer
+                .slice_unchecked(first_byte, last_byte - first_byte),
+            bits: len,
+            offset: start % 8,
+        }
+    }
+}
+
 impl<Buffer: BufferType> ValidityBitmap for Bitmap<Buffer> {}
 
 #[cfg(test)]
 mod tests {
-    use crate::buffer::{ArrayBuffer, BoxBuffer, SliceBuffer};
+    use crate::buffer::{ArcSliceBuffer, ArrayBuffer, BoxBuffer, SliceBuffer};
 
     use super::*;
     use std::mem;
@@ -510,6 +534,33 @@ mod tests {
         assert_eq!(bitmap.into_iter().collect::<Vec<_>>(), vec);
     }
 
+    #[test]
+    fn slice() {
+        let bools = [
+            true, false, true, true, false, false, true, false, true, true,
+        ];
+        let bitmap = bools.iter().collect::<Bitmap<ArcSliceBuffer>>();
+        let sliced = bitmap.slice(3..9);
+        assert_eq!(sliced.len(), 6);
+        assert_eq!(sliced.leading_bits(), 3);
+        assert_eq!(sliced.buffer_ref().len(), 2);
+        assert_eq!(sliced.iter().collect::<Vec<_>>(), bools[3..9]);
+        let mut sliced_twice = sliced.slice(5..);
+        assert_eq!(sliced_twice.leading_bits(), 0);
+        assert_eq!(sliced_twice.buffer_ref().len(), 1);
+        assert_eq!(sliced_twice, [true]);
+        sliced_twice.extend([false, true]);
+        assert_eq!(sliced_twice, [true, false, true]);
+        assert_eq!(bitmap.slice(8..), [true, true]);
+        assert!(bitmap.slice(10..).is_empty());
+
+        let bytes = [0b1010_0000_u8, 0b0000_0001];
+        // Safety:
+        // - 2 bytes have 6 bits with an offset of 4.
+        let bitmap_slice = unsafe { Bitmap::<SliceBuffer>::from_raw_parts(&bytes, 6, 4) };
+        assert_eq!(bitmap_slice.slice(1..5), [true, false, true, true]);
+    }
+
     #[test]
     fn size_of() {
         assert_eq!(
@@ -1,7 +1,14 @@
 //! Traits for memory buffers.
 
-use crate::{FixedSize, Index, Length};
-use std::{marker::PhantomData, mem, rc::Rc, slice, sync::Arc};
+use crate::{FixedSize, Index, Length, Slice};
+use std::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    mem,
+    rc::Rc,
+    slice,
+    sync::Arc,
+};
 
 /// A memory buffer type constructor for Arrow data.
 ///
@@ -294,7 +301,10 @@ impl<T: FixedSize> BufferMut<T> for Box<[T]> {
 
 /// A [`BufferType`] implementation for [`Arc`].
 ///
-/// Stores items `T` in `Arc<[T]>`.
+/// Stores items `T` in `Arc<[T]>`. An `Arc<[T]>` can't refer to a window of
+/// its allocation, so arrays with this buffer type don't implement
+/// [`Slice`]. Use [`ArcSliceBuffer`] for shared buffers that support
+/// zero-copy slicing.
 #[derive(Clone, Copy, Debug)]
 pub struct ArcBuffer;
 
@@ -302,6 +312,161 @@ impl BufferType for ArcBuffer {
     type Buffer<T: FixedSize> = Arc<[T]>;
 }
 
+/// A [`BufferType`] implementation for [`ArcSlice`].
+///
+/// Stores items `T` in [`ArcSlice<T>`], a window into an `Arc<Vec<T>>`, which
+/// supports zero-copy slicing.
+#[derive(Clone, Copy, Debug)]
+pub struct ArcSliceBuffer;
+
+impl BufferType for ArcSliceBuffer {
+    type Buffer<T: FixedSize> = ArcSlice<T>;
+}
+
+/// A window into a shared `Arc<Vec<T>>`.
+///
+/// Clones and slices of this buffer share the same allocation.
+pub struct ArcSlice<T> {
+    /// The shared allocation.
+    buffer: Arc<Vec<T>>,
+    /// The offset of this window in the allocation.
+    offset: usize,
+    /// The number of items in this window.
+    len: usize,
+}
+
+impl<T> AsRef<[T]> for ArcSlice<T> {
+    fn as_ref(&self) -> &[T] {
+        // Safety:
+        // - The window is within bounds of the allocation by construction.
+        unsafe {
+            self.buffer
+                .get_unchecked(self.offset..self.offset + self.len)
+        }
+    }
+}
+
+impl<T: FixedSize> Buffer<T> for ArcSlice<T> {
+    fn as_slice(&self) -> &[T] {
+        self.as_ref()
+    }
+}
+
+impl<T: FixedSize> BufferMut<T> for ArcSlice<T> {
+    /// Returns a mutable slice of the items in this window.
+    ///
+    /// The items of this window are copied into a new allocation first when
+    /// the allocation is shared.
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        if Arc::get_mut(&mut self.buffer).is_none() {
+            *self = Self::from(self.as_ref().to_vec());
+        }
+        let range = self.offset..self.offset + self.len;
+        match Arc::get_mut(&mut self.buffer) {
+            Some(vec) => &mut vec[range],
+            None => unreachable!("allocation is not shared"),
+        }
+    }
+}
+
+impl<T> Clone for ArcSlice<T> {
+    fn clone(&self) -> Self {
+        Self {
+            buffer: Arc::clone(&self.buffer),
+            offset: self.offset,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: Debug> Debug for ArcSlice<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_ref()).finish()
+    }
+}
+
+impl<T> Default for ArcSlice<T> {
+    fn default() -> Self {
+        Self::from(Vec::default())
+    }
+}
+
+impl<T: Clone> Extend<T> for ArcSlice<T> {
+    /// Extends this buffer with the items of the iterator.
+    ///
+    /// The allocation grows in place when it is not shared and this window
+    /// ends at the end of the allocation. Otherwise the items of this window
+    /// are copied into a new allocation first.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let end = self.offset + self.len;
+        match Arc::get_mut(&mut self.buffer) {
+            Some(vec) if vec.len() == end => {
+                vec.extend(iter);
+                self.len = vec.len() - self.offset;
+            }
+            _ => {
+                *self = self.as_ref().iter().cloned().chain(iter).collect();
+            }
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for ArcSlice<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> From<Vec<T>> for ArcSlice<T> {
+    fn from(value: Vec<T>) -> Self {
+        let len = value.len();
+        Self {
+            buffer: Arc::new(value),
+            offset: 0,
+            len,
+        }
+    }
+}
+
+impl<T> FromIterator<T> for ArcSlice<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T> Index for ArcSlice<T> {
+    type Item<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.as_ref().get_unchecked(index)
+    }
+}
+
+impl<T> Length for ArcSlice<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T: PartialEq, U: AsRef<[T]>> PartialEq<U> for ArcSlice<T> {
+    fn eq(&self, other: &U) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<T> Slice for ArcSlice<T> {
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            buffer: Arc::clone(&self.buffer),
+            offset: self.offset + offset,
+            len,
+        }
+    }
+}
+
 impl<T: FixedSize> Buffer<T> for Arc<[T]> {
     fn as_slice(&self) -> &[T] {
         <&[T]>::from(self)
@@ -389,18 +554,41 @@ mod tests {
         let slice: <SliceBuffer as BufferType>::Buffer<u16> = &[1234, 4321];
         assert_eq!(slice.as_bytes(), &[210, 4, 225, 16]);
         let mut slice_mut: <SliceMutBuffer as BufferType>::Buffer<u16> = &mut [4321, 1234];
-        slice_mut.as_mut_slice()[0] = 1234;
-        slice_mut.as_mut_slice()[1] = 4321;
+        BufferMut::as_mut_slice(&mut slice_mut)[0] = 1234;
+        BufferMut::as_mut_slice(&mut slice_mut)[1] = 4321;
         assert_eq!(slice, slice_mut);
     }
 
+    #[test]
+    fn arc_slice() {
+        let arc: <ArcSliceBuffer as BufferType>::Buffer<u16> = [1, 2, 3, 4].into_iter().collect();
+        assert_eq!(arc.as_slice(), [1, 2, 3, 4]);
+        let mut sliced = arc.slice(1..3);
+        assert_eq!(sliced, [2, 3]);
+        assert_eq!(sliced.as_bytes(), [2, 0, 3, 0]);
+        assert_eq!(sliced.index_checked(1), &3);
+        drop(arc);
+        sliced.as_mut_slice()[0] = 5;
+        assert_eq!(sliced, [5, 3]);
+        sliced.extend([6, 7]);
+        assert_eq!(sliced, [5, 3, 6, 7]);
+        let mut tail = sliced.slice(2..);
+        tail.extend([8]);
+        assert_eq!(tail, [6, 7, 8]);
+        assert_eq!(sliced, [5, 3, 6, 7]);
+        let mut head = sliced.slice(..1);
+        head.as_mut_slice()[0] = 9;
+        assert_eq!(head, [9]);
+        assert_eq!(sliced, [5, 3, 6, 7]);
+    }
+
     #[test]
     fn slice_array() {
         let slice_array: <SliceArrayBuffer<2> as BufferType>::Buffer<u32> = &[[1, 2], [3, 4]];
         assert_eq!(<_ as Buffer<u32>>::as_slice(&slice_array), [1, 2, 3, 4]);
         let mut slice_array_mut: <SliceArrayMutBuffer<3> as BufferType>::Buffer<u8> =
             &mut [[1, 2, 3], [4, 5, 6]];
-        slice_array_mut.as_mut_slice()[0] = 0;
+        <_ as BufferMut<u8>>::as_mut_slice(&mut slice_array_mut)[0] = 0;
         assert_eq!(
             <_ as Buffer<u8>>::as_bytes(&slice_array_mut),
             &[0, 2, 3, 4, 5, 6]
@@ -0,0 +1,204 @@
+//! Error type for fallible operations.
+
+use std::fmt::{self, Display, Formatter};
+
+/// Errors returned by fallible operations, e.g. conversions from
+/// [`arrow-rs`] arrays.
+///
+/// The `path` of an error contains the names of the (nested) fields that lead
+/// to the array that caused the error, starting at the outermost field.
+///
+/// [`arrow-rs`]: https://crates.io/crates/arrow
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The data type of an array does not match the expected data type.
+    #[cfg(feature = "arrow-rs")]
+    DataType {
+        /// The path of the field with the mismatching data type.
+        path: Vec<String>,
+        /// The expected data type.
+        expected: arrow_schema::DataType,
+        /// The actual data type.
+        actual: arrow_schema::DataType,
+    },
+    /// The extension type of a field does not match the expected extension
+    /// type.
+    #[cfg(feature = "arrow-rs")]
+    ExtensionType {
+        /// The path of the field with the mismatching extension type.
+        path: Vec<String>,
+        /// The name and metadata of the expected extension type.
+        expected: String,
+        /// The name and metadata of the actual extension type.
+        actual: String,
+    },
+    /// An [`arrow-rs`] operation on an array failed.
+    ///
+    /// [`arrow-rs`]: https://crates.io/crates/arrow
+    #[cfg(feature = "arrow-rs")]
+    Arrow {
+        /// The path of the field of the array.
+        path: Vec<String>,
+        /// The message of the [`arrow_schema::ArrowError`].
+        message: String,
+    },
+    /// An array with nulls was converted into a non-nullable array.
+    Nullability {
+        /// The path of the field with nulls.
+        path: Vec<String>,
+    },
+    /// A field is missing.
+    MissingField {
+        /// The path of the field that is missing a child field.
+        path: Vec<String>,
+        /// The name of the missing field.
+        name: String,
+    },
+    /// The number of child arrays does not match the expected number.
+    FieldCount {
+        /// The path of the field with the mismatching number of child arrays.
+        path: Vec<String>,
+        /// The expected number of child arrays.
+        expected: usize,
+        /// The actual number of child arrays.
+        actual: usize,
+    },
+    /// A value of an array is invalid for its logical type.
+    InvalidValue {
+        /// The path of the field with the invalid value.
+        path: Vec<String>,
+        /// The index of the invalid value.
+        index: usize,
+        /// The reason the value is invalid.
+        message: String,
+    },
+}
+
+impl Error {
+    /// Returns the path of the field that caused this error.
+    #[must_use]
+    pub fn path(&self) -> &[String] {
+        match *self {
+            #[cfg(feature = "arrow-rs")]
+            Self::DataType { ref path, .. }
+            | Self::ExtensionType { ref path, .. }
+            | Self::Arrow { ref path, .. } => path,
+            Self::Nullability { ref path }
+            | Self::MissingField { ref path, .. }
+            | Self::FieldCount { ref path, .. }
+            | Self::InvalidValue { ref path, .. } => path,
+        }
+    }
+
+    /// Prepends the field `name` to the path of this error.
+    ///
+    /// This is used when an error of a child array is propagated via its
+    /// parent array.
+    #[must_use]
+    pub fn with_field(mut self, name: &str) -> Self {
+        match self {
+            #[cfg(feature = "arrow-rs")]
+            Self::DataType { ref mut path, .. }
+            | Self::ExtensionType { ref mut path, .. }
+            | Self::Arrow { ref mut path, .. } => path,
+            Self::Nullability { ref mut path }
+            | Self::MissingField { ref mut path, .. }
+            | Self::FieldCount { ref mut path, .. }
+            | Self::InvalidValue { ref mut path, .. } => path,
+        }
+        .insert(0, name.to_owned());
+        self
+    }
+}
+
+/// Formats a field path as a prefix of an error message.
+struct Path<'a>(&'a [String]);
+
+impl Display for Path<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            write!(f, "field `{}`: ", self.0.join("."))
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let path = Path(self.path());
+        match *self {
+            #[cfg(feature = "arrow-rs")]
+            Self::DataType {
+                ref expected,
+                ref actual,
+                ..
+            } => write!(f, "{path}expected data type {expected}, found {actual}"),
+            #[cfg(feature = "arrow-rs")]
+            Self::ExtensionType {
+                ref expected,
+                ref actual,
+                ..
+            } => write!(
+                f,
+                "{path}expected extension type {expected}, found {actual}"
+            ),
+            #[cfg(feature = "arrow-rs")]
+            Self::Arrow { ref message, .. } => write!(f, "{path}{message}"),
+            Self::Nullability { .. } => write!(f, "{path}expected array without nulls"),
+            Self::MissingField { ref name, .. } => write!(f, "{path}missing field `{name}`"),
+            Self::FieldCount {
+                expected, actual, ..
+            } => write!(f, "{path}expected {expected} child arrays, found {actual}"),
+            Self::InvalidValue {
+                index, ref message, ..
+            } => write!(f, "{path}invalid value at index {index}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(feature = "arrow-rs")]
+impl From<arrow_schema::ArrowError> for Error {
+    fn from(value: arrow_schema::ArrowError) -> Self {
+        Self::Arrow {
+            path: Vec::new(),
+            message: value.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_field() {
+        let error = Error::Nullability { path: Vec::new() };
+        assert!(error.path().is_empty());
+        assert_eq!(error.to_string(), "expected array without nulls");
+
+        let nested = error.with_field("b").with_field("a");
+        assert_eq!(nested.path(), ["a", "b"]);
+        assert_eq!(
+            nested.to_string(),
+            "field `a.b`: expected array without nulls"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn arrow() {
+        let error = Error::from(arrow_schema::ArrowError::InvalidArgumentError(
+            "bad child data".to_owned(),
+        ))
+        .with_field("a");
+        assert_eq!(error.path(), ["a"]);
+        assert_eq!(
+            error.to_string(),
+            "field `a`: Invalid argument error: bad child data"
+        );
+    }
+}
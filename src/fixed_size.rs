@@ -61,6 +61,9 @@ impl FixedSize for usize {}
 impl FixedSize for f32 {}
 impl FixedSize for f64 {}
 
+#[cfg(feature = "half")]
+impl FixedSize for half::f16 {}
+
 #[cfg(not(feature = "arrow-rs"))]
 impl<const N: usize, T: super::FixedSize> FixedSize for [T; N] {}
 
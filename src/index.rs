@@ -1,6 +1,6 @@
 //! Indexing operations.
 
-use crate::Length;
+use crate::{FixedSize, Length};
 use std::{collections::VecDeque, rc::Rc, sync::Arc};
 
 /// Index operation for shared access to values in a collection.
@@ -49,8 +49,78 @@ pub trait Index: Length {
     unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_>;
 }
 
+/// Conversion of borrowed [`Index`] items into owned values.
+///
+/// Items returned by [`Index::index`] may borrow from the collection. This
+/// is used to convert these items back into the types that were used to
+/// construct the collection.
+pub trait IntoOwned<T> {
+    /// Converts this item into an owned value.
+    fn into_owned(self) -> T;
+}
+
+impl<T: FixedSize> IntoOwned<T> for &T {
+    fn into_owned(self) -> T {
+        *self
+    }
+}
+
+impl IntoOwned<bool> for bool {
+    fn into_owned(self) -> bool {
+        self
+    }
+}
+
+impl IntoOwned<String> for &str {
+    fn into_owned(self) -> String {
+        self.to_owned()
+    }
+}
+
+impl IntoOwned<Vec<u8>> for &[u8] {
+    fn into_owned(self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl<T, U: IntoOwned<T>> IntoOwned<Option<T>> for Option<U> {
+    fn into_owned(self) -> Option<T> {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+impl<T, U: IntoOwned<T>, const N: usize> IntoOwned<[T; N]> for [U; N] {
+    fn into_owned(self) -> [T; N] {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+/// Index operation that converts the borrowed item into an owned value.
+///
+/// This is implemented for all [`Index`] types with items that implement
+/// [`IntoOwned`], and can be used in bounds where the item type of the
+/// collection can't be named.
+pub trait IndexOwned<'a, T> {
+    /// Returns the owned value at given index. Skips bound checking.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure index is within bounds.
+    unsafe fn index_owned_unchecked(&'a self, index: usize) -> T;
+}
+
+impl<'a, T, U: Index + 'a> IndexOwned<'a, T> for U
+where
+    <U as Index>::Item<'a>: IntoOwned<T>,
+{
+    unsafe fn index_owned_unchecked(&'a self, index: usize) -> T {
+        self.index_unchecked(index).into_owned()
+    }
+}
+
 impl<T> Index for Vec<T> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -60,7 +130,8 @@ impl<T> Index for Vec<T> {
 }
 
 impl<T, const N: usize> Index for [T; N] {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -70,7 +141,8 @@ impl<T, const N: usize> Index for [T; N] {
 }
 
 impl<T> Index for [T] {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -80,7 +152,8 @@ impl<T> Index for [T] {
 }
 
 impl<T> Index for &[T] {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -90,7 +163,8 @@ impl<T> Index for &[T] {
 }
 
 impl<T> Index for &mut [T] {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -100,7 +174,8 @@ impl<T> Index for &mut [T] {
 }
 
 impl<T> Index for Box<[T]> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -110,7 +185,8 @@ impl<T> Index for Box<[T]> {
 }
 
 impl<T> Index for Rc<[T]> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -120,7 +196,8 @@ impl<T> Index for Rc<[T]> {
 }
 
 impl<T> Index for Arc<[T]> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -130,7 +207,8 @@ impl<T> Index for Arc<[T]> {
 }
 
 impl<T> Index for VecDeque<T> {
-    type Item<'a> = &'a T
+    type Item<'a>
+        = &'a T
     where
         Self: 'a;
 
@@ -42,7 +42,6 @@
     clippy::shadow_unrelated,
     clippy::str_to_string,
     clippy::string_add,
-    clippy::string_to_string,
     clippy::tests_outside_test_module,
     clippy::undocumented_unsafe_blocks,
     clippy::unnecessary_safety_comment,
@@ -63,7 +62,13 @@ mod length;
 pub use self::length::Length;
 
 mod index;
-pub use self::index::Index;
+pub use self::index::{Index, IndexOwned, IntoOwned};
+
+mod slice;
+pub use self::slice::Slice;
+
+mod error;
+pub use self::error::Error;
 
 pub mod buffer;
 
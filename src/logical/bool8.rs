@@ -0,0 +1,138 @@
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// A boolean stored as an 8-bit integer, with the [`arrow.bool8`] canonical
+/// extension type.
+///
+/// [`arrow.bool8`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#bit-boolean
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bool8(pub bool);
+
+impl From<bool> for Bool8 {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bool8> for bool {
+    fn from(value: Bool8) -> Self {
+        value.0
+    }
+}
+
+impl ArrayType<Bool8> for Bool8 {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Bool8> for Option<Bool8> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Bool8, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Bool8> for Bool8 {
+    type ArrayType = i8;
+
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() -> Option<(&'static str, Option<String>)> {
+        Some(("arrow.bool8", None))
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Self(item != 0)
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        i8::from(self.0)
+    }
+}
+
+/// An array for [`Bool8`] items.
+#[allow(unused)]
+pub type Bool8Array<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Bool8, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+
+    #[test]
+    fn from_iter() {
+        let array = [Bool8(true), Bool8(false)]
+            .into_iter()
+            .collect::<Bool8Array>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0.len(), 2);
+
+        let array_nullable = [Some(Bool8(true)), None]
+            .into_iter()
+            .collect::<Bool8Array<true>>();
+        assert_eq!(array_nullable.len(), 2);
+        assert_eq!(array_nullable.0.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let input = [Bool8(true), Bool8(false)];
+        let array = input.into_iter().collect::<Bool8Array>();
+        assert_eq!(array.0 .0.as_slice(), [1, 0]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_nullable = [Some(Bool8(false)), None];
+        let array_nullable = input_nullable.into_iter().collect::<Bool8Array<true>>();
+        assert_eq!(
+            array_nullable.into_iter().collect::<Vec<_>>(),
+            input_nullable
+        );
+    }
+
+    #[test]
+    fn index() {
+        let array = [Bool8(true), Bool8(false)]
+            .into_iter()
+            .collect::<Bool8Array>();
+        assert_eq!(array.index_checked(0), Bool8(true));
+        assert_eq!(array.index_checked(1), Bool8(false));
+        assert_eq!(array.index(2), None);
+
+        assert_eq!(Bool8::from_array_type(-1), Bool8(true));
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() {
+        use std::sync::Arc;
+
+        use arrow_schema::DataType;
+
+        use crate::arrow::Array as _;
+
+        let field = Bool8Array::<true>::as_field("flag");
+        assert_eq!(field.data_type(), &DataType::Int8);
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("arrow.bool8")
+        );
+        assert!(!field.metadata().contains_key("ARROW:extension:metadata"));
+
+        // Non-zero values are true.
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::Int8Array::from(vec![0, 1, -1]));
+        assert_eq!(
+            Bool8Array::<false>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Bool8(false), Bool8(true), Bool8(true)]
+        );
+    }
+}
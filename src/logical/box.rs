@@ -1,4 +1,4 @@
-use crate::array::ArrayType;
+use crate::array::{ArrayType, VariableSizeBinary};
 
 use super::{LogicalArray, LogicalArrayType};
 
@@ -44,10 +44,76 @@ where
 pub type BoxArray<T, const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
     LogicalArray<Box<T>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
 
+impl ArrayType<Box<str>> for Box<str> {
+    type Array<
+        Buffer: crate::buffer::BufferType,
+        OffsetItem: crate::offset::OffsetElement,
+        UnionLayout: crate::array::UnionType,
+    > = LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Box<str>> for Option<Box<str>> {
+    type Array<
+        Buffer: crate::buffer::BufferType,
+        OffsetItem: crate::offset::OffsetElement,
+        UnionLayout: crate::array::UnionType,
+    > = LogicalArray<Box<str>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Box<str>> for Box<str> {
+    type ArrayType = String;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_boxed_str()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_string()
+    }
+}
+
+/// An array for [`Box<str>`] items.
+#[allow(unused)]
+pub type BoxStrArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Box<str>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<Box<[u8]>> for Box<[u8]> {
+    type Array<
+        Buffer: crate::buffer::BufferType,
+        OffsetItem: crate::offset::OffsetElement,
+        UnionLayout: crate::array::UnionType,
+    > = LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Box<[u8]>> for Option<Box<[u8]>> {
+    type Array<
+        Buffer: crate::buffer::BufferType,
+        OffsetItem: crate::offset::OffsetElement,
+        UnionLayout: crate::array::UnionType,
+    > = LogicalArray<Box<[u8]>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Box<[u8]>> for Box<[u8]> {
+    type ArrayType = VariableSizeBinary;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Vec::from(item).into_boxed_slice()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_vec().into()
+    }
+}
+
+/// An array for [`Box<[u8]>`] items.
+#[allow(unused)]
+pub type BoxBytesArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Box<[u8]>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Length;
+    use crate::{Index, Length};
 
     #[test]
     fn from_iter() {
@@ -79,4 +145,37 @@ mod tests {
         let output_nullable = array_nullable.into_iter().collect::<Vec<_>>();
         assert_eq!(input_nullable, output_nullable.as_slice());
     }
+
+    #[test]
+    fn index() {
+        let array = [Box::new("a".to_owned()), Box::new("bc".to_owned())]
+            .into_iter()
+            .collect::<BoxArray<String>>();
+        assert_eq!(array.index_checked(1), Box::new("bc".to_owned()));
+        assert_eq!(array.index(2), None);
+
+        let array_nullable = [Some(Box::new(1)), None]
+            .into_iter()
+            .collect::<BoxArray<i32, true>>();
+        assert_eq!(array_nullable.index_checked(0), Some(Box::new(1)));
+        assert_eq!(array_nullable.index_checked(1), None);
+    }
+
+    #[test]
+    fn str() {
+        let input: [Option<Box<str>>; 2] = [Some("a".into()), None];
+        let array = input.clone().into_iter().collect::<BoxStrArray<true>>();
+        assert_eq!(array.0.len(), 2);
+        assert_eq!(array.index_checked(0), Some("a".into()));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn bytes() {
+        let input: [Box<[u8]>; 2] = [Box::new([1, 2]), Box::new([])];
+        let array = input.clone().into_iter().collect::<BoxBytesArray>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.index_checked(0), Box::from([1, 2]));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
 }
@@ -0,0 +1,86 @@
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+impl ArrayType<char> for char {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<char> for Option<char> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<char, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<char> for char {
+    // The Unicode scalar value of the char.
+    type ArrayType = u32;
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::UInt32Type>(array, |item| {
+            char::from_u32(item)
+                .is_none()
+                .then(|| format!("invalid Unicode scalar value {item:#x}"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        char::from_u32(item).expect("valid char")
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        u32::from(self)
+    }
+}
+
+/// An array for [`char`] items, stored as [`u32`] Unicode scalar values.
+pub type CharArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<char, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_values() {
+        // The chars around the surrogate range.
+        let input = ['a', '\u{D7FF}', '\u{E000}', '🦀', char::MAX];
+        let array = input.into_iter().collect::<CharArray>();
+        assert_eq!(
+            array.0 .0.as_slice(),
+            [0x61, 0xD7FF, 0xE000, 0x1_F980, 0x10_FFFF]
+        );
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn invalid() {
+        use std::sync::Arc;
+
+        use arrow_array::Array as _;
+        use arrow_schema::DataType;
+
+        let surrogate: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::UInt32Array::from(vec![0x61, 0xD800]));
+        assert_eq!(surrogate.data_type(), &DataType::UInt32);
+        assert_eq!(
+            CharArray::<false>::try_from(surrogate).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: "invalid Unicode scalar value 0xd800".to_owned(),
+            })
+        );
+
+        let out_of_range: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::UInt32Array::from(vec![Some(0x11_0000), None]));
+        assert!(CharArray::<true>::try_from(out_of_range).is_err());
+    }
+}
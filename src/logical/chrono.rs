@@ -1,4 +1,9 @@
-use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike, Utc};
+use std::{fmt, hash, marker::PhantomData, ops::Deref};
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, Months, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta,
+    Timelike, Utc,
+};
 
 use crate::{
     array::{ArrayType, UnionType},
@@ -8,6 +13,8 @@ use crate::{
 
 use super::{LogicalArray, LogicalArrayType};
 
+pub use super::time_unit::{Microsecond, Millisecond, Nanosecond, Second, TimeUnit};
+
 impl ArrayType<DateTime<Utc>> for DateTime<Utc> {
     type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
         LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
@@ -21,6 +28,11 @@ impl ArrayType<DateTime<Utc>> for Option<DateTime<Utc>> {
 impl LogicalArrayType<DateTime<Utc>> for DateTime<Utc> {
     type ArrayType = i64;
 
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, Some("UTC".into()))
+    }
+
     fn from_array_type(item: Self::ArrayType) -> Self {
         DateTime::from_timestamp_nanos(item)
     }
@@ -47,6 +59,11 @@ impl ArrayType<NaiveDateTime> for Option<NaiveDateTime> {
 impl LogicalArrayType<NaiveDateTime> for NaiveDateTime {
     type ArrayType = i64;
 
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, None)
+    }
+
     fn from_array_type(item: Self::ArrayType) -> Self {
         DateTime::from_timestamp_nanos(item).naive_utc()
     }
@@ -70,15 +87,40 @@ impl ArrayType<NaiveDate> for Option<NaiveDate> {
         LogicalArray<NaiveDate, true, Buffer, OffsetItem, UnionLayout>;
 }
 
+/// The number of days from the start of the Common Era to the Unix epoch.
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// Returns the date `days` days since the Unix epoch, or `None` when out of
+/// range.
+fn date_from_days(days: i32) -> Option<NaiveDate> {
+    days.checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+}
+
 impl LogicalArrayType<NaiveDate> for NaiveDate {
     type ArrayType = i32;
 
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Date32
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::Date32Type>(array, |days| {
+            date_from_days(days)
+                .is_none()
+                .then(|| format!("date {days} out of range"))
+        })
+    }
+
     fn from_array_type(item: Self::ArrayType) -> Self {
-        NaiveDate::from_num_days_from_ce_opt(item).expect("out of range")
+        // Values converted from Arrow are validated.
+        date_from_days(item).expect("out of range")
     }
 
     fn into_array_type(self) -> Self::ArrayType {
-        self.num_days_from_ce()
+        self.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE
     }
 }
 
@@ -99,16 +141,39 @@ impl ArrayType<NaiveTime> for Option<NaiveTime> {
 /// The number of nano seconds in a second.
 const NANO_SECONDS: i64 = 1_000_000_000;
 
+/// Returns the time `nanos` nanoseconds since midnight, or `None` when out of
+/// range.
+fn time_from_nanos(nanos: i64) -> Option<NaiveTime> {
+    let (secs, nano) = (
+        nanos.div_euclid(NANO_SECONDS),
+        nanos.rem_euclid(NANO_SECONDS),
+    );
+    NaiveTime::from_num_seconds_from_midnight_opt(
+        u32::try_from(secs).ok()?,
+        u32::try_from(nano).ok()?,
+    )
+}
+
 impl LogicalArrayType<NaiveTime> for NaiveTime {
     type ArrayType = i64;
 
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Time64(arrow_schema::TimeUnit::Nanosecond)
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::Time64NanosecondType>(array, |nanos| {
+            time_from_nanos(nanos)
+                .is_none()
+                .then(|| format!("time {nanos} out of range"))
+        })
+    }
+
     fn from_array_type(item: Self::ArrayType) -> Self {
-        let (secs, nano) = (item.div_euclid(NANO_SECONDS), item.rem_euclid(NANO_SECONDS));
-        Self::from_num_seconds_from_midnight_opt(
-            u32::try_from(secs).expect("out of range"),
-            u32::try_from(nano).expect("out of range"),
-        )
-        .expect("out of range")
+        // Values converted from Arrow are validated.
+        time_from_nanos(item).expect("out of range")
     }
 
     fn into_array_type(self) -> Self::ArrayType {
@@ -133,6 +198,11 @@ impl ArrayType<TimeDelta> for Option<TimeDelta> {
 impl LogicalArrayType<TimeDelta> for TimeDelta {
     type ArrayType = i64;
 
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Duration(arrow_schema::TimeUnit::Nanosecond)
+    }
+
     fn from_array_type(item: Self::ArrayType) -> Self {
         Self::nanoseconds(item)
     }
@@ -146,23 +216,475 @@ impl LogicalArrayType<TimeDelta> for TimeDelta {
 pub type TimeDeltaArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
     LogicalArray<TimeDelta, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
 
+impl ArrayType<Months> for Months {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Months> for Option<Months> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Months, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Months> for Months {
+    type ArrayType = i32;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Interval(arrow_schema::IntervalUnit::YearMonth)
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::IntervalYearMonthType>(array, |months| {
+            (months < 0).then(|| format!("negative interval of {months} months"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        Self::new(u32::try_from(item).expect("out of range"))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        i32::try_from(self.as_u32()).expect("out of range")
+    }
+}
+
+/// An array for [`Months`] items.
+pub type MonthsArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Months, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+/// Time zones of [`Timestamp`] items.
+///
+/// The time zone is part of the type, so it can be stored in the Arrow data
+/// type. Other time zones are supported by implementing this trait for a
+/// marker type. Marker types for `chrono-tz` time zones are declared with
+/// the [`chrono_tz_time_zone`](crate::chrono_tz_time_zone) macro.
+pub trait TimeZone {
+    /// The corresponding chrono time zone.
+    type TimeZone: chrono::TimeZone;
+
+    /// Returns the chrono time zone.
+    fn time_zone() -> Self::TimeZone;
+
+    /// Returns the name of the time zone, as stored in the Arrow data type.
+    fn name() -> String;
+}
+
+impl TimeZone for Utc {
+    type TimeZone = Self;
+
+    fn time_zone() -> Self::TimeZone {
+        Self
+    }
+
+    fn name() -> String {
+        "UTC".to_owned()
+    }
+}
+
+/// A [`TimeZone`] with a fixed offset of `SECONDS` east of UTC.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Offset<const SECONDS: i32>;
+
+impl<const SECONDS: i32> TimeZone for Offset<SECONDS> {
+    type TimeZone = FixedOffset;
+
+    fn time_zone() -> Self::TimeZone {
+        FixedOffset::east_opt(SECONDS).expect("offset out of range")
+    }
+
+    fn name() -> String {
+        Self::time_zone().to_string()
+    }
+}
+
+/// Returns the date time that is the given number of units since the Unix
+/// epoch, or `None` if it can't be represented.
+fn to_date_time<Unit: TimeUnit>(value: i64) -> Option<DateTime<Utc>> {
+    let nanoseconds = Unit::to_nanoseconds(value);
+    DateTime::from_timestamp(
+        i64::try_from(nanoseconds.div_euclid(NANO_SECONDS.into())).ok()?,
+        u32::try_from(nanoseconds.rem_euclid(NANO_SECONDS.into())).ok()?,
+    )
+}
+
+/// Returns the number of units since the Unix epoch of the given date time,
+/// or `None` if it can't be represented.
+fn from_date_time<Unit: TimeUnit>(date_time: &DateTime<Utc>) -> Option<i64> {
+    Unit::from_nanoseconds(
+        i128::from(date_time.timestamp()) * i128::from(NANO_SECONDS)
+            + i128::from(date_time.timestamp_subsec_nanos()),
+    )
+}
+
+/// A [`DateTime`] stored as a timestamp with the given [`TimeUnit`] and
+/// [`TimeZone`].
+///
+/// Unlike [`DateTime<Utc>`] items, that are stored as nanoseconds, this can
+/// represent all dates with a unit of [`Microsecond`] or coarser.
+///
+/// # Panics
+///
+/// With `Unit = Nanosecond`, storing a date time outside the years 1677 to
+/// 2262 panics.
+pub struct Timestamp<Unit: TimeUnit = Microsecond, Tz: TimeZone = Utc>(
+    DateTime<Tz::TimeZone>,
+    PhantomData<Unit>,
+);
+
+impl<Unit: TimeUnit, Tz: TimeZone> Timestamp<Unit, Tz> {
+    /// Returns a new timestamp for the given date time.
+    pub fn new(date_time: DateTime<Tz::TimeZone>) -> Self {
+        Self(date_time, PhantomData)
+    }
+
+    /// Returns the inner date time.
+    pub fn into_inner(self) -> DateTime<Tz::TimeZone> {
+        self.0
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> Clone for Timestamp<Unit, Tz> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> Copy for Timestamp<Unit, Tz> where DateTime<Tz::TimeZone>: Copy {}
+
+impl<Unit: TimeUnit, Tz: TimeZone> Default for Timestamp<Unit, Tz> {
+    fn default() -> Self {
+        Self::new(DateTime::<Utc>::UNIX_EPOCH.with_timezone(&Tz::time_zone()))
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> fmt::Debug for Timestamp<Unit, Tz> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Timestamp").field(&self.0).finish()
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> PartialEq for Timestamp<Unit, Tz> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> Eq for Timestamp<Unit, Tz> {}
+
+impl<Unit: TimeUnit, Tz: TimeZone> hash::Hash for Timestamp<Unit, Tz> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> Deref for Timestamp<Unit, Tz> {
+    type Target = DateTime<Tz::TimeZone>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> From<DateTime<Tz::TimeZone>> for Timestamp<Unit, Tz> {
+    fn from(value: DateTime<Tz::TimeZone>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> ArrayType<Timestamp<Unit, Tz>> for Timestamp<Unit, Tz> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> ArrayType<Timestamp<Unit, Tz>> for Option<Timestamp<Unit, Tz>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Timestamp<Unit, Tz>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<Unit: TimeUnit, Tz: TimeZone> LogicalArrayType<Timestamp<Unit, Tz>> for Timestamp<Unit, Tz> {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(Unit::ARROW_TIME_UNIT, Some(Tz::name().into()))
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<Unit::ArrowTimestampType>(array, |timestamp| {
+            to_date_time::<Unit>(timestamp)
+                .is_none()
+                .then(|| format!("timestamp {timestamp} out of range"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        Self::new(
+            to_date_time::<Unit>(item)
+                .expect("out of range")
+                .with_timezone(&Tz::time_zone()),
+        )
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        from_date_time::<Unit>(&self.0.with_timezone(&Utc)).expect("out of range")
+    }
+}
+
+/// An array for [`Timestamp`] items.
+pub type TimestampArray<
+    Unit = Microsecond,
+    Tz = Utc,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+> = LogicalArray<Timestamp<Unit, Tz>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+/// Declares a marker type for a `chrono-tz` time zone, that implements
+/// [`TimeZone`](crate::logical::chrono::TimeZone).
+///
+/// [`Timestamp`](crate::logical::chrono::Timestamp) items with this time zone
+/// store its name in the Arrow data type, e.g.
+/// `Timestamp(Microsecond, Some("Europe/Amsterdam"))`.
+///
+/// ```
+/// use narrow::logical::chrono::{Microsecond, Timestamp};
+///
+/// narrow::chrono_tz_time_zone!(
+///     /// The Europe/Amsterdam time zone.
+///     pub Amsterdam = chrono_tz::Europe::Amsterdam
+/// );
+///
+/// type AmsterdamTimestamp = Timestamp<Microsecond, Amsterdam>;
+/// ```
+#[cfg(feature = "chrono-tz")]
+#[macro_export]
+macro_rules! chrono_tz_time_zone {
+    ($(#[$attr:meta])* $vis:vis $ident:ident = $time_zone:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        $vis struct $ident;
+
+        impl $crate::logical::chrono::TimeZone for $ident {
+            type TimeZone = $crate::logical::chrono::chrono_tz::Tz;
+
+            fn time_zone() -> Self::TimeZone {
+                $time_zone
+            }
+
+            fn name() -> ::std::string::String {
+                ::std::borrow::ToOwned::to_owned(Self::time_zone().name())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "chrono-tz")]
+#[doc(hidden)]
+pub use chrono_tz;
+
+/// Returns the struct data type of date times with a runtime time zone, with
+/// the fields named `timestamp` and `time_zone`.
+#[cfg(feature = "arrow-rs")]
+fn with_field_names(data_type: arrow_schema::DataType, time_zone: &str) -> arrow_schema::DataType {
+    match data_type {
+        arrow_schema::DataType::Struct(fields) => arrow_schema::DataType::Struct(
+            fields
+                .iter()
+                .zip(["timestamp", time_zone])
+                .map(|(field, name)| field.as_ref().clone().with_name(name))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Validates the time zones of the struct array `array` of date times with a
+/// runtime time zone, stored in its second field of type `T`. The `invalid`
+/// function returns the time zone at the given index if it's invalid.
+#[cfg(feature = "arrow-rs")]
+fn validate_time_zones<T: arrow_array::Array + 'static>(
+    array: &dyn arrow_array::Array,
+    invalid: impl Fn(&T, usize) -> Option<String>,
+) -> Result<(), crate::Error> {
+    use arrow_array::{cast::AsArray as _, Array as _};
+
+    // Arrays with other fields are rejected by the conversion.
+    array
+        .as_struct_opt()
+        .and_then(|struct_array| {
+            struct_array
+                .columns()
+                .get(1)
+                .and_then(|column| column.as_any().downcast_ref::<T>())
+                .map(|time_zones| (struct_array, time_zones))
+        })
+        .and_then(|(struct_array, time_zones)| {
+            (0..struct_array.len())
+                .filter(|&index| struct_array.is_valid(index) && time_zones.is_valid(index))
+                .find_map(|index| invalid(time_zones, index).map(|time_zone| (index, time_zone)))
+        })
+        .map_or(Ok(()), |(index, time_zone)| {
+            Err(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index,
+                message: format!("invalid time zone {time_zone}"),
+            })
+        })
+}
+
+impl ArrayType<DateTime<FixedOffset>> for DateTime<FixedOffset> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<DateTime<FixedOffset>> for Option<DateTime<FixedOffset>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<DateTime<FixedOffset>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+/// Date times with a fixed offset are stored as structs of timestamps and
+/// offsets, because the offset can differ per item. Engines that read these
+/// arrays don't recognize them as timestamps, so when all items share an
+/// offset, use a [`Timestamp`] with an [`Offset`] time zone instead, which
+/// stores the offset in the Arrow data type.
+impl LogicalArrayType<DateTime<FixedOffset>> for DateTime<FixedOffset> {
+    // The timestamp and the offset in seconds east of UTC.
+    type ArrayType = (Timestamp<Microsecond>, i32);
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        with_field_names(array_data_type, "offset")
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        validate_time_zones(array, |offsets: &arrow_array::Int32Array, index| {
+            let offset = offsets.value(index);
+            FixedOffset::east_opt(offset)
+                .is_none()
+                .then(|| offset.to_string())
+        })
+    }
+
+    fn from_array_type((timestamp, offset): Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        timestamp
+            .into_inner()
+            .with_timezone(&FixedOffset::east_opt(offset).expect("valid offset"))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        (
+            Timestamp::new(self.with_timezone(&Utc)),
+            self.offset().local_minus_utc(),
+        )
+    }
+}
+
+/// An array for [`DateTime<FixedOffset>`] items, stored as structs of
+/// [`Microsecond`] timestamps and offsets.
+pub type DateTimeFixedOffsetArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<
+        DateTime<FixedOffset>,
+        NULLABLE,
+        Buffer,
+        crate::offset::NA,
+        crate::array::union::NA,
+    >;
+
+#[cfg(feature = "chrono-tz")]
+impl ArrayType<DateTime<chrono_tz::Tz>> for DateTime<chrono_tz::Tz> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+#[cfg(feature = "chrono-tz")]
+impl ArrayType<DateTime<chrono_tz::Tz>> for Option<DateTime<chrono_tz::Tz>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<DateTime<chrono_tz::Tz>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+/// Date times with a `chrono-tz` time zone are stored as structs of
+/// timestamps and time zone names, because the time zone can differ per item.
+/// Engines that read these arrays don't recognize them as timestamps, so when
+/// all items share a time zone, use a [`Timestamp`] with a time zone declared
+/// with the [`chrono_tz_time_zone`](crate::chrono_tz_time_zone) macro instead,
+/// which stores the time zone in the Arrow data type.
+#[cfg(feature = "chrono-tz")]
+impl LogicalArrayType<DateTime<chrono_tz::Tz>> for DateTime<chrono_tz::Tz> {
+    // The timestamp and the name of the time zone.
+    type ArrayType = (Timestamp<Microsecond>, String);
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        with_field_names(array_data_type, "time_zone")
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        validate_time_zones(array, |names: &arrow_array::StringArray, index| {
+            let name = names.value(index);
+            name.parse::<chrono_tz::Tz>()
+                .is_err()
+                .then(|| name.to_owned())
+        })
+    }
+
+    fn from_array_type((timestamp, time_zone): Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        timestamp
+            .into_inner()
+            .with_timezone(&time_zone.parse::<chrono_tz::Tz>().expect("valid time zone"))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        (
+            Timestamp::new(self.with_timezone(&Utc)),
+            self.timezone().name().to_owned(),
+        )
+    }
+}
+
+/// An array for [`DateTime<chrono_tz::Tz>`] items, stored as structs of
+/// [`Microsecond`] timestamps and time zone names.
+#[cfg(feature = "chrono-tz")]
+pub type DateTimeTzArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<
+        DateTime<chrono_tz::Tz>,
+        NULLABLE,
+        Buffer,
+        crate::offset::NA,
+        crate::array::union::NA,
+    >;
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Length;
+    use crate::{Index, Length};
 
     #[test]
     fn round_trip_naivedate() {
         for value in [
             NaiveDate::from_yo_opt(2024, 7)
                 .expect("out of range")
-                .num_days_from_ce(),
+                .into_array_type(),
             NaiveDate::from_yo_opt(2020, 6)
                 .expect("out of range")
-                .num_days_from_ce(),
+                .into_array_type(),
+            NaiveDate::from_yo_opt(1900, 1)
+                .expect("out of range")
+                .into_array_type(),
         ] {
             assert_eq!(NaiveDate::from_array_type(value).into_array_type(), value);
         }
+        assert_eq!(
+            NaiveDate::from_array_type(0),
+            DateTime::<Utc>::UNIX_EPOCH.date_naive()
+        );
     }
 
     #[test]
@@ -224,4 +746,529 @@ mod tests {
         let output_nullable = array_nullable.into_iter().collect::<Vec<_>>();
         assert_eq!(input_nullable, output_nullable.as_slice());
     }
+
+    #[test]
+    fn index() {
+        let input = [
+            DateTime::<Utc>::UNIX_EPOCH,
+            DateTime::<Utc>::from_timestamp_nanos(1234),
+        ];
+        let array = input.into_iter().collect::<DateTimeArray>();
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.index_checked(1), input[1]);
+        assert_eq!(array.index(2), None);
+
+        let input_nullable = [Some(DateTime::<Utc>::UNIX_EPOCH), None];
+        let array_nullable = input_nullable.into_iter().collect::<DateTimeArray<true>>();
+        assert_eq!(array_nullable.index_checked(0), input_nullable[0]);
+        assert_eq!(array_nullable.index_checked(1), None);
+    }
+
+    #[test]
+    fn timestamp() {
+        let historical = NaiveDate::from_ymd_opt(1600, 2, 29)
+            .expect("valid date")
+            .and_hms_micro_opt(12, 34, 56, 789)
+            .expect("valid time")
+            .and_utc();
+        let input = [
+            Timestamp::<Microsecond>::new(historical),
+            Timestamp::new(DateTime::<Utc>::UNIX_EPOCH),
+        ];
+        let array = input.into_iter().collect::<TimestampArray<Microsecond>>();
+        assert_eq!(array.0.as_ref(), [-11_670_953_103_999_211, 0]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        assert_eq!(
+            Timestamp::<Second>::new(historical).into_array_type(),
+            -11_670_953_104
+        );
+        assert_eq!(
+            Timestamp::<Millisecond>::new(historical).into_array_type(),
+            -11_670_953_104_000
+        );
+        // The default unit can represent all dates.
+        assert_eq!(
+            [Timestamp::new(historical)]
+                .into_iter()
+                .collect::<TimestampArray>()
+                .0
+                .as_ref(),
+            [-11_670_953_103_999_211]
+        );
+    }
+
+    #[test]
+    fn timestamp_offset() {
+        let date_time =
+            DateTime::parse_from_rfc3339("1600-01-01T01:00:00+01:00").expect("valid date time");
+        let input = [
+            Some(Timestamp::<Second, Offset<3600>>::new(date_time)),
+            None,
+        ];
+        let array = input
+            .into_iter()
+            .collect::<TimestampArray<Second, Offset<3600>, true>>();
+        let output = array.into_iter().collect::<Vec<_>>();
+        assert_eq!(output, input);
+        assert_eq!(
+            output[0].expect("value").offset(),
+            &FixedOffset::east_opt(3600).expect("valid offset")
+        );
+        assert_eq!(Offset::<3600>::name(), "+01:00");
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn months_negative() {
+        let array: std::sync::Arc<dyn arrow_array::Array> =
+            std::sync::Arc::new(arrow_array::IntervalYearMonthArray::from(vec![
+                Some(12),
+                None,
+                Some(-1),
+            ]));
+        assert_eq!(
+            MonthsArray::<true>::try_from(array).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 2,
+                message: "negative interval of -1 months".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn utc_time_zones() {
+        use arrow_schema::{DataType, TimeUnit};
+
+        let array: std::sync::Arc<dyn arrow_array::Array> = [DateTime::<Utc>::UNIX_EPOCH]
+            .into_iter()
+            .collect::<DateTimeArray>()
+            .into();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        for time_zone in ["UTC", "+00:00", "Z", "Etc/UTC"] {
+            let utc: std::sync::Arc<dyn arrow_array::Array> = std::sync::Arc::new(
+                arrow_array::TimestampNanosecondArray::from(vec![1]).with_timezone(time_zone),
+            );
+            assert_eq!(
+                DateTimeArray::<false>::try_from(utc)
+                    .expect("utc time zone")
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+                [DateTime::<Utc>::from_timestamp_nanos(1)]
+            );
+        }
+        let amsterdam: std::sync::Arc<dyn arrow_array::Array> = std::sync::Arc::new(
+            arrow_array::TimestampNanosecondArray::from(vec![1]).with_timezone("Europe/Amsterdam"),
+        );
+        assert!(DateTimeArray::<false>::try_from(amsterdam).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn naive_out_of_range() {
+        let dates: std::sync::Arc<dyn arrow_array::Array> =
+            std::sync::Arc::new(arrow_array::Date32Array::from(vec![
+                Some(0),
+                None,
+                Some(i32::MAX),
+            ]));
+        assert_eq!(
+            NaiveDateArray::<true>::try_from(dates).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 2,
+                message: format!("date {} out of range", i32::MAX),
+            })
+        );
+
+        let times: std::sync::Arc<dyn arrow_array::Array> =
+            std::sync::Arc::new(arrow_array::Time64NanosecondArray::from(vec![0, -1]));
+        assert_eq!(
+            NaiveTimeArray::<false>::try_from(times).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: "time -1 out of range".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-tz")]
+    fn timestamp_chrono_tz() {
+        use chrono::TimeZone as _;
+
+        crate::chrono_tz_time_zone!(Amsterdam = chrono_tz::Europe::Amsterdam);
+
+        let date_time = chrono_tz::Europe::Amsterdam
+            .with_ymd_and_hms(2024, 7, 1, 12, 0, 0)
+            .single()
+            .expect("valid date time");
+        let input = [Timestamp::<Second, Amsterdam>::new(date_time)];
+        let array = input
+            .into_iter()
+            .collect::<TimestampArray<Second, Amsterdam>>();
+        assert_eq!(array.0.as_ref(), [1_719_828_000]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+        assert_eq!(Amsterdam::name(), "Europe/Amsterdam");
+
+        #[cfg(feature = "arrow-rs")]
+        assert_eq!(
+            <TimestampArray<Second, Amsterdam> as crate::arrow::Array>::data_type(),
+            arrow_schema::DataType::Timestamp(
+                arrow_schema::TimeUnit::Second,
+                Some("Europe/Amsterdam".into())
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_types() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types, Array as _};
+        use arrow_schema::{DataType, TimeUnit};
+        use chrono::{DateTime, NaiveDate, NaiveTime, TimeDelta, Utc};
+
+        use crate::Error;
+
+        let date_time = DateTime::<Utc>::from_timestamp_nanos(1234);
+        let date_time_array: Arc<dyn arrow_array::Array> = [Some(date_time), None]
+            .into_iter()
+            .collect::<DateTimeArray<true>>()
+            .into();
+        assert_eq!(
+            date_time_array.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            date_time_array
+                .as_primitive::<types::TimestampNanosecondType>()
+                .value(0),
+            1234
+        );
+        assert_eq!(
+            DateTimeArray::<true>::try_from(date_time_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(date_time), None]
+        );
+
+        let date = NaiveDate::from_ymd_opt(1970, 1, 2).expect("valid date");
+        let date_array: Arc<dyn arrow_array::Array> =
+            [date].into_iter().collect::<NaiveDateArray>().into();
+        assert_eq!(date_array.data_type(), &DataType::Date32);
+        assert_eq!(date_array.as_primitive::<types::Date32Type>().value(0), 1);
+
+        let time = NaiveTime::from_hms_opt(0, 0, 1).expect("valid time");
+        let time_array: Arc<dyn arrow_array::Array> =
+            [time].into_iter().collect::<NaiveTimeArray>().into();
+        assert_eq!(
+            time_array.data_type(),
+            &DataType::Time64(TimeUnit::Nanosecond)
+        );
+        assert_eq!(
+            NaiveTimeArray::<false>::try_from(time_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [time]
+        );
+
+        let duration_array: Arc<dyn arrow_array::Array> = [TimeDelta::seconds(1)]
+            .into_iter()
+            .collect::<TimeDeltaArray>()
+            .into();
+        assert_eq!(
+            duration_array.data_type(),
+            &DataType::Duration(TimeUnit::Nanosecond)
+        );
+
+        let int64: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::Int64Array::from(vec![1]));
+        assert_eq!(
+            TimeDeltaArray::<false>::try_from(int64).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::Duration(TimeUnit::Nanosecond),
+                actual: DataType::Int64,
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn timestamp_data_type() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types, Array as _};
+        use arrow_schema::{DataType, TimeUnit};
+        use chrono::{DateTime, Utc};
+
+        use crate::Error;
+
+        let date_time =
+            DateTime::parse_from_rfc3339("1600-01-01T00:00:00-02:00").expect("valid date time");
+        let input = [
+            Some(Timestamp::<Second, Offset<-7200>>::new(date_time)),
+            None,
+        ];
+        let array: Arc<dyn arrow_array::Array> = input
+            .into_iter()
+            .collect::<TimestampArray<Second, Offset<-7200>, true>>()
+            .into();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Timestamp(TimeUnit::Second, Some("-02:00".into()))
+        );
+        assert_eq!(
+            array.as_primitive::<types::TimestampSecondType>().value(0),
+            date_time.timestamp()
+        );
+        assert_eq!(
+            TimestampArray::<Second, Offset<-7200>, true>::try_from(Arc::clone(&array))
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+        assert_eq!(
+            TimestampArray::<Second, Utc, true>::try_from(Arc::clone(&array)).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::Timestamp(TimeUnit::Second, Some("UTC".into())),
+                actual: array.data_type().clone(),
+            })
+        );
+        assert_eq!(
+            TimestampArray::<Microsecond, Offset<-7200>, true>::try_from(Arc::clone(&array)).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::Timestamp(TimeUnit::Microsecond, Some("-02:00".into())),
+                actual: array.data_type().clone(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn timestamp_out_of_range() {
+        use std::sync::Arc;
+
+        use crate::Error;
+
+        let array: Arc<dyn arrow_array::Array> = Arc::new(
+            arrow_array::TimestampSecondArray::from(vec![0, i64::MAX]).with_timezone("+00:00"),
+        );
+        assert_eq!(
+            TimestampArray::<Second>::try_from(array).err(),
+            Some(Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: format!("timestamp {} out of range", i64::MAX),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn fixed_offset() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types, Array as _};
+        use arrow_schema::{DataType, Field, Fields, TimeUnit};
+        use chrono::{DateTime, FixedOffset};
+
+        use crate::Error;
+
+        let input = [
+            Some(DateTime::parse_from_rfc3339("1600-01-01T00:00:00-02:00").expect("valid")),
+            None,
+            Some(DateTime::parse_from_rfc3339("2024-06-01T12:00:00.123456+05:30").expect("valid")),
+        ];
+        let array: Arc<dyn arrow_array::Array> = input
+            .into_iter()
+            .collect::<DateTimeFixedOffsetArray<true>>()
+            .into();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Struct(Fields::from(vec![
+                Field::new(
+                    "timestamp",
+                    DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                    false
+                ),
+                Field::new("offset", DataType::Int32, false),
+            ]))
+        );
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(
+            array
+                .as_struct()
+                .column(1)
+                .as_primitive::<types::Int32Type>()
+                .value(2),
+            19_800
+        );
+        let output = DateTimeFixedOffsetArray::<true>::try_from(Arc::clone(&array))
+            .expect("conversion")
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(output, input);
+        assert_eq!(
+            output[0].map(|date_time| *date_time.offset()),
+            FixedOffset::west_opt(7200)
+        );
+
+        let invalid: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::StructArray::new(
+            array.as_struct().fields().clone(),
+            vec![
+                Arc::new(
+                    arrow_array::TimestampMicrosecondArray::from(vec![0]).with_timezone("UTC"),
+                ),
+                Arc::new(arrow_array::Int32Array::from(vec![86_400])),
+            ],
+            None,
+        ));
+        assert_eq!(
+            DateTimeFixedOffsetArray::<false>::try_from(invalid).err(),
+            Some(Error::InvalidValue {
+                path: Vec::new(),
+                index: 0,
+                message: "invalid time zone 86400".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "arrow-rs", feature = "chrono-tz"))]
+    fn date_time_tz() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, Array as _};
+        use arrow_schema::DataType;
+        use chrono::{TimeZone as _, Utc};
+        use chrono_tz::{America::New_York, Europe::Amsterdam};
+
+        use crate::Error;
+
+        let input = [
+            Amsterdam
+                .with_ymd_and_hms(1600, 7, 1, 12, 0, 0)
+                .single()
+                .expect("valid"),
+            Utc.timestamp_opt(0, 0)
+                .single()
+                .expect("valid")
+                .with_timezone(&New_York),
+        ];
+        let array: Arc<dyn arrow_array::Array> =
+            input.into_iter().collect::<DateTimeTzArray>().into();
+        assert!(matches!(array.data_type(), DataType::Struct(_)));
+        assert_eq!(array.as_struct().column_names(), ["timestamp", "time_zone"]);
+        assert_eq!(
+            array.as_struct().column(1).as_string::<i32>().value(0),
+            "Europe/Amsterdam"
+        );
+        let output = DateTimeTzArray::<false>::try_from(Arc::clone(&array))
+            .expect("conversion")
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(output, input);
+        assert_eq!(output[1].timezone(), New_York);
+
+        let invalid: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::StructArray::new(
+            array.as_struct().fields().clone(),
+            vec![
+                Arc::clone(array.as_struct().column(0)),
+                Arc::new(arrow_array::StringArray::from(vec![
+                    "Europe/Amsterdam",
+                    "Mars/Base",
+                ])),
+            ],
+            None,
+        ));
+        assert_eq!(
+            DateTimeTzArray::<false>::try_from(invalid).err(),
+            Some(Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: "invalid time zone Mars/Base".to_owned(),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "arrow-rs", feature = "derive"))]
+    fn struct_field() {
+        use arrow_schema::{DataType, TimeUnit};
+        use chrono::{DateTime, Utc};
+
+        use crate::array::StructArray;
+
+        #[derive(crate::ArrayType, Clone, Debug, PartialEq)]
+        struct Event {
+            at: DateTime<Utc>,
+            until: Option<DateTime<Utc>>,
+        }
+
+        let input = [
+            Event {
+                at: DateTime::<Utc>::UNIX_EPOCH,
+                until: None,
+            },
+            Event {
+                at: DateTime::<Utc>::from_timestamp_nanos(1),
+                until: Some(DateTime::<Utc>::from_timestamp_nanos(2)),
+            },
+        ];
+        let record_batch = arrow_array::RecordBatch::from(
+            input.clone().into_iter().collect::<StructArray<Event>>(),
+        );
+        let schema = record_batch.schema();
+        assert_eq!(
+            schema.field(0).data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        assert!(!schema.field(0).is_nullable());
+        assert!(schema.field(1).is_nullable());
+        assert_eq!(
+            StructArray::<Event>::try_from(record_batch)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn months() {
+        use std::sync::Arc;
+
+        use arrow_array::Array as _;
+        use arrow_schema::{DataType, IntervalUnit};
+        use chrono::Months;
+
+        let input = [Some(Months::new(14)), None];
+        let array: Arc<dyn arrow_array::Array> =
+            input.into_iter().collect::<MonthsArray<true>>().into();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Interval(IntervalUnit::YearMonth)
+        );
+        assert_eq!(
+            MonthsArray::<true>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
 }
@@ -0,0 +1,92 @@
+use std::borrow::Cow;
+
+use crate::{
+    array::{ArrayType, UnionType, VariableSizeBinary},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+impl<'a> ArrayType<Cow<'a, str>> for Cow<'a, str> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<'a> ArrayType<Cow<'a, str>> for Option<Cow<'a, str>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Cow<'a, str>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<'a> LogicalArrayType<Cow<'a, str>> for Cow<'a, str> {
+    type ArrayType = String;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Cow::Owned(item)
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_owned()
+    }
+}
+
+/// An array for [`Cow<str>`] items.
+///
+/// Items are always owned when read from the array.
+#[allow(unused)]
+pub type CowStrArray<'a, const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Cow<'a, str>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl<'a> ArrayType<Cow<'a, [u8]>> for Cow<'a, [u8]> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<'a> ArrayType<Cow<'a, [u8]>> for Option<Cow<'a, [u8]>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Cow<'a, [u8]>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<'a> LogicalArrayType<Cow<'a, [u8]>> for Cow<'a, [u8]> {
+    type ArrayType = VariableSizeBinary;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Cow::Owned(item.into())
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_owned().into()
+    }
+}
+
+/// An array for [`Cow<[u8]>`] items.
+///
+/// Items are always owned when read from the array.
+#[allow(unused)]
+pub type CowBytesArray<'a, const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Cow<'a, [u8]>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+
+    #[test]
+    fn str() {
+        let owned = "bc".to_owned();
+        let input = [Some(Cow::Borrowed("a")), None, Some(Cow::Owned(owned))];
+        let array = input.clone().into_iter().collect::<CowStrArray<true>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index_checked(0), Some(Cow::Borrowed("a")));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn bytes() {
+        let input = [Cow::Borrowed([1_u8, 2].as_slice()), Cow::Owned(vec![3])];
+        let array = input.clone().into_iter().collect::<CowBytesArray>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.index_checked(1), Cow::Borrowed([3].as_slice()));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+}
@@ -0,0 +1,572 @@
+use crate::{
+    array::{ArrayType, FixedSizeBinary, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// Validates that the non-null values of the decimal array `array` fit in
+/// `precision` digits.
+#[cfg(feature = "arrow-rs")]
+fn validate<T: arrow_array::types::DecimalType>(
+    array: &dyn arrow_array::Array,
+    precision: u8,
+    in_range: impl Fn(T::Native) -> bool,
+) -> Result<(), crate::Error>
+where
+    T::Native: std::fmt::Display,
+{
+    super::validate_primitive::<T>(array, |decimal| {
+        (!in_range(decimal)).then(|| format!("value {decimal} exceeds precision {precision}"))
+    })
+}
+
+/// The error returned when a decimal value can't be converted without loss
+/// of digits.
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryFromDecimalError(());
+
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+impl std::fmt::Display for TryFromDecimalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("decimal value out of range")
+    }
+}
+
+#[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+impl std::error::Error for TryFromDecimalError {}
+
+/// Returns the unscaled `value` with scale `from` rescaled to scale `to`, or
+/// `None` if that overflows or drops non-zero digits.
+#[cfg(feature = "rust_decimal")]
+fn rescale(value: i128, from: i64, to: i64) -> Option<i128> {
+    let factor = 10_i128.checked_pow(u32::try_from(to.abs_diff(from)).ok()?)?;
+    if to >= from {
+        value.checked_mul(factor)
+    } else {
+        (value % factor == 0).then(|| value / factor)
+    }
+}
+
+/// A decimal number with `PRECISION` digits, of which `SCALE` digits are
+/// after the decimal point, stored as an unscaled 128-bit integer.
+///
+/// Maps to the Arrow `Decimal128(PRECISION, SCALE)` data type. The precision
+/// must be in `1..=38`, and the scale can't exceed the precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<const PRECISION: u8, const SCALE: i8>(i128);
+
+impl<const PRECISION: u8, const SCALE: i8> Decimal<PRECISION, SCALE> {
+    /// The maximum unscaled value, i.e. `10^PRECISION - 1`.
+    const MAX: i128 = {
+        assert!(
+            PRECISION >= 1 && PRECISION <= 38,
+            "precision must be in 1..=38"
+        );
+        assert!(
+            SCALE < 0 || SCALE.unsigned_abs() <= PRECISION,
+            "scale exceeds precision"
+        );
+        let mut max = 1_i128;
+        let mut digits = 0;
+        while digits < PRECISION {
+            max *= 10;
+            digits += 1;
+        }
+        max - 1
+    };
+
+    /// Returns a decimal for the given unscaled value, or `None` if the value
+    /// has more than `PRECISION` digits.
+    #[must_use]
+    pub fn new(value: i128) -> Option<Self> {
+        (-Self::MAX..=Self::MAX)
+            .contains(&value)
+            .then_some(Self(value))
+    }
+
+    /// Returns the unscaled value.
+    #[must_use]
+    pub fn value(self) -> i128 {
+        self.0
+    }
+}
+
+impl<const PRECISION: u8, const SCALE: i8> ArrayType<Decimal<PRECISION, SCALE>>
+    for Decimal<PRECISION, SCALE>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<const PRECISION: u8, const SCALE: i8> ArrayType<Decimal<PRECISION, SCALE>>
+    for Option<Decimal<PRECISION, SCALE>>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Decimal<PRECISION, SCALE>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<const PRECISION: u8, const SCALE: i8> LogicalArrayType<Decimal<PRECISION, SCALE>>
+    for Decimal<PRECISION, SCALE>
+{
+    type ArrayType = FixedSizeBinary<16>;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Decimal128(PRECISION, SCALE)
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        validate::<arrow_array::types::Decimal128Type>(array, PRECISION, |value| {
+            Self::new(value).is_some()
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        Self(i128::from_le_bytes(item.into()))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.0.to_le_bytes().into()
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<const PRECISION: u8, const SCALE: i8> TryFrom<rust_decimal::Decimal>
+    for Decimal<PRECISION, SCALE>
+{
+    type Error = TryFromDecimalError;
+
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        rescale(value.mantissa(), i64::from(value.scale()), i64::from(SCALE))
+            .and_then(Self::new)
+            .ok_or(TryFromDecimalError(()))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl<const PRECISION: u8, const SCALE: i8> TryFrom<Decimal<PRECISION, SCALE>>
+    for rust_decimal::Decimal
+{
+    type Error = TryFromDecimalError;
+
+    fn try_from(value: Decimal<PRECISION, SCALE>) -> Result<Self, Self::Error> {
+        let scale = SCALE.clamp(0, 28);
+        rescale(value.0, i64::from(SCALE), i64::from(scale))
+            .and_then(|mantissa| {
+                Self::try_from_i128_with_scale(mantissa, scale.unsigned_abs().into()).ok()
+            })
+            .ok_or(TryFromDecimalError(()))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<const PRECISION: u8, const SCALE: i8> TryFrom<bigdecimal::BigDecimal>
+    for Decimal<PRECISION, SCALE>
+{
+    type Error = TryFromDecimalError;
+
+    fn try_from(value: bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        let scaled = value.with_scale(i64::from(SCALE));
+        if scaled == value {
+            i128::try_from(scaled.into_bigint_and_exponent().0)
+                .ok()
+                .and_then(Self::new)
+                .ok_or(TryFromDecimalError(()))
+        } else {
+            Err(TryFromDecimalError(()))
+        }
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<const PRECISION: u8, const SCALE: i8> From<Decimal<PRECISION, SCALE>>
+    for bigdecimal::BigDecimal
+{
+    fn from(value: Decimal<PRECISION, SCALE>) -> Self {
+        Self::new(value.0.into(), i64::from(SCALE))
+    }
+}
+
+/// An array for [`Decimal`] items.
+#[allow(unused)]
+pub type DecimalArray<
+    const PRECISION: u8,
+    const SCALE: i8,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+> = LogicalArray<
+    Decimal<PRECISION, SCALE>,
+    NULLABLE,
+    Buffer,
+    crate::offset::NA,
+    crate::array::union::NA,
+>;
+
+/// A decimal number with `PRECISION` digits, of which `SCALE` digits are
+/// after the decimal point, stored as an unscaled 256-bit integer.
+///
+/// Maps to the Arrow `Decimal256(PRECISION, SCALE)` data type. The precision
+/// must be in `1..=76`, and the scale can't exceed the precision.
+#[cfg(feature = "arrow-rs")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal256<const PRECISION: u8, const SCALE: i8>(arrow_buffer::i256);
+
+#[cfg(feature = "arrow-rs")]
+impl<const PRECISION: u8, const SCALE: i8> Decimal256<PRECISION, SCALE> {
+    /// The precision, checked to be valid.
+    const PRECISION: u8 = {
+        assert!(
+            PRECISION >= 1 && PRECISION <= 76,
+            "precision must be in 1..=76"
+        );
+        assert!(
+            SCALE < 0 || SCALE.unsigned_abs() <= PRECISION,
+            "scale exceeds precision"
+        );
+        PRECISION
+    };
+
+    /// Returns a decimal for the given unscaled value, or `None` if the value
+    /// has more than `PRECISION` digits.
+    #[must_use]
+    pub fn new(value: arrow_buffer::i256) -> Option<Self> {
+        let max = arrow_buffer::i256::from_i128(10)
+            .wrapping_pow(u32::from(Self::PRECISION))
+            .wrapping_sub(arrow_buffer::i256::ONE);
+        (max.wrapping_neg()..=max)
+            .contains(&value)
+            .then_some(Self(value))
+    }
+
+    /// Returns the unscaled value.
+    #[must_use]
+    pub fn value(self) -> arrow_buffer::i256 {
+        self.0
+    }
+}
+
+#[cfg(feature = "arrow-rs")]
+impl<const PRECISION: u8, const SCALE: i8> From<Decimal<PRECISION, SCALE>>
+    for Decimal256<PRECISION, SCALE>
+{
+    fn from(value: Decimal<PRECISION, SCALE>) -> Self {
+        Self(arrow_buffer::i256::from_i128(value.0))
+    }
+}
+
+#[cfg(feature = "arrow-rs")]
+impl<const PRECISION: u8, const SCALE: i8> ArrayType<Decimal256<PRECISION, SCALE>>
+    for Decimal256<PRECISION, SCALE>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+#[cfg(feature = "arrow-rs")]
+impl<const PRECISION: u8, const SCALE: i8> ArrayType<Decimal256<PRECISION, SCALE>>
+    for Option<Decimal256<PRECISION, SCALE>>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Decimal256<PRECISION, SCALE>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+#[cfg(feature = "arrow-rs")]
+impl<const PRECISION: u8, const SCALE: i8> LogicalArrayType<Decimal256<PRECISION, SCALE>>
+    for Decimal256<PRECISION, SCALE>
+{
+    type ArrayType = FixedSizeBinary<32>;
+
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Decimal256(PRECISION, SCALE)
+    }
+
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        validate::<arrow_array::types::Decimal256Type>(array, PRECISION, |value| {
+            Self::new(value).is_some()
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        Self(arrow_buffer::i256::from_le_bytes(item.into()))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.0.to_le_bytes().into()
+    }
+}
+
+#[cfg(all(feature = "arrow-rs", feature = "bigdecimal"))]
+impl<const PRECISION: u8, const SCALE: i8> TryFrom<bigdecimal::BigDecimal>
+    for Decimal256<PRECISION, SCALE>
+{
+    type Error = TryFromDecimalError;
+
+    fn try_from(value: bigdecimal::BigDecimal) -> Result<Self, Self::Error> {
+        let scaled = value.with_scale(i64::from(SCALE));
+        if scaled != value {
+            return Err(TryFromDecimalError(()));
+        }
+        let bytes = scaled.into_bigint_and_exponent().0.to_signed_bytes_le();
+        if bytes.len() > 32 {
+            return Err(TryFromDecimalError(()));
+        }
+        // Sign-extend the two's complement bytes.
+        let mut le_bytes = if bytes.last().is_some_and(|byte| byte & 0x80 != 0) {
+            [u8::MAX; 32]
+        } else {
+            [0; 32]
+        };
+        le_bytes[..bytes.len()].copy_from_slice(&bytes);
+        Self::new(arrow_buffer::i256::from_le_bytes(le_bytes)).ok_or(TryFromDecimalError(()))
+    }
+}
+
+#[cfg(all(feature = "arrow-rs", feature = "bigdecimal"))]
+impl<const PRECISION: u8, const SCALE: i8> From<Decimal256<PRECISION, SCALE>>
+    for bigdecimal::BigDecimal
+{
+    fn from(value: Decimal256<PRECISION, SCALE>) -> Self {
+        Self::new(
+            bigdecimal::num_bigint::BigInt::from_signed_bytes_le(&value.0.to_le_bytes()),
+            i64::from(SCALE),
+        )
+    }
+}
+
+/// An array for [`Decimal256`] items.
+#[cfg(feature = "arrow-rs")]
+#[allow(unused)]
+pub type Decimal256Array<
+    const PRECISION: u8,
+    const SCALE: i8,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+> = LogicalArray<
+    Decimal256<PRECISION, SCALE>,
+    NULLABLE,
+    Buffer,
+    crate::offset::NA,
+    crate::array::union::NA,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+
+    #[test]
+    fn new() {
+        assert_eq!(Decimal::<3, 1>::new(999).map(Decimal::value), Some(999));
+        assert_eq!(Decimal::<3, 1>::new(-999).map(Decimal::value), Some(-999));
+        assert_eq!(Decimal::<3, 1>::new(1000), None);
+        assert_eq!(Decimal::<3, 1>::new(-1000), None);
+        assert!(Decimal::<38, 0>::new(i128::MAX).is_none());
+        assert!(Decimal::<38, 0>::new(10_i128.pow(38) - 1).is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn new_256() {
+        use arrow_buffer::i256;
+
+        assert!(Decimal256::<76, 0>::new(i256::MAX).is_none());
+        assert!(Decimal256::<39, 2>::new(i256::from_i128(i128::MAX)).is_some());
+        assert!(Decimal256::<2, 0>::new(i256::from_i128(-100)).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "rust_decimal")]
+    fn rust_decimal() {
+        let value = rust_decimal::Decimal::new(-12_345, 2);
+        assert_eq!(
+            Decimal::<10, 3>::try_from(value).map(Decimal::value),
+            Ok(-123_450)
+        );
+        assert_eq!(
+            Decimal::<10, 1>::try_from(value),
+            Err(TryFromDecimalError(()))
+        );
+        assert_eq!(
+            Decimal::<4, 2>::try_from(value),
+            Err(TryFromDecimalError(()))
+        );
+        assert_eq!(
+            Decimal::<10, -2>::try_from(rust_decimal::Decimal::new(1_200, 0)).map(Decimal::value),
+            Ok(12)
+        );
+
+        let decimal = Decimal::<10, 3>::new(-123_450).expect("in range");
+        assert_eq!(rust_decimal::Decimal::try_from(decimal), Ok(value));
+        assert_eq!(
+            rust_decimal::Decimal::try_from(Decimal::<38, -2>::new(12).expect("in range")),
+            Ok(rust_decimal::Decimal::new(1_200, 0))
+        );
+        assert_eq!(
+            rust_decimal::Decimal::try_from(
+                Decimal::<38, 0>::new(10_i128.pow(37)).expect("in range")
+            ),
+            Err(TryFromDecimalError(()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bigdecimal")]
+    fn bigdecimal() {
+        use bigdecimal::BigDecimal;
+
+        let value = BigDecimal::new((-12_345).into(), 2);
+        assert_eq!(
+            Decimal::<10, 3>::try_from(value.clone()).map(Decimal::value),
+            Ok(-123_450)
+        );
+        assert_eq!(
+            Decimal::<10, 1>::try_from(value.clone()),
+            Err(TryFromDecimalError(()))
+        );
+        assert_eq!(
+            Decimal::<4, 2>::try_from(value.clone()),
+            Err(TryFromDecimalError(()))
+        );
+        assert_eq!(
+            Decimal::<38, 0>::try_from(BigDecimal::new(1.into(), -40)),
+            Err(TryFromDecimalError(()))
+        );
+        assert_eq!(
+            BigDecimal::from(Decimal::<10, 3>::new(-123_450).expect("in range")),
+            value
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "arrow-rs", feature = "bigdecimal"))]
+    fn bigdecimal_256() {
+        use arrow_buffer::i256;
+        use bigdecimal::BigDecimal;
+
+        let value = BigDecimal::new((-12_345).into(), 2);
+        assert_eq!(
+            Decimal256::<40, 3>::try_from(value.clone()).map(Decimal256::value),
+            Ok(i256::from_i128(-123_450))
+        );
+        assert_eq!(
+            Decimal256::<40, 1>::try_from(value.clone()),
+            Err(TryFromDecimalError(()))
+        );
+        assert_eq!(
+            Decimal256::<76, 0>::try_from(BigDecimal::new(1.into(), -76)),
+            Err(TryFromDecimalError(()))
+        );
+        let large = BigDecimal::new(1.into(), -75);
+        assert_eq!(
+            Decimal256::<76, 0>::try_from(large.clone()).map(BigDecimal::from),
+            Ok(large)
+        );
+        assert_eq!(
+            BigDecimal::from(Decimal256::<40, 2>::new(i256::from_i128(-12_345)).expect("in range")),
+            value
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        let array = [Decimal::<10, 2>::new(12_345), Decimal::new(-1)]
+            .into_iter()
+            .map(|decimal| decimal.expect("in range"))
+            .collect::<DecimalArray<10, 2>>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0.len(), 2);
+
+        let array_nullable = [Decimal::<5, -2>::new(1), None]
+            .into_iter()
+            .collect::<DecimalArray<5, -2, true>>();
+        assert_eq!(array_nullable.len(), 2);
+        assert_eq!(array_nullable.0.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let input = [Decimal::<38, 10>::new(-42), None, Decimal::new(1)];
+        let array = input.into_iter().collect::<DecimalArray<38, 10, true>>();
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_types() {
+        use std::sync::Arc;
+
+        use arrow_array::{
+            cast::AsArray as _,
+            types::{Decimal128Type, Decimal256Type},
+            Array as _,
+        };
+        use arrow_buffer::i256;
+        use arrow_schema::DataType;
+
+        use crate::Error;
+
+        let input = [Decimal::<10, 2>::new(-12_345), None];
+        let array: Arc<dyn arrow_array::Array> = input
+            .into_iter()
+            .collect::<DecimalArray<10, 2, true>>()
+            .into();
+        assert_eq!(array.data_type(), &DataType::Decimal128(10, 2));
+        assert_eq!(array.as_primitive::<Decimal128Type>().value(0), -12_345);
+        assert_eq!(
+            array.as_primitive::<Decimal128Type>().value_as_string(0),
+            "-123.45"
+        );
+        assert_eq!(
+            DecimalArray::<10, 2, true>::try_from(Arc::clone(&array))
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+        assert_eq!(
+            DecimalArray::<10, 3, true>::try_from(array).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::Decimal128(10, 3),
+                actual: DataType::Decimal128(10, 2),
+            })
+        );
+
+        let wide = [Decimal256::<76, 0>::new(i256::from_i128(i128::MIN)).expect("in range")];
+        let wide_array: Arc<dyn arrow_array::Array> =
+            wide.into_iter().collect::<Decimal256Array<76, 0>>().into();
+        assert_eq!(wide_array.data_type(), &DataType::Decimal256(76, 0));
+        assert_eq!(
+            wide_array.as_primitive::<Decimal256Type>().value(0),
+            i256::from_i128(i128::MIN)
+        );
+        assert_eq!(
+            Decimal256Array::<76, 0>::try_from(wide_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            wide
+        );
+
+        let out_of_range: Arc<dyn arrow_array::Array> = Arc::new(
+            arrow_array::Decimal128Array::from(vec![Some(1), None, Some(1_000)])
+                .with_precision_and_scale(3, 1)
+                .expect("valid precision and scale"),
+        );
+        assert_eq!(
+            DecimalArray::<3, 1, true>::try_from(out_of_range).err(),
+            Some(Error::InvalidValue {
+                path: Vec::new(),
+                index: 2,
+                message: "value 1000 exceeds precision 3".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+impl ArrayType<Duration> for Duration {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Duration> for Option<Duration> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Duration, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Duration> for Duration {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Duration(arrow_schema::TimeUnit::Nanosecond)
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::DurationNanosecondType>(array, |item| {
+            (item < 0).then(|| format!("negative duration {item}"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        Duration::from_nanos(u64::try_from(item).expect("out of range"))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        i64::try_from(self.as_nanos()).expect("out of range")
+    }
+}
+
+/// An array for [`Duration`] items, stored as nanoseconds.
+///
+/// # Panics
+///
+/// Collecting durations longer than [`i64::MAX`] nanoseconds into this array
+/// panics.
+pub type DurationArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Duration, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nanoseconds() {
+        let max = Duration::from_nanos(i64::MAX.unsigned_abs());
+        let input = [Some(Duration::new(1, 2)), None, Some(max)];
+        let array = input.into_iter().collect::<DurationArray<true>>();
+        assert_eq!(Duration::new(1, 2).into_array_type(), 1_000_000_002);
+        assert_eq!(max.into_array_type(), i64::MAX);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn out_of_range() {
+        let _ = [Duration::MAX].into_iter().collect::<DurationArray>();
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn negative() {
+        use std::sync::Arc;
+
+        use arrow_array::Array as _;
+        use arrow_schema::{DataType, TimeUnit};
+
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::DurationNanosecondArray::from(vec![
+                Some(1),
+                None,
+                Some(-1),
+            ]));
+        assert_eq!(array.data_type(), &DataType::Duration(TimeUnit::Nanosecond));
+        assert_eq!(
+            DurationArray::<true>::try_from(array).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 2,
+                message: "negative duration -1".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::hash::{BuildHasher, Hash};
+
+use indexmap::IndexSet;
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+impl<T: ArrayType<T> + Eq + Hash, S: BuildHasher + Default> ArrayType<IndexSet<T, S>>
+    for IndexSet<T, S>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T> + Eq + Hash, S: BuildHasher + Default> ArrayType<IndexSet<T, S>>
+    for Option<IndexSet<T, S>>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<IndexSet<T, S>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T> + Eq + Hash, S: BuildHasher + Default> LogicalArrayType<IndexSet<T, S>>
+    for IndexSet<T, S>
+{
+    type ArrayType = Vec<T>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_iter().collect()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_iter().collect()
+    }
+}
+
+/// An array for [`IndexSet`] items.
+///
+/// The items of the sets are stored in insertion order.
+#[allow(unused)]
+pub type IndexSetArray<
+    T,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+    OffsetItem = i32,
+> = LogicalArray<IndexSet<T>, NULLABLE, Buffer, OffsetItem, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insertion_order() {
+        let input = [IndexSet::from_iter([3, 1, 2]), IndexSet::default()];
+        assert_eq!(input[0].clone().into_array_type(), [3, 1, 2]);
+        let array = input.clone().into_iter().collect::<IndexSetArray<u32>>();
+        let output = array.into_iter().collect::<Vec<_>>();
+        assert_eq!(output, input);
+        // Sets compare equal regardless of order.
+        assert!(output[0].iter().eq(&[3, 1, 2]));
+    }
+
+    #[test]
+    fn duplicates() {
+        let set = IndexSet::<String>::from_array_type(vec![
+            "b".to_owned(),
+            "a".to_owned(),
+            "b".to_owned(),
+        ]);
+        assert!(set.iter().eq(["b", "a"]));
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_type() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types::UInt8Type, Array as _};
+        use arrow_schema::{DataType, Field};
+
+        let input = [None, Some(IndexSet::from_iter([3, 1, 2]))];
+        let array: Arc<dyn arrow_array::Array> = input
+            .clone()
+            .into_iter()
+            .collect::<IndexSetArray<u8, true>>()
+            .into();
+        assert_eq!(
+            array.data_type(),
+            &DataType::List(Arc::new(Field::new("item", DataType::UInt8, false)))
+        );
+        assert_eq!(
+            array
+                .as_list::<i32>()
+                .values()
+                .as_primitive::<UInt8Type>()
+                .values()
+                .as_ref(),
+            [3, 1, 2]
+        );
+        let output = IndexSetArray::<u8, true>::try_from(array)
+            .expect("conversion")
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(output, input);
+        assert!(output[1].as_ref().expect("a set").iter().eq(&[3, 1, 2]));
+    }
+}
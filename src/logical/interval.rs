@@ -0,0 +1,333 @@
+use crate::{
+    array::{ArrayType, FixedSizeBinary, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// An interval of a number of months.
+///
+/// Maps to the Arrow `Interval(YearMonth)` data type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalYearMonth {
+    /// The number of months.
+    pub months: i32,
+}
+
+impl ArrayType<IntervalYearMonth> for IntervalYearMonth {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<IntervalYearMonth> for Option<IntervalYearMonth> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<IntervalYearMonth, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<IntervalYearMonth> for IntervalYearMonth {
+    type ArrayType = i32;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Interval(arrow_schema::IntervalUnit::YearMonth)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Self { months: item }
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.months
+    }
+}
+
+/// An array for [`IntervalYearMonth`] items.
+pub type IntervalYearMonthArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<IntervalYearMonth, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+/// An interval of a number of days and milliseconds.
+///
+/// Maps to the Arrow `Interval(DayTime)` data type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalDayTime {
+    /// The number of days.
+    pub days: i32,
+    /// The number of milliseconds.
+    pub milliseconds: i32,
+}
+
+impl ArrayType<IntervalDayTime> for IntervalDayTime {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<IntervalDayTime> for Option<IntervalDayTime> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<IntervalDayTime, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<IntervalDayTime> for IntervalDayTime {
+    // The little-endian bytes of the days, followed by the milliseconds.
+    type ArrayType = FixedSizeBinary<8>;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Interval(arrow_schema::IntervalUnit::DayTime)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        let [d0, d1, d2, d3, m0, m1, m2, m3] = item.into();
+        Self {
+            days: i32::from_le_bytes([d0, d1, d2, d3]),
+            milliseconds: i32::from_le_bytes([m0, m1, m2, m3]),
+        }
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        let [d0, d1, d2, d3] = self.days.to_le_bytes();
+        let [m0, m1, m2, m3] = self.milliseconds.to_le_bytes();
+        [d0, d1, d2, d3, m0, m1, m2, m3].into()
+    }
+}
+
+/// An array for [`IntervalDayTime`] items.
+pub type IntervalDayTimeArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<IntervalDayTime, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+/// An interval of a number of months, days and nanoseconds.
+///
+/// Maps to the Arrow `Interval(MonthDayNano)` data type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalMonthDayNano {
+    /// The number of months.
+    pub months: i32,
+    /// The number of days.
+    pub days: i32,
+    /// The number of nanoseconds.
+    pub nanoseconds: i64,
+}
+
+impl ArrayType<IntervalMonthDayNano> for IntervalMonthDayNano {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<IntervalMonthDayNano> for Option<IntervalMonthDayNano> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<IntervalMonthDayNano, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<IntervalMonthDayNano> for IntervalMonthDayNano {
+    // The little-endian bytes of the months, followed by the days and the
+    // nanoseconds.
+    type ArrayType = FixedSizeBinary<16>;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Interval(arrow_schema::IntervalUnit::MonthDayNano)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        let [m0, m1, m2, m3, d0, d1, d2, d3, n0, n1, n2, n3, n4, n5, n6, n7] = item.into();
+        Self {
+            months: i32::from_le_bytes([m0, m1, m2, m3]),
+            days: i32::from_le_bytes([d0, d1, d2, d3]),
+            nanoseconds: i64::from_le_bytes([n0, n1, n2, n3, n4, n5, n6, n7]),
+        }
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        let [m0, m1, m2, m3] = self.months.to_le_bytes();
+        let [d0, d1, d2, d3] = self.days.to_le_bytes();
+        let [n0, n1, n2, n3, n4, n5, n6, n7] = self.nanoseconds.to_le_bytes();
+        [
+            m0, m1, m2, m3, d0, d1, d2, d3, n0, n1, n2, n3, n4, n5, n6, n7,
+        ]
+        .into()
+    }
+}
+
+/// An array for [`IntervalMonthDayNano`] items.
+pub type IntervalMonthDayNanoArray<
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+> = LogicalArray<
+    IntervalMonthDayNano,
+    NULLABLE,
+    Buffer,
+    crate::offset::NA,
+    crate::array::union::NA,
+>;
+
+#[cfg(feature = "arrow-rs")]
+impl From<IntervalDayTime> for arrow_buffer::IntervalDayTime {
+    fn from(value: IntervalDayTime) -> Self {
+        Self::new(value.days, value.milliseconds)
+    }
+}
+
+#[cfg(feature = "arrow-rs")]
+impl From<arrow_buffer::IntervalDayTime> for IntervalDayTime {
+    fn from(value: arrow_buffer::IntervalDayTime) -> Self {
+        Self {
+            days: value.days,
+            milliseconds: value.milliseconds,
+        }
+    }
+}
+
+#[cfg(feature = "arrow-rs")]
+impl From<IntervalMonthDayNano> for arrow_buffer::IntervalMonthDayNano {
+    fn from(value: IntervalMonthDayNano) -> Self {
+        Self::new(value.months, value.days, value.nanoseconds)
+    }
+}
+
+#[cfg(feature = "arrow-rs")]
+impl From<arrow_buffer::IntervalMonthDayNano> for IntervalMonthDayNano {
+    fn from(value: arrow_buffer::IntervalMonthDayNano) -> Self {
+        Self {
+            months: value.months,
+            days: value.days,
+            nanoseconds: value.nanoseconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for value in [
+            IntervalDayTime::default(),
+            IntervalDayTime {
+                days: -1,
+                milliseconds: i32::MAX,
+            },
+        ] {
+            assert_eq!(
+                IntervalDayTime::from_array_type(value.into_array_type()),
+                value
+            );
+        }
+        for value in [
+            IntervalMonthDayNano::default(),
+            IntervalMonthDayNano {
+                months: i32::MIN,
+                days: 3,
+                nanoseconds: -1,
+            },
+        ] {
+            assert_eq!(
+                IntervalMonthDayNano::from_array_type(value.into_array_type()),
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn layout() {
+        let day_time: [u8; 8] = IntervalDayTime {
+            days: 1,
+            milliseconds: -2,
+        }
+        .into_array_type()
+        .into();
+        assert_eq!(day_time, [1, 0, 0, 0, 0xFE, 0xFF, 0xFF, 0xFF]);
+
+        let month_day_nano: [u8; 16] = IntervalMonthDayNano {
+            months: -1,
+            days: 2,
+            nanoseconds: 3,
+        }
+        .into_array_type()
+        .into();
+        assert_eq!(
+            month_day_nano,
+            [0xFF, 0xFF, 0xFF, 0xFF, 2, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_types() {
+        use std::sync::Arc;
+
+        use arrow_array::{
+            cast::AsArray as _,
+            types::{IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType},
+            Array as _,
+        };
+        use arrow_schema::{DataType, IntervalUnit};
+
+        let year_month: Arc<dyn arrow_array::Array> = [IntervalYearMonth { months: -13 }]
+            .into_iter()
+            .collect::<IntervalYearMonthArray>()
+            .into();
+        assert_eq!(
+            year_month.data_type(),
+            &DataType::Interval(IntervalUnit::YearMonth)
+        );
+        assert_eq!(
+            year_month.as_primitive::<IntervalYearMonthType>().value(0),
+            -13
+        );
+
+        let day_time_input = [
+            Some(IntervalDayTime {
+                days: -2,
+                milliseconds: 1_500,
+            }),
+            None,
+        ];
+        let day_time: Arc<dyn arrow_array::Array> = day_time_input
+            .into_iter()
+            .collect::<IntervalDayTimeArray<true>>()
+            .into();
+        assert_eq!(
+            day_time.data_type(),
+            &DataType::Interval(IntervalUnit::DayTime)
+        );
+        assert_eq!(
+            day_time.as_primitive::<IntervalDayTimeType>().value(0),
+            arrow_buffer::IntervalDayTime::new(-2, 1_500)
+        );
+        assert_eq!(
+            IntervalDayTimeArray::<true>::try_from(day_time)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            day_time_input
+        );
+
+        let month_day_nano_input = [IntervalMonthDayNano {
+            months: 1,
+            days: -3,
+            nanoseconds: i64::MIN,
+        }];
+        let month_day_nano: Arc<dyn arrow_array::Array> = month_day_nano_input
+            .into_iter()
+            .collect::<IntervalMonthDayNanoArray>()
+            .into();
+        assert_eq!(
+            month_day_nano.data_type(),
+            &DataType::Interval(IntervalUnit::MonthDayNano)
+        );
+        assert_eq!(
+            month_day_nano
+                .as_primitive::<IntervalMonthDayNanoType>()
+                .value(0),
+            arrow_buffer::IntervalMonthDayNano::new(1, -3, i64::MIN)
+        );
+        assert_eq!(
+            IntervalMonthDayNanoArray::<false>::try_from(month_day_nano)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            month_day_nano_input
+        );
+    }
+}
@@ -0,0 +1,370 @@
+use std::marker::PhantomData;
+
+use jiff::{civil::Date, SignedDuration, Timestamp};
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{
+    time_unit::{Microsecond, TimeUnit},
+    LogicalArray, LogicalArrayType,
+};
+
+impl ArrayType<Timestamp> for Timestamp {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Timestamp> for Option<Timestamp> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Timestamp, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Timestamp> for Timestamp {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, Some("UTC".into()))
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Timestamp::from_nanosecond(i128::from(item)).expect("out of range")
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        i64::try_from(self.as_nanosecond()).expect("out of range")
+    }
+}
+
+/// An array for [`Timestamp`] items, stored as nanoseconds.
+///
+/// # Panics
+///
+/// Storing a timestamp outside the years 1677 to 2262 panics, use a
+/// [`UnitTimestamp`] with a unit of [`Microsecond`] or coarser for other
+/// timestamps.
+#[allow(unused)]
+pub type TimestampArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Timestamp, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+/// The number of nanoseconds in a second.
+const NANO_SECONDS: i128 = 1_000_000_000;
+
+/// Returns the timestamp `nanos` nanoseconds since the Unix epoch, or `None`
+/// when out of range.
+fn timestamp_from_nanos(nanos: i128) -> Option<Timestamp> {
+    // Timestamps with a number of seconds out of range are rejected by
+    // `Timestamp::new`, but not by `Timestamp::from_nanosecond`.
+    Timestamp::new(
+        i64::try_from(nanos / NANO_SECONDS).ok()?,
+        i32::try_from(nanos % NANO_SECONDS).ok()?,
+    )
+    .ok()
+}
+
+/// A [`Timestamp`] stored with the given [`TimeUnit`].
+///
+/// Unlike [`Timestamp`] items, that are stored as nanoseconds, this can
+/// represent all timestamps with a unit of [`Microsecond`] or coarser.
+///
+/// # Panics
+///
+/// With `Unit = Nanosecond`, storing a timestamp outside the years 1677 to
+/// 2262 panics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct UnitTimestamp<Unit: TimeUnit = Microsecond>(Timestamp, PhantomData<Unit>);
+
+impl<Unit: TimeUnit> UnitTimestamp<Unit> {
+    /// Returns a new timestamp with this unit for the given timestamp.
+    #[must_use]
+    pub fn new(timestamp: Timestamp) -> Self {
+        Self(timestamp, PhantomData)
+    }
+
+    /// Returns the inner timestamp.
+    #[must_use]
+    pub fn into_inner(self) -> Timestamp {
+        self.0
+    }
+}
+
+impl<Unit: TimeUnit> Default for UnitTimestamp<Unit> {
+    fn default() -> Self {
+        Self::new(Timestamp::UNIX_EPOCH)
+    }
+}
+
+impl<Unit: TimeUnit> From<Timestamp> for UnitTimestamp<Unit> {
+    fn from(value: Timestamp) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Unit: TimeUnit> ArrayType<UnitTimestamp<Unit>> for UnitTimestamp<Unit> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<Unit: TimeUnit> ArrayType<UnitTimestamp<Unit>> for Option<UnitTimestamp<Unit>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<UnitTimestamp<Unit>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<Unit: TimeUnit> LogicalArrayType<UnitTimestamp<Unit>> for UnitTimestamp<Unit> {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(Unit::ARROW_TIME_UNIT, Some("UTC".into()))
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<Unit::ArrowTimestampType>(array, |timestamp| {
+            timestamp_from_nanos(Unit::to_nanoseconds(timestamp))
+                .is_none()
+                .then(|| format!("timestamp {timestamp} out of range"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        Self::new(timestamp_from_nanos(Unit::to_nanoseconds(item)).expect("out of range"))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        Unit::from_nanoseconds(self.0.as_nanosecond()).expect("out of range")
+    }
+}
+
+/// An array for [`UnitTimestamp`] items.
+pub type UnitTimestampArray<
+    Unit = Microsecond,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+> = LogicalArray<UnitTimestamp<Unit>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<Date> for Date {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Date> for Option<Date> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Date, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+/// The date of the Unix epoch.
+const UNIX_EPOCH_DATE: Date = Date::constant(1970, 1, 1);
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Returns the date `days` days since the Unix epoch, or `None` when out of
+/// range.
+fn date_from_days(days: i32) -> Option<Date> {
+    UNIX_EPOCH_DATE
+        .checked_add(SignedDuration::from_secs(i64::from(days) * SECONDS_PER_DAY))
+        .ok()
+}
+
+impl LogicalArrayType<Date> for Date {
+    type ArrayType = i32;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Date32
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::Date32Type>(array, |days| {
+            date_from_days(days)
+                .is_none()
+                .then(|| format!("date {days} out of range"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        date_from_days(item).expect("out of range")
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        i32::try_from(self.duration_since(UNIX_EPOCH_DATE).as_secs() / SECONDS_PER_DAY)
+            .expect("out of range")
+    }
+}
+
+/// An array for [`Date`] items.
+#[allow(unused)]
+pub type DateArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Date, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+
+    #[test]
+    fn round_trip_date() {
+        for value in [-719_162, -1, 0, 1, 19_723] {
+            assert_eq!(Date::from_array_type(value).into_array_type(), value);
+        }
+        assert_eq!(Date::from_array_type(0), UNIX_EPOCH_DATE);
+        assert_eq!(Date::constant(1969, 12, 31).into_array_type(), -1);
+    }
+
+    #[test]
+    fn round_trip_timestamp() {
+        for value in [i64::MIN, -1234, 0, 1234, i64::MAX] {
+            assert_eq!(Timestamp::from_array_type(value).into_array_type(), value);
+        }
+        assert_eq!(Timestamp::from_array_type(0), Timestamp::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn from_iter() {
+        let array = [Timestamp::UNIX_EPOCH, Timestamp::UNIX_EPOCH]
+            .into_iter()
+            .collect::<TimestampArray>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0.len(), 2);
+
+        let array_nullable = [Some(UNIX_EPOCH_DATE), None]
+            .into_iter()
+            .collect::<DateArray<true>>();
+        assert_eq!(array_nullable.len(), 2);
+        assert_eq!(array_nullable.0.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let input = [
+            Timestamp::UNIX_EPOCH,
+            Timestamp::from_nanosecond(-1234).expect("valid timestamp"),
+        ];
+        let array = input.into_iter().collect::<TimestampArray>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_nullable = [Some(Date::constant(1600, 2, 29)), None];
+        let array_nullable = input_nullable.into_iter().collect::<DateArray<true>>();
+        assert_eq!(
+            array_nullable.into_iter().collect::<Vec<_>>(),
+            input_nullable
+        );
+    }
+
+    #[test]
+    fn index() {
+        let input = [UNIX_EPOCH_DATE, Date::MAX];
+        let array = input.into_iter().collect::<DateArray>();
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.index_checked(1), input[1]);
+        assert_eq!(array.index(2), None);
+
+        let input_nullable = [None, Some(Date::MIN)];
+        let array_nullable = input_nullable.into_iter().collect::<DateArray<true>>();
+        assert_eq!(array_nullable.index_checked(0), None);
+        assert_eq!(array_nullable.index_checked(1), input_nullable[1]);
+    }
+
+    #[test]
+    fn unit_timestamp() {
+        let historical: Timestamp = "1600-02-29T12:34:56.000789Z"
+            .parse()
+            .expect("valid timestamp");
+        let input = [UnitTimestamp::new(historical), UnitTimestamp::default()];
+        let array = input.into_iter().collect::<UnitTimestampArray>();
+        assert_eq!(array.0.as_ref(), [-11_670_953_103_999_211, 0]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        assert_eq!(
+            UnitTimestamp::<crate::logical::time_unit::Second>::new(historical).into_array_type(),
+            -11_670_953_104
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn out_of_range() {
+        use std::sync::Arc;
+
+        use crate::logical::time_unit::Second;
+
+        let timestamps: Arc<dyn arrow_array::Array> = Arc::new(
+            arrow_array::TimestampSecondArray::from(vec![0, i64::MIN]).with_timezone("UTC"),
+        );
+        assert_eq!(
+            UnitTimestampArray::<Second>::try_from(timestamps).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: format!("timestamp {} out of range", i64::MIN),
+            })
+        );
+
+        let dates: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::Date32Array::from(vec![
+            Some(0),
+            None,
+            Some(i32::MAX),
+        ]));
+        assert_eq!(
+            DateArray::<true>::try_from(dates).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 2,
+                message: format!("date {} out of range", i32::MAX),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_types() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types, Array as _};
+        use arrow_schema::{DataType, TimeUnit};
+        use jiff::{civil::Date, Timestamp};
+
+        use crate::Error;
+
+        let timestamp = Timestamp::from_nanosecond(1234).expect("valid timestamp");
+        let timestamp_array: Arc<dyn arrow_array::Array> = [Some(timestamp), None]
+            .into_iter()
+            .collect::<TimestampArray<true>>()
+            .into();
+        assert_eq!(
+            timestamp_array.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            TimestampArray::<true>::try_from(timestamp_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(timestamp), None]
+        );
+
+        let date = Date::constant(1970, 1, 2);
+        let date_array: Arc<dyn arrow_array::Array> =
+            [date].into_iter().collect::<DateArray>().into();
+        assert_eq!(date_array.data_type(), &DataType::Date32);
+        assert_eq!(date_array.as_primitive::<types::Date32Type>().value(0), 1);
+
+        let int32: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::Int32Array::from(vec![1]));
+        assert_eq!(
+            DateArray::<false>::try_from(int32).err(),
+            Some(Error::DataType {
+                path: Vec::new(),
+                expected: DataType::Date32,
+                actual: DataType::Int32,
+            })
+        );
+    }
+}
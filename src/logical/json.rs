@@ -0,0 +1,248 @@
+use std::ops::{Deref, DerefMut};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// The name of the [JSON canonical extension type](https://arrow.apache.org/docs/format/CanonicalExtensions.html#json).
+#[cfg(feature = "arrow-rs")]
+const EXTENSION_NAME: &str = "arrow.json";
+
+/// Validates that the non-null values of the string array `array` are JSON
+/// texts that deserialize into `T`.
+#[cfg(feature = "arrow-rs")]
+fn validate<T: DeserializeOwned>(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+    use arrow_array::{cast::AsArray as _, GenericStringArray, OffsetSizeTrait};
+
+    fn validate_strings<T: DeserializeOwned, O: OffsetSizeTrait>(
+        array: &GenericStringArray<O>,
+    ) -> Result<(), crate::Error> {
+        array
+            .iter()
+            .enumerate()
+            .try_for_each(|(index, value)| match value {
+                Some(json) => serde_json::from_str::<T>(json).map(drop).map_err(|error| {
+                    crate::Error::InvalidValue {
+                        path: Vec::new(),
+                        index,
+                        message: error.to_string(),
+                    }
+                }),
+                None => Ok(()),
+            })
+    }
+
+    // Arrays that are not string arrays are rejected by the conversion.
+    match array.as_string_opt::<i32>() {
+        Some(strings) => validate_strings::<T, i32>(strings),
+        None => array
+            .as_string_opt::<i64>()
+            .map_or(Ok(()), validate_strings::<T, i64>),
+    }
+}
+
+impl ArrayType<Value> for Value {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Value> for Option<Value> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Value, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Value> for Value {
+    type ArrayType = String;
+
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() -> Option<(&'static str, Option<String>)> {
+        Some((EXTENSION_NAME, None))
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        validate::<serde::de::IgnoredAny>(array)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        serde_json::from_str(&item).expect("valid json")
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.to_string()
+    }
+}
+
+/// An array for [`Value`] items.
+#[allow(unused)]
+pub type JsonValueArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Value, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+/// A value that is stored as JSON text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Json<T>(pub T);
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> ArrayType<Json<T>> for Json<T> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: Serialize + DeserializeOwned> ArrayType<Json<T>> for Option<Json<T>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Json<T>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: Serialize + DeserializeOwned> LogicalArrayType<Json<T>> for Json<T> {
+    type ArrayType = String;
+
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() -> Option<(&'static str, Option<String>)> {
+        Some((EXTENSION_NAME, None))
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        validate::<T>(array)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Self(serde_json::from_str(&item).expect("valid json"))
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        serde_json::to_string(&self.0).expect("serializable")
+    }
+}
+
+/// An array for [`Json`] items.
+#[allow(unused)]
+pub type JsonArray<T, const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Json<T>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn compact_text() {
+        assert_eq!(
+            json!({ "a": [1, null, 2.5] }).into_array_type(),
+            r#"{"a":[1,null,2.5]}"#
+        );
+        assert_eq!(
+            Json(("a".to_owned(), Some(1_u32))).into_array_type(),
+            r#"["a",1]"#
+        );
+
+        let input = [Some(Json(vec![Some(1_u8), None])), None];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<JsonArray<Vec<Option<u8>>, true>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() {
+        use std::sync::Arc;
+
+        use arrow_array::cast::AsArray as _;
+        use arrow_schema::DataType;
+
+        use crate::arrow::Array as _;
+
+        let field = JsonValueArray::<true>::as_field("payload");
+        assert_eq!(field.data_type(), &DataType::Utf8);
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("arrow.json")
+        );
+        assert_eq!(
+            JsonArray::<u8>::as_field("payload").metadata(),
+            field.metadata()
+        );
+
+        let input = [Some(json!({ "a": [1, null] })), None];
+        let array: Arc<dyn arrow_array::Array> = input
+            .clone()
+            .into_iter()
+            .collect::<JsonValueArray<true>>()
+            .into();
+        assert_eq!(array.as_string::<i32>().value(0), r#"{"a":[1,null]}"#);
+        assert_eq!(
+            JsonValueArray::<true>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn invalid() {
+        use std::sync::Arc;
+
+        use crate::Error;
+
+        let invalid: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::StringArray::from(vec![
+            Some("1"),
+            None,
+            Some("{"),
+        ]));
+        assert!(matches!(
+            JsonValueArray::<true>::try_from(Arc::clone(&invalid)),
+            Err(Error::InvalidValue { index: 2, .. })
+        ));
+        // Valid JSON texts are rejected if they don't deserialize into the
+        // value type.
+        assert!(matches!(
+            JsonArray::<String, true>::try_from(invalid),
+            Err(Error::InvalidValue { index: 0, .. })
+        ));
+
+        let large: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::LargeStringArray::from(vec!["1", "256"]));
+        assert!(matches!(
+            JsonArray::<u8>::try_from(large),
+            Err(Error::InvalidValue { index: 1, .. })
+        ));
+
+        let numbers: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::StringArray::from(vec!["1", " 2 "]));
+        assert_eq!(
+            JsonArray::<u8>::try_from(numbers)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Json(1), Json(2)]
+        );
+    }
+}
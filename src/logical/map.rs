@@ -1,7 +1,7 @@
 #![allow(missing_docs)]
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     hash::{BuildHasher, Hash},
 };
 
@@ -28,7 +28,21 @@ impl<K: array::ArrayType<K> + Eq + Hash, V: array::ArrayType<V>, S: BuildHasher
         LogicalArray<HashMap<K, V, S>, true, Buffer, OffsetItem, UnionLayout>;
 }
 
-// TODO(mbrobbel): support HashMap<K, Option<V>>
+/// Returns the Arrow map data type for the given data type of the array of
+/// key-value pairs, or the given data type when it can't be stored as a map.
+#[cfg(feature = "arrow-rs")]
+fn map_data_type(
+    array_data_type: arrow_schema::DataType,
+    keys_sorted: bool,
+) -> arrow_schema::DataType {
+    match array_data_type {
+        arrow_schema::DataType::List(field) => arrow_schema::DataType::Map(
+            std::sync::Arc::new(field.as_ref().clone().with_name("entries")),
+            keys_sorted,
+        ),
+        data_type => data_type,
+    }
+}
 
 /// An item in a map.
 #[derive(ArrayType)]
@@ -39,11 +53,28 @@ pub struct KeyValue<K, V> {
     value: V,
 }
 
+/// An item in a map with nullable values.
+#[derive(ArrayType)]
+pub struct KeyNullableValue<K, V>
+where
+    Option<V>: array::ArrayType<V>,
+{
+    /// The key.
+    key: K,
+    /// The value.
+    value: Option<V>,
+}
+
 impl<K: array::ArrayType<K> + Hash + Eq, V: array::ArrayType<V>, S: BuildHasher + Default>
     LogicalArrayType<HashMap<K, V, S>> for HashMap<K, V, S>
 {
     type ArrayType = Vec<KeyValue<K, V>>;
 
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        map_data_type(array_data_type, false)
+    }
+
     fn from_array_type(item: Self::ArrayType) -> Self {
         item.into_iter()
             .map(|KeyValue { key, value }| (key, value))
@@ -57,6 +88,49 @@ impl<K: array::ArrayType<K> + Hash + Eq, V: array::ArrayType<V>, S: BuildHasher
     }
 }
 
+impl<K: array::ArrayType<K> + Eq + Hash, V: array::ArrayType<V>, S: BuildHasher + Default>
+    array::ArrayType<HashMap<K, Option<V>, S>> for HashMap<K, Option<V>, S>
+where
+    Option<V>: array::ArrayType<V>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Eq + Hash, V: array::ArrayType<V>, S: BuildHasher + Default>
+    array::ArrayType<HashMap<K, Option<V>, S>> for Option<HashMap<K, Option<V>, S>>
+where
+    Option<V>: array::ArrayType<V>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<HashMap<K, Option<V>, S>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Eq + Hash, V: array::ArrayType<V>, S: BuildHasher + Default>
+    LogicalArrayType<HashMap<K, Option<V>, S>> for HashMap<K, Option<V>, S>
+where
+    Option<V>: array::ArrayType<V>,
+{
+    type ArrayType = Vec<KeyNullableValue<K, V>>;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        map_data_type(array_data_type, false)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_iter()
+            .map(|KeyNullableValue { key, value }| (key, value))
+            .collect()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_iter()
+            .map(|(key, value)| KeyNullableValue { key, value })
+            .collect()
+    }
+}
+
 /// An array for [`HashMap`] items.
 #[allow(unused)]
 pub type HashMapArray<
@@ -67,10 +141,101 @@ pub type HashMapArray<
     OffsetItem = i32,
 > = LogicalArray<HashMap<K, V>, NULLABLE, Buffer, OffsetItem, crate::array::union::NA>;
 
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> array::ArrayType<BTreeMap<K, V>>
+    for BTreeMap<K, V>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> array::ArrayType<BTreeMap<K, V>>
+    for Option<BTreeMap<K, V>>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<BTreeMap<K, V>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> LogicalArrayType<BTreeMap<K, V>>
+    for BTreeMap<K, V>
+{
+    type ArrayType = Vec<KeyValue<K, V>>;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        // Items are iterated in key order, so the keys of every map are sorted.
+        map_data_type(array_data_type, true)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_iter()
+            .map(|KeyValue { key, value }| (key, value))
+            .collect()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_iter()
+            .map(|(key, value)| KeyValue { key, value })
+            .collect()
+    }
+}
+
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> array::ArrayType<BTreeMap<K, Option<V>>>
+    for BTreeMap<K, Option<V>>
+where
+    Option<V>: array::ArrayType<V>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> array::ArrayType<BTreeMap<K, Option<V>>>
+    for Option<BTreeMap<K, Option<V>>>
+where
+    Option<V>: array::ArrayType<V>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<BTreeMap<K, Option<V>>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<K: array::ArrayType<K> + Ord, V: array::ArrayType<V>> LogicalArrayType<BTreeMap<K, Option<V>>>
+    for BTreeMap<K, Option<V>>
+where
+    Option<V>: array::ArrayType<V>,
+{
+    type ArrayType = Vec<KeyNullableValue<K, V>>;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        map_data_type(array_data_type, true)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_iter()
+            .map(|KeyNullableValue { key, value }| (key, value))
+            .collect()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_iter()
+            .map(|(key, value)| KeyNullableValue { key, value })
+            .collect()
+    }
+}
+
+/// An array for [`BTreeMap`] items.
+#[allow(unused)]
+pub type BTreeMapArray<
+    K,
+    V,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+    OffsetItem = i32,
+> = LogicalArray<BTreeMap<K, V>, NULLABLE, Buffer, OffsetItem, crate::array::union::NA>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Length;
+    use crate::{Index, Length};
 
     #[test]
     fn from_iter() {
@@ -123,4 +288,199 @@ mod tests {
         let output_nullable = array_nullable.into_iter().collect::<Vec<_>>();
         assert_eq!(input_nullable, output_nullable.as_slice());
     }
+
+    #[test]
+    fn index() {
+        let input = [
+            HashMap::default(),
+            HashMap::from_iter([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+        ];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<HashMapArray<String, i32>>();
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.index_checked(1), input[1]);
+        assert_eq!(array.index(2), None);
+
+        let input_nullable = [None, Some(HashMap::from_iter([("a".to_owned(), 1)]))];
+        let array_nullable = input_nullable
+            .clone()
+            .into_iter()
+            .collect::<HashMapArray<String, u64, true>>();
+        assert_eq!(array_nullable.index_checked(0), None);
+        assert_eq!(array_nullable.index_checked(1), input_nullable[1]);
+    }
+
+    #[test]
+    fn btree_map() {
+        let input = [
+            Some(BTreeMap::from_iter([
+                ("b".to_owned(), 2),
+                ("a".to_owned(), 1),
+            ])),
+            None,
+            Some(BTreeMap::default()),
+        ];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<BTreeMapArray<String, u16, true>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn nullable_values() {
+        let input = [
+            Some(HashMap::from_iter([
+                ("a".to_owned(), Some(1)),
+                ("b".to_owned(), None),
+            ])),
+            None,
+            Some(HashMap::from_iter([("c".to_owned(), None)])),
+        ];
+        let mut array = input[..1]
+            .iter()
+            .cloned()
+            .collect::<HashMapArray<String, Option<u32>, true>>();
+        array.extend(input[1..].iter().cloned());
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let sorted = [BTreeMap::from_iter([(1_u8, None), (2, Some(true))])];
+        let sorted_array = sorted
+            .clone()
+            .into_iter()
+            .collect::<BTreeMapArray<u8, Option<bool>>>();
+        assert_eq!(sorted_array.into_iter().collect::<Vec<_>>(), sorted);
+    }
+
+    #[test]
+    fn nested() {
+        let input = [HashMap::from_iter([
+            (
+                "a".to_owned(),
+                Some(BTreeMap::from_iter([(1_u16, vec![Some(1_u8), None])])),
+            ),
+            ("b".to_owned(), None),
+        ])];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<HashMapArray<String, Option<BTreeMap<u16, Vec<Option<u8>>>>>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_type() {
+        use std::{
+            collections::{BTreeMap, HashMap},
+            sync::Arc,
+        };
+
+        use arrow_array::{cast::AsArray as _, Array as _};
+        use arrow_schema::DataType;
+
+        let input = [
+            Some(HashMap::from_iter([("a".to_owned(), 1)])),
+            None,
+            Some(HashMap::default()),
+        ];
+        let array: Arc<dyn arrow_array::Array> = input
+            .clone()
+            .into_iter()
+            .collect::<HashMapArray<String, u32, true>>()
+            .into();
+        let DataType::Map(ref entries, false) = *array.data_type() else {
+            panic!("expected unsorted map data type")
+        };
+        assert_eq!(entries.name(), "entries");
+        assert!(!entries.is_nullable());
+        let map_array = array.as_map();
+        assert_eq!(map_array.null_count(), 1);
+        assert_eq!(map_array.keys().as_string::<i32>().value(0), "a");
+        assert_eq!(
+            HashMapArray::<String, u32, true>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+
+        let sorted = [BTreeMap::from_iter([(2_u8, true), (1, false)])];
+        let sorted_array: Arc<dyn arrow_array::Array> = sorted
+            .clone()
+            .into_iter()
+            .collect::<BTreeMapArray<u8, bool>>()
+            .into();
+        assert!(matches!(sorted_array.data_type(), DataType::Map(_, true)));
+        assert_eq!(
+            sorted_array
+                .as_map()
+                .keys()
+                .as_primitive::<arrow_array::types::UInt8Type>()
+                .values()
+                .as_ref(),
+            [1, 2]
+        );
+        assert_eq!(
+            BTreeMapArray::<u8, bool>::try_from(sorted_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            sorted
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn nullable_values_data_type() {
+        use std::{
+            collections::{BTreeMap, HashMap},
+            sync::Arc,
+        };
+
+        use arrow_array::{cast::AsArray as _, Array as _};
+        use arrow_schema::DataType;
+
+        type Nested = HashMapArray<String, Option<BTreeMap<u8, Option<bool>>>, true>;
+
+        let input = [
+            Some(HashMap::from_iter([
+                (
+                    "a".to_owned(),
+                    Some(BTreeMap::from_iter([(1, None), (2, Some(true))])),
+                ),
+                ("b".to_owned(), None),
+            ])),
+            None,
+        ];
+        let array: Arc<dyn arrow_array::Array> =
+            input.clone().into_iter().collect::<Nested>().into();
+        let DataType::Map(ref entries, false) = *array.data_type() else {
+            panic!("expected unsorted map data type")
+        };
+        let DataType::Struct(ref fields) = *entries.data_type() else {
+            panic!("expected struct entries")
+        };
+        assert!(!fields[0].is_nullable());
+        assert!(fields[1].is_nullable());
+        assert!(matches!(fields[1].data_type(), DataType::Map(_, true)));
+
+        let values = array.as_map().values().as_map();
+        assert_eq!(values.null_count(), 1);
+        assert_eq!(values.values().null_count(), 1);
+
+        assert_eq!(
+            Nested::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
 }
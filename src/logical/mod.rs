@@ -7,20 +7,83 @@ use crate::{
     buffer::BufferType,
     offset::OffsetElement,
     validity::Nullability,
-    Length,
+    Index, IndexOwned, Length, Slice,
 };
 
+/// Bool8 support via logical arrays.
+pub mod bool8;
+
 /// Box support via logical arrays.
 pub mod r#box;
 
+/// Char support via logical arrays.
+pub mod char;
+
 #[cfg(feature = "chrono")]
 /// Chrono support via logical arrays.
 pub mod chrono;
 
+/// Cow support via logical arrays.
+pub mod cow;
+
+/// Decimal support via logical arrays.
+pub mod decimal;
+
+/// Duration support via logical arrays.
+pub mod duration;
+
+#[cfg(feature = "indexmap")]
+/// Indexmap support via logical arrays.
+pub mod indexmap;
+
+/// Interval support via logical arrays.
+pub mod interval;
+
+#[cfg(feature = "jiff")]
+/// Jiff support via logical arrays.
+pub mod jiff;
+
+#[cfg(feature = "serde_json")]
+/// JSON support via logical arrays.
+pub mod json;
+
 #[cfg(feature = "map")]
 /// Map arrays via logical arrays.
 pub mod map;
 
+/// IP address support via logical arrays.
+pub mod net;
+
+/// Non-zero and wrapping integer support via logical arrays.
+pub mod num;
+
+/// Opaque support via logical arrays.
+pub mod opaque;
+
+#[cfg(feature = "arrow-rs")]
+/// Primitive types without an Arrow native type via logical arrays.
+pub mod primitive;
+
+/// Rc and Arc support via logical arrays.
+pub mod rc;
+
+/// Set support via logical arrays.
+pub mod set;
+
+/// System time support via logical arrays.
+pub mod system_time;
+
+/// Fixed-shape tensor support via logical arrays.
+pub mod tensor;
+
+#[cfg(feature = "time")]
+/// Time support via logical arrays.
+pub mod time;
+
+#[cfg(any(feature = "chrono", feature = "jiff", feature = "time"))]
+/// Units of timestamp logical types.
+pub mod time_unit;
+
 #[cfg(feature = "uuid")]
 /// Uuid support via logical arrays.
 pub mod uuid;
@@ -37,6 +100,43 @@ where
     /// Corresponding [`ArrayType`].
     type ArrayType: ArrayType<Self::ArrayType>;
 
+    /// Returns the Arrow data type of this logical type, given the data type
+    /// of the array of [`Self::ArrayType`].
+    ///
+    /// The returned data type must have the same physical layout as the
+    /// given data type.
+    #[cfg(feature = "arrow-rs")]
+    #[must_use]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        array_data_type
+    }
+
+    /// Returns the name and the serialized metadata of the Arrow [extension
+    /// type] of this logical type, if any.
+    ///
+    /// The extension type is added to the metadata of the fields of arrays of
+    /// this type, and checked when struct arrays, or arrays with their field,
+    /// are converted from Arrow.
+    ///
+    /// [extension type]: https://arrow.apache.org/docs/format/Columnar.html#extension-types
+    #[cfg(feature = "arrow-rs")]
+    #[must_use]
+    fn extension_type() -> Option<(&'static str, Option<String>)> {
+        None
+    }
+
+    /// Validates the values of an Arrow array before it is converted into an
+    /// array of this logical type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidValue`](crate::Error::InvalidValue) when a
+    /// value can't be converted via [`Self::from_array_type`].
+    #[cfg(feature = "arrow-rs")]
+    fn validate(_array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        Ok(())
+    }
+
     /// Convert from [`Self::ArrayType`].
     fn from_array_type(item: Self::ArrayType) -> Self;
 
@@ -44,6 +144,31 @@ where
     fn into_array_type(self) -> Self::ArrayType;
 }
 
+/// Validates the non-null values of the primitive array `array`, returning an
+/// [`Error::InvalidValue`](crate::Error::InvalidValue) with the message
+/// returned by `invalid` for the first invalid value.
+#[cfg(feature = "arrow-rs")]
+pub(crate) fn validate_primitive<T: arrow_array::ArrowPrimitiveType>(
+    array: &dyn arrow_array::Array,
+    invalid: impl Fn(T::Native) -> Option<String>,
+) -> Result<(), crate::Error> {
+    use arrow_array::cast::AsArray as _;
+
+    // Arrays that are not primitive arrays of `T` are rejected by the
+    // conversion.
+    array.as_primitive_opt::<T>().map_or(Ok(()), |values| {
+        values.iter().enumerate().try_for_each(|(index, value)| {
+            value.and_then(&invalid).map_or(Ok(()), |message| {
+                Err(crate::Error::InvalidValue {
+                    path: Vec::new(),
+                    index,
+                    message,
+                })
+            })
+        })
+    })
+}
+
 /// An array for [`LogicalArrayType`] items, that are stored in Arrow arrays,
 /// but convertable from and to theirself via this array wrapper.
 #[allow(clippy::type_complexity)]
@@ -299,9 +424,88 @@ where
     }
 }
 
+impl<
+        T: LogicalArrayType<T>,
+        const NULLABLE: bool,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+        UnionLayout: UnionType,
+    > Slice for LogicalArray<T, NULLABLE, Buffer, OffsetItem, UnionLayout>
+where
+    Option<T>: ArrayType<T>,
+    <T as LogicalArrayType<T>>::ArrayType: Nullability<NULLABLE>,
+    <<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item:
+        ArrayType<<T as LogicalArrayType<T>>::ArrayType>,
+    <<<T as LogicalArrayType<T>>::ArrayType as Nullability<NULLABLE>>::Item as ArrayType<
+        <T as LogicalArrayType<T>>::ArrayType,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self(self.0.slice_unchecked(offset, len))
+    }
+}
+
+impl<
+        T: LogicalArrayType<T>,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+        UnionLayout: UnionType,
+    > Index for LogicalArray<T, false, Buffer, OffsetItem, UnionLayout>
+where
+    Option<T>: ArrayType<T>,
+    <<T as LogicalArrayType<T>>::ArrayType as ArrayType<
+        <T as LogicalArrayType<T>>::ArrayType,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: Length,
+    for<'a> <<T as LogicalArrayType<T>>::ArrayType as ArrayType<
+        <T as LogicalArrayType<T>>::ArrayType,
+    >>::Array<Buffer, OffsetItem, UnionLayout>:
+        IndexOwned<'a, <T as LogicalArrayType<T>>::ArrayType>,
+{
+    type Item<'a> = T
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        LogicalArrayType::from_array_type(self.0.index_owned_unchecked(index))
+    }
+}
+
+impl<
+        T: LogicalArrayType<T>,
+        Buffer: BufferType,
+        OffsetItem: OffsetElement,
+        UnionLayout: UnionType,
+    > Index for LogicalArray<T, true, Buffer, OffsetItem, UnionLayout>
+where
+    Option<T>: ArrayType<T>,
+    Option<<T as LogicalArrayType<T>>::ArrayType>: ArrayType<<T as LogicalArrayType<T>>::ArrayType>,
+    <Option<<T as LogicalArrayType<T>>::ArrayType> as ArrayType<
+        <T as LogicalArrayType<T>>::ArrayType,
+    >>::Array<Buffer, OffsetItem, UnionLayout>: Length,
+    for<'a> <Option<<T as LogicalArrayType<T>>::ArrayType> as ArrayType<
+        <T as LogicalArrayType<T>>::ArrayType,
+    >>::Array<Buffer, OffsetItem, UnionLayout>:
+        IndexOwned<'a, Option<<T as LogicalArrayType<T>>::ArrayType>>,
+{
+    type Item<'a>
+        = Option<T>
+    where
+        Self: 'a;
+
+    unsafe fn index_unchecked(&self, index: usize) -> Self::Item<'_> {
+        self.0
+            .index_owned_unchecked(index)
+            .map(LogicalArrayType::from_array_type)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{array::union, buffer::VecBuffer, offset};
+    use crate::{
+        array::union,
+        buffer::{ArcSliceBuffer, VecBuffer},
+        offset,
+    };
 
     use super::*;
 
@@ -357,4 +561,32 @@ mod tests {
         let output_nullable = array_nullable.into_iter().collect::<Vec<_>>();
         assert_eq!(output_nullable, input_nullable);
     }
+
+    #[test]
+    fn index() {
+        let array = [Foo(1), Foo(2), Foo(3), Foo(4)]
+            .into_iter()
+            .collect::<FooArray>();
+        assert_eq!(array.index_checked(0), Foo(1));
+        assert_eq!(array.index_checked(3), Foo(4));
+        assert_eq!(array.index(4), None);
+
+        let array_nullable = [Some(Foo(1)), None, Some(Foo(3)), Some(Foo(4))]
+            .into_iter()
+            .collect::<FooArray<true>>();
+        assert_eq!(array_nullable.index_checked(0), Some(Foo(1)));
+        assert_eq!(array_nullable.index_checked(1), None);
+        assert_eq!(array_nullable.index(4), None);
+    }
+
+    #[test]
+    fn slice() {
+        let array = [Some(Foo(1)), None, Some(Foo(3)), Some(Foo(4))]
+            .into_iter()
+            .collect::<FooArray<true, ArcSliceBuffer>>();
+        let sliced = array.slice(1..3);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.index_checked(0), None);
+        assert_eq!(sliced.index_checked(1), Some(Foo(3)));
+    }
 }
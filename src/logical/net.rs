@@ -0,0 +1,165 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    array::{ArrayType, FixedSizeBinary, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+impl ArrayType<Ipv4Addr> for Ipv4Addr {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Ipv4Addr> for Option<Ipv4Addr> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Ipv4Addr, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Ipv4Addr> for Ipv4Addr {
+    type ArrayType = FixedSizeBinary<4>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        <[u8; 4]>::from(item).into()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.octets().into()
+    }
+}
+
+/// An array for [`Ipv4Addr`] items.
+#[allow(unused)]
+pub type Ipv4AddrArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Ipv4Addr, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<Ipv6Addr> for Ipv6Addr {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Ipv6Addr> for Option<Ipv6Addr> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Ipv6Addr, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Ipv6Addr> for Ipv6Addr {
+    type ArrayType = FixedSizeBinary<16>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        <[u8; 16]>::from(item).into()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.octets().into()
+    }
+}
+
+/// An array for [`Ipv6Addr`] items.
+#[allow(unused)]
+pub type Ipv6AddrArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Ipv6Addr, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<IpAddr> for IpAddr {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<IpAddr> for Option<IpAddr> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<IpAddr, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<IpAddr> for IpAddr {
+    // IPv4 addresses are stored as IPv4-mapped IPv6 addresses.
+    type ArrayType = FixedSizeBinary<16>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        let addr = Ipv6Addr::from(<[u8; 16]>::from(item));
+        addr.to_ipv4_mapped().map_or(IpAddr::V6(addr), IpAddr::V4)
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        match self {
+            IpAddr::V4(addr) => addr.to_ipv6_mapped(),
+            IpAddr::V6(addr) => addr,
+        }
+        .octets()
+        .into()
+    }
+}
+
+/// An array for [`IpAddr`] items.
+///
+/// IPv4 addresses are stored as IPv4-mapped IPv6 addresses, which means that
+/// IPv4-mapped IPv6 addresses are read as IPv4 addresses.
+#[allow(unused)]
+pub type IpAddrArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<IpAddr, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+
+    #[test]
+    fn from_iter() {
+        let array = [Ipv4Addr::LOCALHOST, Ipv4Addr::new(192, 168, 0, 1)]
+            .into_iter()
+            .collect::<Ipv4AddrArray>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0.len(), 2);
+
+        let array_nullable = [Some(Ipv6Addr::LOCALHOST), None]
+            .into_iter()
+            .collect::<Ipv6AddrArray<true>>();
+        assert_eq!(array_nullable.len(), 2);
+        assert_eq!(array_nullable.0.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let input = [
+            Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))),
+            None,
+            Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))),
+        ];
+        let array = input.into_iter().collect::<IpAddrArray<true>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn index() {
+        let array = [
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(Ipv6Addr::LOCALHOST),
+        ]
+        .into_iter()
+        .collect::<IpAddrArray>();
+        assert_eq!(array.index_checked(0), IpAddr::V4(Ipv4Addr::LOCALHOST));
+        assert_eq!(array.index_checked(1), IpAddr::V6(Ipv6Addr::LOCALHOST));
+        assert_eq!(array.index(2), None);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_type() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, Array as _};
+        use arrow_schema::DataType;
+
+        let array: Arc<dyn arrow_array::Array> = [IpAddr::V4(Ipv4Addr::LOCALHOST)]
+            .into_iter()
+            .collect::<IpAddrArray>()
+            .into();
+        assert_eq!(array.data_type(), &DataType::FixedSizeBinary(16));
+        // IPv4 addresses are stored as IPv4-mapped IPv6 addresses.
+        assert_eq!(
+            array.as_fixed_size_binary().value(0),
+            Ipv4Addr::LOCALHOST.to_ipv6_mapped().octets()
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// Implement [`LogicalArrayType`] for a non-zero integer type that is stored
+/// as its primitive type.
+macro_rules! impl_non_zero {
+    ($ty:ty, $primitive:ty, $arrow_type:ty, $ident:ident) => {
+        impl ArrayType<$ty> for $ty {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl ArrayType<$ty> for Option<$ty> {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<$ty, true, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl LogicalArrayType<$ty> for $ty {
+            type ArrayType = $primitive;
+
+            #[cfg(feature = "arrow-rs")]
+            fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+                super::validate_primitive::<$arrow_type>(array, |item| {
+                    (item == 0).then(|| format!("zero value for {}", stringify!($ty)))
+                })
+            }
+
+            fn from_array_type(item: Self::ArrayType) -> Self {
+                // Values converted from Arrow are validated.
+                Self::new(item).expect("non-zero value")
+            }
+
+            fn into_array_type(self) -> Self::ArrayType {
+                self.get()
+            }
+        }
+
+        #[doc = concat!("An array for [`", stringify!($ty), "`] items.")]
+        pub type $ident<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+            LogicalArray<$ty, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+    };
+}
+
+impl_non_zero!(NonZeroI8, i8, arrow_array::types::Int8Type, NonZeroI8Array);
+impl_non_zero!(
+    NonZeroI16,
+    i16,
+    arrow_array::types::Int16Type,
+    NonZeroI16Array
+);
+impl_non_zero!(
+    NonZeroI32,
+    i32,
+    arrow_array::types::Int32Type,
+    NonZeroI32Array
+);
+impl_non_zero!(
+    NonZeroI64,
+    i64,
+    arrow_array::types::Int64Type,
+    NonZeroI64Array
+);
+impl_non_zero!(
+    NonZeroIsize,
+    isize,
+    arrow_array::types::Int64Type,
+    NonZeroIsizeArray
+);
+impl_non_zero!(NonZeroU8, u8, arrow_array::types::UInt8Type, NonZeroU8Array);
+impl_non_zero!(
+    NonZeroU16,
+    u16,
+    arrow_array::types::UInt16Type,
+    NonZeroU16Array
+);
+impl_non_zero!(
+    NonZeroU32,
+    u32,
+    arrow_array::types::UInt32Type,
+    NonZeroU32Array
+);
+impl_non_zero!(
+    NonZeroU64,
+    u64,
+    arrow_array::types::UInt64Type,
+    NonZeroU64Array
+);
+impl_non_zero!(
+    NonZeroUsize,
+    usize,
+    arrow_array::types::UInt64Type,
+    NonZeroUsizeArray
+);
+
+impl<T: ArrayType<T>> ArrayType<Wrapping<T>> for Wrapping<T>
+where
+    Option<T>: ArrayType<T>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T>> ArrayType<Wrapping<T>> for Option<Wrapping<T>>
+where
+    Option<T>: ArrayType<T>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Wrapping<T>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T>> LogicalArrayType<Wrapping<T>> for Wrapping<T>
+where
+    Option<T>: ArrayType<T>,
+{
+    type ArrayType = T;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Wrapping(item)
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.0
+    }
+}
+
+/// An array for [`Wrapping`] items.
+pub type WrappingArray<T, const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Wrapping<T>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        assert_eq!(NonZeroI8::MIN.into_array_type(), i8::MIN);
+        assert_eq!(NonZeroU64::MAX.into_array_type(), u64::MAX);
+
+        let input = [NonZeroI32::new(i32::MIN), None, NonZeroI32::new(-1)];
+        let array = input.into_iter().collect::<NonZeroI32Array<true>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    fn wrapping() {
+        let input = [Wrapping(u8::MAX) + Wrapping(1), Wrapping(u8::MAX)];
+        let array = input.into_iter().collect::<WrappingArray<u8>>();
+        assert_eq!(array.0 .0.as_slice(), [0, u8::MAX]);
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn zero() {
+        use std::sync::Arc;
+
+        use arrow_array::Array as _;
+        use arrow_schema::DataType;
+
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::UInt64Array::from(vec![1, 0]));
+        assert_eq!(array.data_type(), &DataType::UInt64);
+        assert_eq!(
+            NonZeroU64Array::<false>::try_from(array).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: "zero value for NonZeroU64".to_owned(),
+            })
+        );
+
+        // Null slots are not validated.
+        let nullable: Arc<dyn arrow_array::Array> = [NonZeroI16::new(-1), None]
+            .into_iter()
+            .collect::<NonZeroI16Array<true>>()
+            .into();
+        assert_eq!(nullable.data_type(), &DataType::Int16);
+        assert_eq!(
+            NonZeroI16Array::<true>::try_from(nullable)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [NonZeroI16::new(-1), None]
+        );
+    }
+}
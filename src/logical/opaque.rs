@@ -0,0 +1,250 @@
+use std::{fmt, hash, marker::PhantomData, ops::Deref};
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// Types of [`Opaque`] items.
+///
+/// The names are stored in the metadata of the [`arrow.opaque`] extension
+/// type, so consumers can tell which external type the storage values
+/// represent.
+///
+/// [`arrow.opaque`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#opaque
+pub trait OpaqueType {
+    /// The name of the type in the external system.
+    const TYPE_NAME: &'static str;
+
+    /// The name of the external system.
+    const VENDOR_NAME: &'static str;
+}
+
+/// A value of an external type, that is stored as `T` with the
+/// [`arrow.opaque`] canonical extension type.
+///
+/// [`arrow.opaque`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#opaque
+pub struct Opaque<T, Type: OpaqueType>(T, PhantomData<Type>);
+
+impl<T, Type: OpaqueType> Opaque<T, Type> {
+    /// Returns a new opaque value for the given storage value.
+    pub fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns the inner storage value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Clone, Type: OpaqueType> Clone for Opaque<T, Type> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<T: Copy, Type: OpaqueType> Copy for Opaque<T, Type> {}
+
+impl<T: fmt::Debug, Type: OpaqueType> fmt::Debug for Opaque<T, Type> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Opaque").field(&self.0).finish()
+    }
+}
+
+impl<T: PartialEq, Type: OpaqueType> PartialEq for Opaque<T, Type> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Eq, Type: OpaqueType> Eq for Opaque<T, Type> {}
+
+impl<T: hash::Hash, Type: OpaqueType> hash::Hash for Opaque<T, Type> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T, Type: OpaqueType> Deref for Opaque<T, Type> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, Type: OpaqueType> From<T> for Opaque<T, Type> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: ArrayType<T>, Type: OpaqueType> ArrayType<Opaque<T, Type>> for Opaque<T, Type>
+where
+    Option<T>: ArrayType<T>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T>, Type: OpaqueType> ArrayType<Opaque<T, Type>> for Option<Opaque<T, Type>>
+where
+    Option<T>: ArrayType<T>,
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Opaque<T, Type>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+/// Returns `value` as a JSON string literal.
+#[cfg(feature = "arrow-rs")]
+fn json_string(value: &str) -> String {
+    use std::fmt::Write as _;
+
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            control if control.is_control() => {
+                write!(json, "\\u{:04x}", u32::from(control)).expect("infallible");
+            }
+            other => json.push(other),
+        }
+    }
+    json.push('"');
+    json
+}
+
+impl<T: ArrayType<T>, Type: OpaqueType> LogicalArrayType<Opaque<T, Type>> for Opaque<T, Type>
+where
+    Option<T>: ArrayType<T>,
+{
+    type ArrayType = T;
+
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() -> Option<(&'static str, Option<String>)> {
+        Some((
+            "arrow.opaque",
+            Some(format!(
+                r#"{{"type_name":{},"vendor_name":{}}}"#,
+                json_string(Type::TYPE_NAME),
+                json_string(Type::VENDOR_NAME)
+            )),
+        ))
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Self::new(item)
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.0
+    }
+}
+
+/// An array for [`Opaque`] items.
+#[allow(unused)]
+pub type OpaqueArray<T, Type, const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Opaque<T, Type>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+
+    struct Geometry;
+
+    impl OpaqueType for Geometry {
+        const TYPE_NAME: &'static str = "geometry";
+        const VENDOR_NAME: &'static str = "postgis";
+    }
+
+    #[test]
+    fn from_iter() {
+        let array = [Opaque::new(vec![1_u8]), Opaque::new(vec![])]
+            .into_iter()
+            .collect::<OpaqueArray<Vec<u8>, Geometry>>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0.len(), 2);
+
+        let array_nullable = [Some(Opaque::new(1_u32)), None]
+            .into_iter()
+            .collect::<OpaqueArray<u32, Geometry, true>>();
+        assert_eq!(array_nullable.len(), 2);
+        assert_eq!(array_nullable.0.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let input = [Opaque::new("a".to_owned()), Opaque::new("bc".to_owned())];
+        let array = input
+            .clone()
+            .into_iter()
+            .collect::<OpaqueArray<String, Geometry>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_nullable = [None, Some(Opaque::new(1_u32))];
+        let array_nullable = input_nullable
+            .into_iter()
+            .collect::<OpaqueArray<u32, Geometry, true>>();
+        assert_eq!(
+            array_nullable.into_iter().collect::<Vec<_>>(),
+            input_nullable
+        );
+    }
+
+    #[test]
+    fn index() {
+        let input = [Opaque::new(1_u32), Opaque::new(2)];
+        let array = input.into_iter().collect::<OpaqueArray<u32, Geometry>>();
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.index_checked(1), input[1]);
+        assert_eq!(array.index(2), None);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn json_string() {
+        assert_eq!(super::json_string("postgis"), r#""postgis""#);
+        assert_eq!(super::json_string("a\"b\\c\n"), r#""a\"b\\c\u000a""#);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() {
+        use std::sync::Arc;
+
+        use arrow_array::Array as _;
+        use arrow_schema::DataType;
+
+        use crate::arrow::Array as _;
+
+        let field = OpaqueArray::<Vec<u8>, Geometry>::as_field("geometry");
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("arrow.opaque")
+        );
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:metadata")
+                .map(String::as_str),
+            Some(r#"{"type_name":"geometry","vendor_name":"postgis"}"#)
+        );
+
+        // The storage type is the data type of the wrapped type.
+        let array: Arc<dyn arrow_array::Array> = [Opaque::new(1_u32)]
+            .into_iter()
+            .collect::<OpaqueArray<u32, Geometry>>()
+            .into();
+        assert_eq!(array.data_type(), &DataType::UInt32);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{
+    array::{ArrayType, FixedSizeBinary, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// Implement [`LogicalArrayType`] for a primitive type that is stored as
+/// another primitive type, with checked conversions.
+macro_rules! impl_logical_primitive {
+    ($ty:ty, $array_type:ty, $arrow_type:ty, $ident:ident) => {
+        impl ArrayType<$ty> for $ty {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl ArrayType<$ty> for Option<$ty> {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<$ty, true, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl LogicalArrayType<$ty> for $ty {
+            type ArrayType = $array_type;
+
+            fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+                super::validate_primitive::<$arrow_type>(array, |item| {
+                    Self::try_from(item)
+                        .is_err()
+                        .then(|| format!("value {item} out of range for {}", stringify!($ty)))
+                })
+            }
+
+            fn from_array_type(item: Self::ArrayType) -> Self {
+                // Values converted from Arrow are validated.
+                Self::try_from(item).expect("out of range")
+            }
+
+            fn into_array_type(self) -> Self::ArrayType {
+                Self::ArrayType::try_from(self).expect("out of range")
+            }
+        }
+
+        #[doc = "An array for [`"]
+        #[doc = stringify!($ty)]
+        #[doc = "`] items, stored as [`"]
+        #[doc = stringify!($array_type)]
+        #[doc = "`] items."]
+        pub type $ident<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+            LogicalArray<$ty, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+    };
+}
+
+impl_logical_primitive!(usize, u64, arrow_array::types::UInt64Type, UsizeArray);
+impl_logical_primitive!(isize, i64, arrow_array::types::Int64Type, IsizeArray);
+
+impl ArrayType<u128> for u128 {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<u128> for Option<u128> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<u128, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<u128> for u128 {
+    // The little-endian bytes of the value.
+    type ArrayType = FixedSizeBinary<16>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Self::from_le_bytes(item.into())
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.to_le_bytes().into()
+    }
+}
+
+/// An array for [`u128`] items, stored as little-endian
+/// [`FixedSizeBinary<16>`] items.
+pub type Uint128Array<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<u128, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::{cast::AsArray as _, types, Array as _};
+    use arrow_schema::DataType;
+
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        let usize_array: Arc<dyn arrow_array::Array> =
+            [0, usize::MAX].into_iter().collect::<UsizeArray>().into();
+        assert_eq!(usize_array.data_type(), &DataType::UInt64);
+        assert_eq!(
+            usize_array.as_primitive::<types::UInt64Type>().value(1),
+            u64::MAX
+        );
+        assert_eq!(
+            UsizeArray::<false>::try_from(usize_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [0, usize::MAX]
+        );
+
+        let isize_input = [Some(isize::MIN), None, Some(isize::MAX)];
+        let isize_array: Arc<dyn arrow_array::Array> =
+            isize_input.into_iter().collect::<IsizeArray<true>>().into();
+        assert_eq!(isize_array.data_type(), &DataType::Int64);
+        assert_eq!(
+            IsizeArray::<true>::try_from(isize_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            isize_input
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn out_of_range() {
+        let array: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::UInt64Array::from(vec![0, u64::MAX]));
+        assert_eq!(
+            UsizeArray::<false>::try_from(array).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: format!("value {} out of range for usize", u64::MAX),
+            })
+        );
+    }
+
+    #[test]
+    fn u128_little_endian() {
+        let input = [Some(u128::MAX - 1), None, Some(1)];
+        let array: Arc<dyn arrow_array::Array> =
+            input.into_iter().collect::<Uint128Array<true>>().into();
+        assert_eq!(array.data_type(), &DataType::FixedSizeBinary(16));
+        assert_eq!(
+            array.as_fixed_size_binary().value(0),
+            (u128::MAX - 1).to_le_bytes()
+        );
+        assert_eq!(array.as_fixed_size_binary().value(2)[0], 1);
+        assert_eq!(
+            Uint128Array::<true>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::{rc::Rc, sync::Arc};
+
+use crate::{
+    array::{ArrayType, UnionType, VariableSizeBinary},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// Implement [`LogicalArrayType`] for the reference-counted pointer `$ptr`,
+/// mapping `$ptr<T>` like `T`, `$ptr<str>` like [`String`] and `$ptr<[u8]>`
+/// like [`VariableSizeBinary`].
+macro_rules! impl_rc {
+    ($ptr:ident, $array:ident, $str_array:ident, $bytes_array:ident) => {
+        impl<T: ArrayType<T> + Clone> ArrayType<$ptr<T>> for $ptr<T>
+        where
+            Option<T>: ArrayType<T>,
+        {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl<T: ArrayType<T> + Clone> ArrayType<$ptr<T>> for Option<$ptr<T>>
+        where
+            Option<T>: ArrayType<T>,
+        {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<$ptr<T>, true, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl<T: ArrayType<T> + Clone> LogicalArrayType<$ptr<T>> for $ptr<T>
+        where
+            Option<T>: ArrayType<T>,
+        {
+            type ArrayType = T;
+
+            fn from_array_type(item: Self::ArrayType) -> Self {
+                $ptr::new(item)
+            }
+
+            fn into_array_type(self) -> Self::ArrayType {
+                $ptr::try_unwrap(self).unwrap_or_else(|shared| T::clone(&shared))
+            }
+        }
+
+        #[doc = concat!("An array for [`", stringify!($ptr), "`] items.")]
+        #[allow(unused)]
+        pub type $array<T, const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+            LogicalArray<$ptr<T>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+        impl ArrayType<$ptr<str>> for $ptr<str> {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl ArrayType<$ptr<str>> for Option<$ptr<str>> {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<$ptr<str>, true, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl LogicalArrayType<$ptr<str>> for $ptr<str> {
+            type ArrayType = String;
+
+            fn from_array_type(item: Self::ArrayType) -> Self {
+                item.into()
+            }
+
+            fn into_array_type(self) -> Self::ArrayType {
+                self.as_ref().to_owned()
+            }
+        }
+
+        #[doc = concat!("An array for [`", stringify!($ptr), "<str>`] items.")]
+        #[allow(unused)]
+        pub type $str_array<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+            LogicalArray<$ptr<str>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+        impl ArrayType<$ptr<[u8]>> for $ptr<[u8]> {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl ArrayType<$ptr<[u8]>> for Option<$ptr<[u8]>> {
+            type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+                LogicalArray<$ptr<[u8]>, true, Buffer, OffsetItem, UnionLayout>;
+        }
+
+        impl LogicalArrayType<$ptr<[u8]>> for $ptr<[u8]> {
+            type ArrayType = VariableSizeBinary;
+
+            fn from_array_type(item: Self::ArrayType) -> Self {
+                Vec::from(item).into()
+            }
+
+            fn into_array_type(self) -> Self::ArrayType {
+                self.to_vec().into()
+            }
+        }
+
+        #[doc = concat!("An array for [`", stringify!($ptr), "<[u8]>`] items.")]
+        #[allow(unused)]
+        pub type $bytes_array<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+            LogicalArray<$ptr<[u8]>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+    };
+}
+
+impl_rc!(Rc, RcArray, RcStrArray, RcBytesArray);
+impl_rc!(Arc, ArcArray, ArcStrArray, ArcBytesArray);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Index, Length};
+
+    #[test]
+    fn from_iter() {
+        let array = [Rc::new(1), Rc::new(42)]
+            .into_iter()
+            .collect::<RcArray<i32>>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0.len(), 2);
+
+        let array_nullable = [Some(Arc::new(1)), None]
+            .into_iter()
+            .collect::<ArcArray<i32, true>>();
+        assert_eq!(array_nullable.len(), 2);
+        assert_eq!(array_nullable.0.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let shared = Arc::new("a".to_owned());
+        let input = [Arc::clone(&shared), shared];
+        let array = input.clone().into_iter().collect::<ArcArray<String>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_nullable = [Some(Rc::new(vec![1_u8])), None];
+        let array_nullable = input_nullable
+            .clone()
+            .into_iter()
+            .collect::<RcArray<Vec<u8>, true>>();
+        assert_eq!(
+            array_nullable.into_iter().collect::<Vec<_>>(),
+            input_nullable
+        );
+    }
+
+    #[test]
+    fn str() {
+        let input: [Option<Arc<str>>; 3] = [Some("a".into()), None, Some("bc".into())];
+        let array = input.clone().into_iter().collect::<ArcStrArray<true>>();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.index_checked(2), Some("bc".into()));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_rc: [Rc<str>; 2] = ["a".into(), "bc".into()];
+        let array_rc = input_rc.clone().into_iter().collect::<RcStrArray>();
+        assert_eq!(array_rc.into_iter().collect::<Vec<_>>(), input_rc);
+    }
+
+    #[test]
+    fn bytes() {
+        let input: [Arc<[u8]>; 2] = [Arc::new([1, 2]), Arc::new([])];
+        let array = input.clone().into_iter().collect::<ArcBytesArray>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.index_checked(0), Arc::from([1, 2]));
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_rc: [Option<Rc<[u8]>>; 2] = [None, Some(Rc::new([3]))];
+        let array_rc = input_rc.clone().into_iter().collect::<RcBytesArray<true>>();
+        assert_eq!(array_rc.into_iter().collect::<Vec<_>>(), input_rc);
+    }
+
+    #[test]
+    #[cfg(all(feature = "arrow-rs", feature = "derive"))]
+    fn pointer_struct_field() {
+        use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+        use arrow_schema::DataType;
+
+        use crate::array::StructArray;
+
+        #[derive(crate::ArrayType, Clone, Debug, PartialEq)]
+        struct Token<'a> {
+            name: Arc<str>,
+            text: Cow<'a, str>,
+            raw: Option<Box<[u8]>>,
+            count: Rc<u32>,
+        }
+
+        let input = [
+            Token {
+                name: "a".into(),
+                text: Cow::Borrowed("b"),
+                raw: None,
+                count: Rc::new(1),
+            },
+            Token {
+                name: "c".into(),
+                text: Cow::Owned("d".to_owned()),
+                raw: Some(Box::new([1, 2])),
+                count: Rc::new(2),
+            },
+        ];
+        let record_batch = arrow_array::RecordBatch::from(
+            input.clone().into_iter().collect::<StructArray<Token>>(),
+        );
+        let schema = record_batch.schema();
+        assert_eq!(schema.field(0).data_type(), &DataType::Utf8);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert!(schema.field(2).is_nullable());
+        assert_eq!(schema.field(3).data_type(), &DataType::UInt32);
+        assert_eq!(
+            StructArray::<Token>::try_from(record_batch)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+impl<T: ArrayType<T> + Eq + Hash, S: BuildHasher + Default> ArrayType<HashSet<T, S>>
+    for HashSet<T, S>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T> + Eq + Hash, S: BuildHasher + Default> ArrayType<HashSet<T, S>>
+    for Option<HashSet<T, S>>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<HashSet<T, S>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T> + Eq + Hash, S: BuildHasher + Default> LogicalArrayType<HashSet<T, S>>
+    for HashSet<T, S>
+{
+    type ArrayType = Vec<T>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_iter().collect()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_iter().collect()
+    }
+}
+
+/// An array for [`HashSet`] items.
+///
+/// The items of the sets are stored in iteration order, which is not
+/// deterministic.
+#[allow(unused)]
+pub type HashSetArray<
+    T,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+    OffsetItem = i32,
+> = LogicalArray<HashSet<T>, NULLABLE, Buffer, OffsetItem, crate::array::union::NA>;
+
+impl<T: ArrayType<T> + Ord> ArrayType<BTreeSet<T>> for BTreeSet<T> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T> + Ord> ArrayType<BTreeSet<T>> for Option<BTreeSet<T>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<BTreeSet<T>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T> + Ord> LogicalArrayType<BTreeSet<T>> for BTreeSet<T> {
+    type ArrayType = Vec<T>;
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        item.into_iter().collect()
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.into_iter().collect()
+    }
+}
+
+/// An array for [`BTreeSet`] items.
+///
+/// The items of the sets are stored in ascending order.
+#[allow(unused)]
+pub type BTreeSetArray<
+    T,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+    OffsetItem = i32,
+> = LogicalArray<BTreeSet<T>, NULLABLE, Buffer, OffsetItem, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicates() {
+        assert_eq!(
+            HashSet::<u8>::from_array_type(vec![2, 1, 2]),
+            HashSet::from_iter([1, 2])
+        );
+        assert_eq!(
+            BTreeSet::<u8>::from_array_type(vec![2, 1, 2]),
+            BTreeSet::from_iter([1, 2])
+        );
+    }
+
+    #[test]
+    fn btree_set_order() {
+        let input = [BTreeSet::from_iter([3, 1, 2]), BTreeSet::default()];
+        assert_eq!(input[0].clone().into_array_type(), [1, 2, 3]);
+        let array = input.clone().into_iter().collect::<BTreeSetArray<i64>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_type() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types::UInt16Type, Array as _};
+        use arrow_schema::{DataType, Field};
+
+        let input = [Some(BTreeSet::from_iter([3, 1, 2])), None];
+        let array: Arc<dyn arrow_array::Array> = input
+            .clone()
+            .into_iter()
+            .collect::<BTreeSetArray<u16, true>>()
+            .into();
+        assert_eq!(
+            array.data_type(),
+            &DataType::List(Arc::new(Field::new("item", DataType::UInt16, false)))
+        );
+        assert_eq!(
+            array
+                .as_list::<i32>()
+                .values()
+                .as_primitive::<UInt16Type>()
+                .values()
+                .as_ref(),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            BTreeSetArray::<u16, true>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn duplicates_from_arrow() {
+        use std::sync::Arc;
+
+        use arrow_buffer::OffsetBuffer;
+        use arrow_schema::{DataType, Field};
+
+        let array: Arc<dyn arrow_array::Array> = Arc::new(arrow_array::ListArray::new(
+            Arc::new(Field::new("item", DataType::UInt16, false)),
+            OffsetBuffer::from_lengths([3, 1]),
+            Arc::new(arrow_array::UInt16Array::from(vec![2, 1, 2, 4])),
+            None,
+        ));
+        assert_eq!(
+            HashSetArray::<u16>::try_from(Arc::clone(&array))
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [HashSet::from_iter([1, 2]), HashSet::from_iter([4])]
+        );
+        assert_eq!(
+            BTreeSetArray::<u16>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [BTreeSet::from_iter([1, 2]), BTreeSet::from_iter([4])]
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::time::{Duration, SystemTime};
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+impl ArrayType<SystemTime> for SystemTime {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<SystemTime> for Option<SystemTime> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<SystemTime, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<SystemTime> for SystemTime {
+    // Nanoseconds since the Unix epoch.
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, Some("UTC".into()))
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        let duration = Duration::from_nanos(item.unsigned_abs());
+        if item < 0 {
+            SystemTime::UNIX_EPOCH - duration
+        } else {
+            SystemTime::UNIX_EPOCH + duration
+        }
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        match self.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => i64::try_from(duration.as_nanos()).expect("out of range"),
+            Err(error) => i64::try_from(error.duration().as_nanos())
+                .map(i64::wrapping_neg)
+                .expect("out of range"),
+        }
+    }
+}
+
+/// An array for [`SystemTime`] items, stored as nanoseconds since the Unix
+/// epoch.
+pub type SystemTimeArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<SystemTime, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for value in [
+            0,
+            1,
+            -1,
+            1_234_567_890_123_456_789,
+            -1_234_567_890_123_456_789,
+        ] {
+            assert_eq!(SystemTime::from_array_type(value).into_array_type(), value);
+        }
+    }
+
+    #[test]
+    fn before_epoch() {
+        let input = [
+            Some(SystemTime::UNIX_EPOCH - Duration::from_nanos(1)),
+            None,
+            Some(SystemTime::UNIX_EPOCH - Duration::new(1, 500)),
+        ];
+        let array = input.into_iter().collect::<SystemTimeArray<true>>();
+        assert_eq!(
+            input.map(|value| value.map(SystemTime::into_array_type)),
+            [Some(-1), None, Some(-1_000_000_500)]
+        );
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_type() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types, Array as _};
+        use arrow_schema::{DataType, TimeUnit};
+
+        let input = [
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1),
+            SystemTime::UNIX_EPOCH - Duration::from_secs(1),
+        ];
+        let array: Arc<dyn arrow_array::Array> =
+            input.into_iter().collect::<SystemTimeArray>().into();
+        assert_eq!(
+            array.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            array
+                .as_primitive::<types::TimestampNanosecondType>()
+                .values()
+                .as_ref(),
+            [1_000_000_000, -1_000_000_000]
+        );
+        assert_eq!(
+            SystemTimeArray::<false>::try_from(array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+    }
+}
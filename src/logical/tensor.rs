@@ -0,0 +1,220 @@
+use std::ops::Deref;
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{LogicalArray, LogicalArrayType};
+
+/// A matrix of `M` rows and `N` columns, stored with the
+/// [`arrow.fixed_shape_tensor`] canonical extension type with shape `[M, N]`.
+///
+/// The elements are stored in row-major order in a fixed-size list of
+/// `M * N` items.
+///
+/// [`arrow.fixed_shape_tensor`]: https://arrow.apache.org/docs/format/CanonicalExtensions.html#fixed-shape-tensor
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FixedShapeTensor<T, const M: usize, const N: usize>(pub [[T; N]; M]);
+
+impl<T, const M: usize, const N: usize> Deref for FixedShapeTensor<T, M, N> {
+    type Target = [[T; N]; M];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<[[T; N]; M]> for FixedShapeTensor<T, M, N> {
+    fn from(value: [[T; N]; M]) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: ArrayType<T>, const M: usize, const N: usize> ArrayType<FixedShapeTensor<T, M, N>>
+    for FixedShapeTensor<T, M, N>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T>, const M: usize, const N: usize> ArrayType<FixedShapeTensor<T, M, N>>
+    for Option<FixedShapeTensor<T, M, N>>
+{
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<FixedShapeTensor<T, M, N>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<T: ArrayType<T>, const M: usize, const N: usize> LogicalArrayType<FixedShapeTensor<T, M, N>>
+    for FixedShapeTensor<T, M, N>
+{
+    type ArrayType = [[T; N]; M];
+
+    /// Returns a fixed-size list of `M * N` items, i.e. the flattened nested
+    /// fixed-size lists of the rows.
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(array_data_type: arrow_schema::DataType) -> arrow_schema::DataType {
+        match array_data_type {
+            arrow_schema::DataType::FixedSizeList(ref field, _) => match *field.data_type() {
+                arrow_schema::DataType::FixedSizeList(ref item, _) => {
+                    arrow_schema::DataType::FixedSizeList(
+                        std::sync::Arc::clone(item),
+                        i32::try_from(M * N).expect("out of range"),
+                    )
+                }
+                _ => array_data_type,
+            },
+            data_type => data_type,
+        }
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() -> Option<(&'static str, Option<String>)> {
+        Some((
+            "arrow.fixed_shape_tensor",
+            Some(format!(r#"{{"shape":[{M},{N}]}}"#)),
+        ))
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Self(item)
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.0
+    }
+}
+
+/// An array for [`FixedShapeTensor`] items.
+#[allow(unused)]
+pub type FixedShapeTensorArray<
+    T,
+    const M: usize,
+    const N: usize,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+> = LogicalArray<
+    FixedShapeTensor<T, M, N>,
+    NULLABLE,
+    Buffer,
+    crate::offset::NA,
+    crate::array::union::NA,
+>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows() {
+        let tensor = FixedShapeTensor::from([[1_u8, 2, 3], [4, 5, 6]]);
+        assert_eq!(tensor[1], [4, 5, 6]);
+        assert_eq!(tensor[0][2], 3);
+
+        let input = [None, Some(tensor)];
+        let array = input
+            .into_iter()
+            .collect::<FixedShapeTensorArray<u8, 2, 3, true>>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn row_major() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types, Array as _};
+        use arrow_schema::{DataType, Field};
+
+        use crate::arrow::Array as _;
+
+        let field = FixedShapeTensorArray::<f32, 2, 3>::as_field("tensor");
+        assert_eq!(
+            field.data_type(),
+            &DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, false)), 6)
+        );
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("arrow.fixed_shape_tensor")
+        );
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:metadata")
+                .map(String::as_str),
+            Some(r#"{"shape":[2,3]}"#)
+        );
+
+        let input = [
+            Some(FixedShapeTensor([[1_f32, 2., 3.], [4., 5., 6.]])),
+            None,
+            Some(FixedShapeTensor([[7., 8., 9.], [10., 11., 12.]])),
+        ];
+        let array: Arc<dyn arrow_array::Array> = input
+            .into_iter()
+            .collect::<FixedShapeTensorArray<f32, 2, 3, true>>()
+            .into();
+        assert_eq!(array.data_type(), field.data_type());
+        assert_eq!(array.null_count(), 1);
+        let values = array.as_fixed_size_list().value(2);
+        assert_eq!(
+            values.as_primitive::<types::Float32Type>().values(),
+            &[7., 8., 9., 10., 11., 12.]
+        );
+        assert_eq!(
+            FixedShapeTensorArray::<f32, 2, 3, true>::try_from(array.slice(1, 2))
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input[1..]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn extension_metadata() {
+        use std::sync::Arc;
+
+        use crate::arrow::Array as _;
+
+        let array: Arc<dyn arrow_array::Array> = [FixedShapeTensor([[1_u8, 2, 3], [4, 5, 6]])]
+            .into_iter()
+            .collect::<FixedShapeTensorArray<u8, 2, 3>>()
+            .into();
+        let field = |metadata: &str| {
+            Arc::new(
+                FixedShapeTensorArray::<u8, 2, 3>::as_field("tensor").with_metadata(
+                    [
+                        (
+                            "ARROW:extension:name".to_owned(),
+                            "arrow.fixed_shape_tensor".to_owned(),
+                        ),
+                        ("ARROW:extension:metadata".to_owned(), metadata.to_owned()),
+                    ]
+                    .into(),
+                ),
+            )
+        };
+
+        // Other writers may reorder members, add whitespace, or add the
+        // optional members.
+        let other_writer =
+            field(r#"{ "dim_names": ["x", "y"], "permutation": [0, 1], "shape": [2, 3] }"#);
+        assert!(
+            FixedShapeTensorArray::<u8, 2, 3>::try_from((other_writer, Arc::clone(&array))).is_ok()
+        );
+
+        assert_eq!(
+            FixedShapeTensorArray::<u8, 2, 3>::try_from((field(r#"{"shape":[3,2]}"#), array)).err(),
+            Some(crate::Error::ExtensionType {
+                path: vec!["tensor".to_owned()],
+                expected: r#"arrow.fixed_shape_tensor {"shape":[2,3]}"#.to_owned(),
+                actual: r#"arrow.fixed_shape_tensor {"shape":[3,2]}"#.to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,561 @@
+use std::marker::PhantomData;
+
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+use crate::{
+    array::{ArrayType, UnionType},
+    buffer::BufferType,
+    offset::OffsetElement,
+};
+
+use super::{
+    time_unit::{Microsecond, TimeUnit},
+    LogicalArray, LogicalArrayType,
+};
+
+impl ArrayType<OffsetDateTime> for OffsetDateTime {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<OffsetDateTime> for Option<OffsetDateTime> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<OffsetDateTime, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+/// Items are stored as UTC timestamps, so the offset of an [`OffsetDateTime`]
+/// is not preserved.
+impl LogicalArrayType<OffsetDateTime> for OffsetDateTime {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, Some("UTC".into()))
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(item)).expect("out of range")
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        i64::try_from(self.unix_timestamp_nanos()).expect("out of range")
+    }
+}
+
+/// An array for [`OffsetDateTime`] items, stored as UTC timestamps in
+/// nanoseconds.
+///
+/// The offset of the items is not preserved, items read from this array are
+/// in UTC.
+///
+/// # Panics
+///
+/// Storing a date time outside the years 1677 to 2262 panics, use a
+/// [`Timestamp`] with a unit of [`Microsecond`] or coarser for other date
+/// times.
+#[allow(unused)]
+pub type OffsetDateTimeArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<OffsetDateTime, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<PrimitiveDateTime> for PrimitiveDateTime {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<PrimitiveDateTime> for Option<PrimitiveDateTime> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<PrimitiveDateTime, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<PrimitiveDateTime> for PrimitiveDateTime {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(arrow_schema::TimeUnit::Nanosecond, None)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        let date_time = OffsetDateTime::from_array_type(item);
+        PrimitiveDateTime::new(date_time.date(), date_time.time())
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.assume_utc().into_array_type()
+    }
+}
+
+/// An array for [`PrimitiveDateTime`] items, stored as timestamps in
+/// nanoseconds.
+///
+/// # Panics
+///
+/// Storing a date time outside the years 1677 to 2262 panics.
+#[allow(unused)]
+pub type PrimitiveDateTimeArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<PrimitiveDateTime, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+/// An [`OffsetDateTime`] stored as a UTC timestamp with the given
+/// [`TimeUnit`].
+///
+/// Unlike [`OffsetDateTime`] items, that are stored as nanoseconds, this can
+/// represent all dates with a unit of [`Microsecond`] or coarser. The offset
+/// is not preserved, items read from arrays of timestamps are in UTC.
+///
+/// # Panics
+///
+/// With `Unit = Nanosecond`, storing a date time outside the years 1677 to
+/// 2262 panics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Timestamp<Unit: TimeUnit = Microsecond>(OffsetDateTime, PhantomData<Unit>);
+
+impl<Unit: TimeUnit> Timestamp<Unit> {
+    /// Returns a new timestamp for the given date time.
+    #[must_use]
+    pub fn new(date_time: OffsetDateTime) -> Self {
+        Self(date_time, PhantomData)
+    }
+
+    /// Returns the inner date time.
+    #[must_use]
+    pub fn into_inner(self) -> OffsetDateTime {
+        self.0
+    }
+}
+
+impl<Unit: TimeUnit> Default for Timestamp<Unit> {
+    fn default() -> Self {
+        Self::new(OffsetDateTime::UNIX_EPOCH)
+    }
+}
+
+impl<Unit: TimeUnit> From<OffsetDateTime> for Timestamp<Unit> {
+    fn from(value: OffsetDateTime) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<Unit: TimeUnit> ArrayType<Timestamp<Unit>> for Timestamp<Unit> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<Unit: TimeUnit> ArrayType<Timestamp<Unit>> for Option<Timestamp<Unit>> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Timestamp<Unit>, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl<Unit: TimeUnit> LogicalArrayType<Timestamp<Unit>> for Timestamp<Unit> {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Timestamp(Unit::ARROW_TIME_UNIT, Some("UTC".into()))
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<Unit::ArrowTimestampType>(array, |timestamp| {
+            OffsetDateTime::from_unix_timestamp_nanos(Unit::to_nanoseconds(timestamp))
+                .is_err()
+                .then(|| format!("timestamp {timestamp} out of range"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        Self::new(
+            OffsetDateTime::from_unix_timestamp_nanos(Unit::to_nanoseconds(item))
+                .expect("out of range"),
+        )
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        Unit::from_nanoseconds(self.0.unix_timestamp_nanos()).expect("out of range")
+    }
+}
+
+/// An array for [`Timestamp`] items.
+pub type TimestampArray<
+    Unit = Microsecond,
+    const NULLABLE: bool = false,
+    Buffer = crate::buffer::VecBuffer,
+> = LogicalArray<Timestamp<Unit>, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<Date> for Date {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Date> for Option<Date> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Date, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+/// The Julian day of the Unix epoch.
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+/// Returns the date `days` days since the Unix epoch, or `None` when out of
+/// range.
+fn date_from_days(days: i32) -> Option<Date> {
+    days.checked_add(UNIX_EPOCH_JULIAN_DAY)
+        .and_then(|julian_day| Date::from_julian_day(julian_day).ok())
+}
+
+impl LogicalArrayType<Date> for Date {
+    type ArrayType = i32;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Date32
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::Date32Type>(array, |days| {
+            date_from_days(days)
+                .is_none()
+                .then(|| format!("date {days} out of range"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        date_from_days(item).expect("out of range")
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        self.to_julian_day() - UNIX_EPOCH_JULIAN_DAY
+    }
+}
+
+/// An array for [`Date`] items.
+#[allow(unused)]
+pub type DateArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Date, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<Time> for Time {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Time> for Option<Time> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Time, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+/// The number of nano seconds in a second.
+const NANO_SECONDS: i64 = 1_000_000_000;
+
+/// Returns the time `nanos` nanoseconds since midnight, or `None` when out of
+/// range.
+fn time_from_nanos(nanos: i64) -> Option<Time> {
+    let (secs, nano) = (
+        nanos.div_euclid(NANO_SECONDS),
+        nanos.rem_euclid(NANO_SECONDS),
+    );
+    Time::from_hms_nano(
+        u8::try_from(secs / 3600).ok()?,
+        u8::try_from(secs / 60 % 60).ok()?,
+        u8::try_from(secs % 60).ok()?,
+        u32::try_from(nano).ok()?,
+    )
+    .ok()
+}
+
+impl LogicalArrayType<Time> for Time {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Time64(arrow_schema::TimeUnit::Nanosecond)
+    }
+
+    #[cfg(feature = "arrow-rs")]
+    fn validate(array: &dyn arrow_array::Array) -> Result<(), crate::Error> {
+        super::validate_primitive::<arrow_array::types::Time64NanosecondType>(array, |nanos| {
+            time_from_nanos(nanos)
+                .is_none()
+                .then(|| format!("time {nanos} out of range"))
+        })
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        // Values converted from Arrow are validated.
+        time_from_nanos(item).expect("out of range")
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        let (hour, minute, second, nano) = self.as_hms_nano();
+        (i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second)) * NANO_SECONDS
+            + i64::from(nano)
+    }
+}
+
+/// An array for [`Time`] items.
+#[allow(unused)]
+pub type TimeArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Time, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+impl ArrayType<Duration> for Duration {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Self, false, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl ArrayType<Duration> for Option<Duration> {
+    type Array<Buffer: BufferType, OffsetItem: OffsetElement, UnionLayout: UnionType> =
+        LogicalArray<Duration, true, Buffer, OffsetItem, UnionLayout>;
+}
+
+impl LogicalArrayType<Duration> for Duration {
+    type ArrayType = i64;
+
+    #[cfg(feature = "arrow-rs")]
+    fn data_type(_: arrow_schema::DataType) -> arrow_schema::DataType {
+        arrow_schema::DataType::Duration(arrow_schema::TimeUnit::Nanosecond)
+    }
+
+    fn from_array_type(item: Self::ArrayType) -> Self {
+        Duration::nanoseconds(item)
+    }
+
+    fn into_array_type(self) -> Self::ArrayType {
+        i64::try_from(self.whole_nanoseconds()).expect("out of range")
+    }
+}
+
+/// An array for [`Duration`] items.
+#[allow(unused)]
+pub type DurationArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuffer> =
+    LogicalArray<Duration, NULLABLE, Buffer, crate::offset::NA, crate::array::union::NA>;
+
+#[cfg(test)]
+mod tests {
+    use time::UtcOffset;
+
+    use super::*;
+    use crate::{Index, Length};
+
+    #[test]
+    fn round_trip_date() {
+        for value in [-719_162, -1, 0, 1, 19_723] {
+            assert_eq!(Date::from_array_type(value).into_array_type(), value);
+        }
+        assert_eq!(Date::from_array_type(0), OffsetDateTime::UNIX_EPOCH.date());
+    }
+
+    #[test]
+    fn round_trip_time() {
+        for value in [
+            0,
+            1234,
+            1234 * NANO_SECONDS,
+            86_398 * NANO_SECONDS + 1_999_999_999,
+        ] {
+            assert_eq!(Time::from_array_type(value).into_array_type(), value);
+        }
+    }
+
+    #[test]
+    fn round_trip_offset_date_time() {
+        let offset = UtcOffset::from_hms(1, 0, 0).expect("valid offset");
+        let date_time = OffsetDateTime::from_array_type(-1234).to_offset(offset);
+        assert_eq!(date_time.into_array_type(), -1234);
+        assert_eq!(
+            OffsetDateTime::from_array_type(date_time.into_array_type()),
+            date_time
+        );
+    }
+
+    #[test]
+    fn round_trip_duration() {
+        for value in [-1234, 0, 1234, i64::MAX] {
+            assert_eq!(Duration::from_array_type(value).into_array_type(), value);
+        }
+    }
+
+    #[test]
+    fn from_iter() {
+        let array = [OffsetDateTime::UNIX_EPOCH, OffsetDateTime::UNIX_EPOCH]
+            .into_iter()
+            .collect::<OffsetDateTimeArray>();
+        assert_eq!(array.len(), 2);
+        assert_eq!(array.0.len(), 2);
+
+        let array_nullable = [Some(Date::MIN), None]
+            .into_iter()
+            .collect::<DateArray<true>>();
+        assert_eq!(array_nullable.len(), 2);
+        assert_eq!(array_nullable.0.len(), 2);
+    }
+
+    #[test]
+    fn into_iter() {
+        let date_time = OffsetDateTime::UNIX_EPOCH + Duration::nanoseconds(1234);
+        let input = [
+            PrimitiveDateTime::new(date_time.date(), date_time.time()),
+            PrimitiveDateTime::MIN
+                .replace_year(1800)
+                .expect("valid year"),
+        ];
+        let array = input.into_iter().collect::<PrimitiveDateTimeArray>();
+        assert_eq!(array.into_iter().collect::<Vec<_>>(), input);
+
+        let input_nullable = [Some(Duration::SECOND), None, Some(-Duration::SECOND)];
+        let array_nullable = input_nullable.into_iter().collect::<DurationArray<true>>();
+        assert_eq!(
+            array_nullable.into_iter().collect::<Vec<_>>(),
+            input_nullable
+        );
+    }
+
+    #[test]
+    fn index() {
+        let input = [Time::MIDNIGHT, Time::MAX];
+        let array = input.into_iter().collect::<TimeArray>();
+        assert_eq!(array.index_checked(0), input[0]);
+        assert_eq!(array.index_checked(1), input[1]);
+        assert_eq!(array.index(2), None);
+
+        let input_nullable = [None, Some(Time::MAX)];
+        let array_nullable = input_nullable.into_iter().collect::<TimeArray<true>>();
+        assert_eq!(array_nullable.index_checked(0), None);
+        assert_eq!(array_nullable.index_checked(1), input_nullable[1]);
+    }
+
+    #[test]
+    fn timestamp() {
+        let historical = PrimitiveDateTime::new(
+            Date::from_calendar_date(1600, time::Month::February, 29).expect("valid date"),
+            Time::from_hms_micro(12, 34, 56, 789).expect("valid time"),
+        )
+        .assume_offset(UtcOffset::from_hms(1, 0, 0).expect("valid offset"));
+        let input = [Timestamp::new(historical), Timestamp::default()];
+        let array = input.into_iter().collect::<TimestampArray>();
+        assert_eq!(array.0.as_ref(), [-11_670_956_703_999_211, 0]);
+        let output = array.into_iter().collect::<Vec<_>>();
+        assert_eq!(output, input);
+        assert_eq!(output[0].into_inner().offset(), UtcOffset::UTC);
+
+        assert_eq!(
+            Timestamp::<crate::logical::time_unit::Second>::new(historical).into_array_type(),
+            -11_670_956_704
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn out_of_range() {
+        use std::sync::Arc;
+
+        use crate::logical::time_unit::Second;
+
+        let timestamps: Arc<dyn arrow_array::Array> = Arc::new(
+            arrow_array::TimestampSecondArray::from(vec![0, i64::MAX]).with_timezone("UTC"),
+        );
+        assert_eq!(
+            TimestampArray::<Second>::try_from(timestamps).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 1,
+                message: format!("timestamp {} out of range", i64::MAX),
+            })
+        );
+
+        let dates: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::Date32Array::from(vec![Some(i32::MIN), None]));
+        assert!(DateArray::<true>::try_from(dates).is_err());
+
+        let times: Arc<dyn arrow_array::Array> =
+            Arc::new(arrow_array::Time64NanosecondArray::from(vec![
+                86_400 * NANO_SECONDS,
+            ]));
+        assert_eq!(
+            TimeArray::<false>::try_from(times).err(),
+            Some(crate::Error::InvalidValue {
+                path: Vec::new(),
+                index: 0,
+                message: format!("time {} out of range", 86_400 * NANO_SECONDS),
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn data_types() {
+        use std::sync::Arc;
+
+        use arrow_array::{cast::AsArray as _, types, Array as _};
+        use arrow_schema::{DataType, TimeUnit};
+        use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+        let date_time = OffsetDateTime::UNIX_EPOCH + Duration::nanoseconds(1234);
+        let date_time_array: Arc<dyn arrow_array::Array> = [Some(date_time), None]
+            .into_iter()
+            .collect::<OffsetDateTimeArray<true>>()
+            .into();
+        assert_eq!(
+            date_time_array.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+        );
+        assert_eq!(
+            date_time_array
+                .as_primitive::<types::TimestampNanosecondType>()
+                .value(0),
+            1234
+        );
+        assert_eq!(
+            OffsetDateTimeArray::<true>::try_from(date_time_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [Some(date_time), None]
+        );
+
+        let primitive_date_time = PrimitiveDateTime::new(date_time.date(), date_time.time());
+        let primitive_date_time_array: Arc<dyn arrow_array::Array> = [primitive_date_time]
+            .into_iter()
+            .collect::<PrimitiveDateTimeArray>()
+            .into();
+        assert_eq!(
+            primitive_date_time_array.data_type(),
+            &DataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+
+        let date = Date::from_calendar_date(1970, Month::January, 2).expect("valid date");
+        let date_array: Arc<dyn arrow_array::Array> =
+            [date].into_iter().collect::<DateArray>().into();
+        assert_eq!(date_array.data_type(), &DataType::Date32);
+        assert_eq!(date_array.as_primitive::<types::Date32Type>().value(0), 1);
+
+        let time = Time::from_hms(0, 0, 1).expect("valid time");
+        let time_array: Arc<dyn arrow_array::Array> =
+            [time].into_iter().collect::<TimeArray>().into();
+        assert_eq!(
+            time_array.data_type(),
+            &DataType::Time64(TimeUnit::Nanosecond)
+        );
+        assert_eq!(
+            time_array
+                .as_primitive::<types::Time64NanosecondType>()
+                .value(0),
+            1_000_000_000
+        );
+        assert_eq!(
+            TimeArray::<false>::try_from(time_array)
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [time]
+        );
+
+        let duration_array: Arc<dyn arrow_array::Array> = [Duration::SECOND]
+            .into_iter()
+            .collect::<DurationArray>()
+            .into();
+        assert_eq!(
+            duration_array.data_type(),
+            &DataType::Duration(TimeUnit::Nanosecond)
+        );
+    }
+}
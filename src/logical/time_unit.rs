@@ -0,0 +1,111 @@
+/// Units of timestamp items, stored as the number of units since the Unix
+/// epoch.
+pub trait TimeUnit {
+    /// The corresponding Arrow time unit.
+    #[cfg(feature = "arrow-rs")]
+    const ARROW_TIME_UNIT: arrow_schema::TimeUnit;
+
+    /// The corresponding Arrow timestamp type.
+    #[cfg(feature = "arrow-rs")]
+    type ArrowTimestampType: arrow_array::types::ArrowTimestampType<Native = i64>;
+
+    /// The number of nanoseconds in one unit.
+    const NANOSECONDS: i128;
+
+    /// Returns the number of units in the given number of nanoseconds,
+    /// truncating any finer precision, or `None` if it can't be represented.
+    #[must_use]
+    fn from_nanoseconds(nanoseconds: i128) -> Option<i64> {
+        i64::try_from(nanoseconds.div_euclid(Self::NANOSECONDS)).ok()
+    }
+
+    /// Returns the number of nanoseconds in the given number of units.
+    #[must_use]
+    fn to_nanoseconds(value: i64) -> i128 {
+        i128::from(value) * Self::NANOSECONDS
+    }
+}
+
+/// Second [`TimeUnit`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Second;
+
+impl TimeUnit for Second {
+    #[cfg(feature = "arrow-rs")]
+    const ARROW_TIME_UNIT: arrow_schema::TimeUnit = arrow_schema::TimeUnit::Second;
+
+    #[cfg(feature = "arrow-rs")]
+    type ArrowTimestampType = arrow_array::types::TimestampSecondType;
+
+    const NANOSECONDS: i128 = 1_000_000_000;
+}
+
+/// Millisecond [`TimeUnit`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Millisecond;
+
+impl TimeUnit for Millisecond {
+    #[cfg(feature = "arrow-rs")]
+    const ARROW_TIME_UNIT: arrow_schema::TimeUnit = arrow_schema::TimeUnit::Millisecond;
+
+    #[cfg(feature = "arrow-rs")]
+    type ArrowTimestampType = arrow_array::types::TimestampMillisecondType;
+
+    const NANOSECONDS: i128 = 1_000_000;
+}
+
+/// Microsecond [`TimeUnit`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Microsecond;
+
+impl TimeUnit for Microsecond {
+    #[cfg(feature = "arrow-rs")]
+    const ARROW_TIME_UNIT: arrow_schema::TimeUnit = arrow_schema::TimeUnit::Microsecond;
+
+    #[cfg(feature = "arrow-rs")]
+    type ArrowTimestampType = arrow_array::types::TimestampMicrosecondType;
+
+    const NANOSECONDS: i128 = 1_000;
+}
+
+/// Nanosecond [`TimeUnit`].
+///
+/// Only date times between the years 1677 and 2262 can be stored as
+/// nanoseconds, use [`Microsecond`] or a coarser unit for other date times.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Nanosecond;
+
+impl TimeUnit for Nanosecond {
+    #[cfg(feature = "arrow-rs")]
+    const ARROW_TIME_UNIT: arrow_schema::TimeUnit = arrow_schema::TimeUnit::Nanosecond;
+
+    #[cfg(feature = "arrow-rs")]
+    type ArrowTimestampType = arrow_array::types::TimestampNanosecondType;
+
+    const NANOSECONDS: i128 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_nanoseconds() {
+        assert_eq!(Second::from_nanoseconds(-1), Some(-1));
+        assert_eq!(Millisecond::from_nanoseconds(1_999_999), Some(1));
+        assert_eq!(Microsecond::from_nanoseconds(i128::MAX), None);
+        assert_eq!(
+            Nanosecond::from_nanoseconds(i128::from(i64::MIN)),
+            Some(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn to_nanoseconds() {
+        assert_eq!(Second::to_nanoseconds(-1), -1_000_000_000);
+        assert_eq!(
+            Microsecond::to_nanoseconds(i64::MAX),
+            i128::from(i64::MAX) * 1_000
+        );
+    }
+}
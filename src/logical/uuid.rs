@@ -21,6 +21,11 @@ impl ArrayType<uuid::Uuid> for Option<uuid::Uuid> {
 impl LogicalArrayType<uuid::Uuid> for uuid::Uuid {
     type ArrayType = FixedSizeBinary<16>;
 
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() -> Option<(&'static str, Option<String>)> {
+        Some(("arrow.uuid", None))
+    }
+
     fn from_array_type(item: Self::ArrayType) -> Self {
         Self::from_bytes(item.into())
     }
@@ -38,7 +43,7 @@ pub type UuidArray<const NULLABLE: bool = false, Buffer = crate::buffer::VecBuff
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Length;
+    use crate::{Index, Length};
 
     #[test]
     fn from_iter() {
@@ -67,4 +72,107 @@ mod tests {
         let output_nullable = array_nullable.into_iter().collect::<Vec<_>>();
         assert_eq!(input_nullable, output_nullable.as_slice());
     }
+
+    #[test]
+    fn index() {
+        let array = [Uuid::from_u128(1), Uuid::from_u128(42)]
+            .into_iter()
+            .collect::<UuidArray>();
+        assert_eq!(array.index_checked(1), Uuid::from_u128(42));
+        assert_eq!(array.index(2), None);
+
+        let array_nullable = [Some(Uuid::from_u128(1)), None]
+            .into_iter()
+            .collect::<UuidArray<true>>();
+        assert_eq!(array_nullable.index_checked(0), Some(Uuid::from_u128(1)));
+        assert_eq!(array_nullable.index_checked(1), None);
+    }
+
+    #[test]
+    #[cfg(feature = "arrow-rs")]
+    fn extension_type() {
+        use std::sync::Arc;
+
+        use arrow_schema::{Field, Schema};
+        use uuid::Uuid;
+
+        use crate::{array::StructArray, arrow::Array as _, Error};
+
+        let field = UuidArray::<false>::as_field("id");
+        assert_eq!(
+            field
+                .metadata()
+                .get("ARROW:extension:name")
+                .map(String::as_str),
+            Some("arrow.uuid")
+        );
+
+        let input = [(Uuid::from_u128(1), 2_u32)];
+        let record_batch =
+            arrow_array::RecordBatch::from(input.into_iter().collect::<StructArray<(Uuid, u32)>>());
+        assert_eq!(
+            record_batch.schema().field(0),
+            &field.clone().with_name("0")
+        );
+        assert_eq!(
+            StructArray::<(Uuid, u32)>::try_from(record_batch.clone())
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            input
+        );
+
+        // Untagged storage arrays are accepted.
+        let with_schema = |uuid_field: Field| {
+            arrow_array::RecordBatch::try_new(
+                Arc::new(Schema::new(vec![
+                    uuid_field,
+                    record_batch.schema().field(1).clone(),
+                ])),
+                record_batch.columns().to_vec(),
+            )
+            .expect("valid record batch")
+        };
+        let untagged = with_schema(
+            record_batch
+                .schema()
+                .field(0)
+                .clone()
+                .with_metadata(std::collections::HashMap::default()),
+        );
+        assert!(StructArray::<(Uuid, u32)>::try_from(untagged).is_ok());
+
+        let mismatch = with_schema(record_batch.schema().field(0).clone().with_metadata(
+            [("ARROW:extension:name".to_owned(), "arrow.opaque".to_owned())].into(),
+        ));
+        assert_eq!(
+            StructArray::<(Uuid, u32)>::try_from(mismatch).err(),
+            Some(Error::ExtensionType {
+                path: vec!["0".to_owned()],
+                expected: "arrow.uuid".to_owned(),
+                actual: "arrow.opaque".to_owned(),
+            })
+        );
+
+        // Top-level arrays are checked against their field.
+        let column = Arc::clone(record_batch.column(0));
+        assert_eq!(
+            UuidArray::<false>::try_from((Arc::new(field.clone()), Arc::clone(&column)))
+                .expect("conversion")
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [input[0].0]
+        );
+        let opaque_field = Arc::new(field.with_metadata(
+            [("ARROW:extension:name".to_owned(), "arrow.opaque".to_owned())].into(),
+        ));
+        assert_eq!(
+            UuidArray::<false>::try_from((opaque_field, column)).err(),
+            Some(Error::ExtensionType {
+                path: vec!["id".to_owned()],
+                expected: "arrow.uuid".to_owned(),
+                actual: "arrow.opaque".to_owned(),
+            })
+        );
+    }
 }
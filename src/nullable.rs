@@ -3,7 +3,7 @@
 use crate::{
     bitmap::{Bitmap, BitmapIntoIter, BitmapIter, BitmapRef, BitmapRefMut, ValidityBitmap},
     buffer::{self, BufferMut, BufferRef, BufferRefMut, BufferType, VecBuffer},
-    FixedSize, Index, Length,
+    FixedSize, Index, Length, Slice,
 };
 use std::{
     borrow::Borrow,
@@ -175,7 +175,8 @@ impl<T, Buffer: BufferType> Index for Nullable<T, Buffer>
 where
     T: Index,
 {
-    type Item<'a> = Option<<T as Index>::Item<'a>>
+    type Item<'a>
+        = Option<<T as Index>::Item<'a>>
     where
         Self: 'a;
 
@@ -250,12 +251,26 @@ where
     }
 }
 
+impl<T, Buffer: BufferType> Slice for Nullable<T, Buffer>
+where
+    T: Slice,
+    Bitmap<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            data: self.data.slice_unchecked(offset, len),
+            validity: self.validity.slice_unchecked(offset, len),
+        }
+    }
+}
+
 impl<T, Buffer: BufferType> ValidityBitmap for Nullable<T, Buffer> {}
 
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::buffer::ArcSliceBuffer;
     use std::{
         iter::{self, Repeat, Take},
         mem,
@@ -331,6 +346,20 @@ mod tests {
         let _ = nullable.index_checked(2);
     }
 
+    #[test]
+    fn slice() {
+        let input = [Some(1), Some(2), None, Some(4), None];
+        let nullable = input
+            .into_iter()
+            .collect::<Nullable<<ArcSliceBuffer as BufferType>::Buffer<u32>, ArcSliceBuffer>>();
+        let sliced = nullable.slice(1..4);
+        assert_eq!(sliced.len(), 3);
+        assert_eq!(sliced.index_checked(0), Some(&2));
+        assert_eq!(sliced.index_checked(1), None);
+        assert_eq!(sliced.index_checked(2), Some(&4));
+        assert_eq!(sliced.slice(2..).index_checked(0), Some(&4));
+    }
+
     #[test]
     fn opt_bool_iter() {
         let input = [Some(true), Some(false), None];
@@ -1,13 +1,15 @@
 //! Offsets for variable-sized arrays.
 
 use crate::{
+    array::VariableSizeBinary,
     bitmap::{Bitmap, BitmapRef, BitmapRefMut, ValidityBitmap},
     buffer::{Buffer, BufferType, VecBuffer},
     nullable::Nullable,
     validity::Validity,
-    FixedSize, Index, Length,
+    FixedSize, Index, IntoOwned, Length, Slice,
 };
 use std::{
+    collections::VecDeque,
     iter::{self, Map, Peekable, Zip},
     num::TryFromIntError,
     ops::{AddAssign, Range, Sub},
@@ -83,7 +85,7 @@ pub struct OffsetSlot<'a, OffsetItem: OffsetElement, Buffer: BufferType> {
     index: usize,
 }
 
-impl<'a, OffsetItem: OffsetElement, Buffer: BufferType> OffsetSlot<'a, OffsetItem, Buffer> {
+impl<OffsetItem: OffsetElement, Buffer: BufferType> OffsetSlot<'_, OffsetItem, Buffer> {
     /// Returns the position of this slot in the buffer i.e. the index.
     #[must_use]
     pub fn position(&self) -> usize {
@@ -390,8 +392,8 @@ where
 
 // TODO(mbrobbel): this is the remaining items in the iterator, maybe we want
 // this to be the original slot length?
-impl<'a, T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
-    for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
+impl<T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType> Length
+    for OffsetSlice<'_, T, NULLABLE, OffsetItem, Buffer>
 where
     <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
 {
@@ -420,10 +422,47 @@ where
     }
 }
 
+impl<'a, T, U, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    IntoOwned<Vec<U>> for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    T: Index,
+    <T as Index>::Item<'a>: IntoOwned<U>,
+{
+    fn into_owned(self) -> Vec<U> {
+        self.map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl<'a, T, U, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    IntoOwned<VecDeque<U>> for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    T: Index,
+    <T as Index>::Item<'a>: IntoOwned<U>,
+{
+    fn into_owned(self) -> VecDeque<U> {
+        self.map(IntoOwned::into_owned).collect()
+    }
+}
+
+impl<'a, T, const NULLABLE: bool, OffsetItem: OffsetElement, Buffer: BufferType>
+    IntoOwned<VariableSizeBinary> for OffsetSlice<'a, T, NULLABLE, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Validity<NULLABLE>,
+    T: Index,
+    <T as Index>::Item<'a>: IntoOwned<u8>,
+{
+    fn into_owned(self) -> VariableSizeBinary {
+        self.map(IntoOwned::into_owned).collect::<Vec<_>>().into()
+    }
+}
+
 impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Index
     for Offset<T, false, OffsetItem, Buffer>
 {
-    type Item<'a> = OffsetSlice<'a, T, false, OffsetItem, Buffer>
+    type Item<'a>
+        = OffsetSlice<'a, T, false, OffsetItem, Buffer>
     where
         Self: 'a;
 
@@ -443,7 +482,8 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Index
 impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Index
     for Offset<T, true, OffsetItem, Buffer>
 {
-    type Item<'a> = Option<OffsetSlice<'a, T, true, OffsetItem, Buffer>>
+    type Item<'a>
+        = Option<OffsetSlice<'a, T, true, OffsetItem, Buffer>>
     where
         Self: 'a;
 
@@ -638,6 +678,56 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> Length
     }
 }
 
+/// Returns the data of `data` up to the `end` offset value.
+///
+/// The offset values in a window of the offsets still point to the slots in
+/// the data, so the data is shared from the start. The data is truncated at
+/// the end of the window, so that extending a slice appends the new items
+/// after the last slot of the window.
+///
+/// # Safety
+///
+/// Caller must ensure `end` is within bounds of `data`.
+unsafe fn slice_data<T: Slice, OffsetItem: OffsetElement>(data: &T, end: OffsetItem) -> T {
+    data.slice_unchecked(0, end.try_into().expect("convert fail"))
+}
+
+impl<T: Slice, OffsetItem: OffsetElement, Buffer: BufferType> Slice
+    for Offset<T, false, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            data: slice_data(
+                &self.data,
+                *self.offsets.as_slice().get_unchecked(offset + len),
+            ),
+            offsets: self.offsets.slice_unchecked(offset, len + 1),
+        }
+    }
+}
+
+impl<T: Slice, OffsetItem: OffsetElement, Buffer: BufferType> Slice
+    for Offset<T, true, OffsetItem, Buffer>
+where
+    <Buffer as BufferType>::Buffer<OffsetItem>: Slice,
+    Bitmap<Buffer>: Slice,
+{
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        Self {
+            data: slice_data(
+                &self.data,
+                *self.offsets.data.as_slice().get_unchecked(offset + len),
+            ),
+            offsets: Nullable {
+                data: self.offsets.data.slice_unchecked(offset, len + 1),
+                validity: self.offsets.validity.slice_unchecked(offset, len),
+            },
+        }
+    }
+}
+
 impl<T, OffsetItem: OffsetElement, Buffer: BufferType> BitmapRef
     for Offset<T, true, OffsetItem, Buffer>
 {
@@ -664,6 +754,7 @@ impl<T, OffsetItem: OffsetElement, Buffer: BufferType> ValidityBitmap
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::buffer::ArcSliceBuffer;
 
     #[test]
     fn default() {
@@ -814,6 +905,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn slice() {
+        let input = vec![vec![1, 2, 3, 4], vec![5, 6], vec![7, 8, 9]];
+        let offset = input.into_iter().collect::<Offset<
+            <ArcSliceBuffer as BufferType>::Buffer<u8>,
+            false,
+            i32,
+            ArcSliceBuffer,
+        >>();
+        let sliced = offset.slice(1..);
+        assert_eq!(sliced.len(), 2);
+        assert_eq!(sliced.offsets.as_slice(), &[4, 6, 9]);
+        assert_eq!(
+            sliced.index_checked(1).copied().collect::<Vec<_>>(),
+            [7, 8, 9]
+        );
+
+        let input_nullable = vec![Some(vec![1, 2, 3, 4]), None, Some(vec![5, 6, 7, 8])];
+        let offset_nullable = input_nullable.into_iter().collect::<Offset<
+            <ArcSliceBuffer as BufferType>::Buffer<u8>,
+            true,
+            i32,
+            ArcSliceBuffer,
+        >>();
+        let sliced_nullable = offset_nullable.slice(1..3);
+        assert_eq!(sliced_nullable.len(), 2);
+        assert!(sliced_nullable.index_checked(0).is_none());
+        assert_eq!(
+            sliced_nullable
+                .index_checked(1)
+                .expect("a value")
+                .copied()
+                .collect::<Vec<_>>(),
+            [5, 6, 7, 8]
+        );
+    }
+
     #[test]
     fn convert() {
         let input = vec![vec![1, 2, 3, 4], vec![5, 6], vec![7, 8, 9]];
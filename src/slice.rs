@@ -0,0 +1,75 @@
+//! Zero-copy slicing of collections.
+
+use crate::Length;
+use std::ops::{Bound, RangeBounds};
+
+/// Zero-copy slicing of a collection.
+///
+/// Slices share the underlying memory of the collection, which means this is
+/// only implemented for collections with buffers that support sharing (a
+/// window of) their memory.
+pub trait Slice: Length + Sized {
+    /// Returns a slice with the items in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    #[must_use]
+    fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1).expect("range start overflow"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1).expect("range end overflow"),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        assert!(
+            start <= end && end <= len,
+            "range (is {start}..{end}) should be within len (is {len})"
+        );
+
+        // Safety:
+        // - Bounds checked above.
+        unsafe { self.slice_unchecked(start, end - start) }
+    }
+
+    /// Returns a slice with `len` items starting at `offset`. Skips bound
+    /// checking.
+    ///
+    /// # Safety
+    ///
+    /// Caller must ensure `offset + len` is within bounds.
+    #[must_use]
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self;
+}
+
+impl<T> Slice for &[T] {
+    unsafe fn slice_unchecked(&self, offset: usize, len: usize) -> Self {
+        self.get_unchecked(offset..offset + len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice() {
+        let slice: &[u8] = &[1, 2, 3, 4];
+        assert_eq!(slice.slice(..), [1, 2, 3, 4]);
+        assert_eq!(slice.slice(1..3), [2, 3]);
+        assert_eq!(slice.slice(1..=3), [2, 3, 4]);
+        assert!(slice.slice(4..).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "range (is 2..5) should be within len (is 4)")]
+    fn out_of_bounds() {
+        let slice: &[u8] = &[1, 2, 3, 4];
+        let _ = slice.slice(2..5);
+    }
+}